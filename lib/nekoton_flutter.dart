@@ -1,5 +1,6 @@
 library nekoton_flutter;
 
+export 'src/cancellation_token.dart';
 export 'src/constants.dart';
 export 'src/core/accounts_storage/accounts_storage.dart';
 export 'src/core/accounts_storage/constants.dart';
@@ -31,25 +32,36 @@ export 'src/core/models/message.dart';
 export 'src/core/models/pending_transaction.dart';
 export 'src/core/models/polling_method.dart';
 export 'src/core/models/raw_contract_state.dart';
+export 'src/core/models/send_journal_entry.dart';
+export 'src/core/models/subscription_alert_payload.dart';
 export 'src/core/models/transaction.dart';
 export 'src/core/models/transaction_id.dart';
 export 'src/core/models/transactions_batch_info.dart';
 export 'src/core/models/transactions_batch_type.dart';
 export 'src/core/models/transactions_list.dart';
+export 'src/core/send_journal.dart';
+export 'src/core/subscription_errors.dart';
 export 'src/core/token_wallet/get_token_root_details.dart';
 export 'src/core/token_wallet/get_token_root_details_from_token_wallet.dart';
 export 'src/core/token_wallet/get_token_wallet_details.dart';
+export 'src/core/token_wallet/get_token_wallet_details_batch.dart';
+export 'src/core/token_wallet/get_token_wallet_history.dart';
+export 'src/core/token_wallet/models/bridge_burn.dart';
 export 'src/core/token_wallet/models/root_token_contract_details.dart';
 export 'src/core/token_wallet/models/symbol.dart';
 export 'src/core/token_wallet/models/token_incoming_transfer.dart';
 export 'src/core/token_wallet/models/token_outgoing_transfer.dart';
 export 'src/core/token_wallet/models/token_swap_back.dart';
 export 'src/core/token_wallet/models/token_wallet_details.dart';
+export 'src/core/token_wallet/models/token_wallet_details_batch_item.dart';
+export 'src/core/token_wallet/models/token_wallet_history.dart';
+export 'src/core/token_wallet/models/token_wallet_history_item.dart';
 export 'src/core/token_wallet/models/token_wallet_transaction.dart';
 export 'src/core/token_wallet/models/token_wallet_transaction_with_data.dart';
 export 'src/core/token_wallet/models/token_wallet_version.dart';
 export 'src/core/token_wallet/models/transfer_recipient.dart';
 export 'src/core/token_wallet/token_wallet.dart';
+export 'src/core/ton_wallet/compute_code_hash_from_abi.dart';
 export 'src/core/ton_wallet/find_existing_wallets.dart';
 export 'src/core/ton_wallet/get_existing_wallet_info.dart';
 export 'src/core/ton_wallet/get_wallet_custodians.dart';
@@ -66,6 +78,8 @@ export 'src/core/ton_wallet/models/token_wallet_deployed_notification.dart';
 export 'src/core/ton_wallet/models/ton_wallet_details.dart';
 export 'src/core/ton_wallet/models/ton_wallet_transaction_with_data.dart';
 export 'src/core/ton_wallet/models/transaction_additional_info.dart';
+export 'src/core/ton_wallet/models/transfer_from_uri_output.dart';
+export 'src/core/ton_wallet/models/transfer_uri_issue.dart';
 export 'src/core/ton_wallet/models/wallet_interaction_info.dart';
 export 'src/core/ton_wallet/models/wallet_interaction_method.dart';
 export 'src/core/ton_wallet/ton_wallet.dart';
@@ -99,6 +113,7 @@ export 'src/crypto/ledger_key/ledger_update_key_input.dart';
 export 'src/crypto/ledger_key/ledger_update_key_input_rename.dart';
 export 'src/crypto/mnemonic/derive_from_phrase.dart';
 export 'src/crypto/mnemonic/generate_key.dart';
+export 'src/crypto/mnemonic/generate_key_pair.dart';
 export 'src/crypto/mnemonic/get_hints.dart';
 export 'src/crypto/mnemonic/models/generated_key.dart';
 export 'src/crypto/mnemonic/models/keypair.dart';
@@ -110,6 +125,7 @@ export 'src/crypto/models/export_key_input.dart';
 export 'src/crypto/models/export_key_output.dart';
 export 'src/crypto/models/get_public_keys.dart';
 export 'src/crypto/models/sign_input.dart';
+export 'src/crypto/models/signature_encoding.dart';
 export 'src/crypto/models/signature_parts.dart';
 export 'src/crypto/models/signed_data.dart';
 export 'src/crypto/models/signed_data_raw.dart';
@@ -127,41 +143,149 @@ export 'src/external/models/gql_network_settings.dart';
 export 'src/external/models/jrpc_network_settings.dart';
 export 'src/external/models/ledger_signature_context.dart';
 export 'src/external/storage.dart';
+export 'src/helpers/abi/batch_run_local.dart';
+export 'src/helpers/abi/build_bridge_burn_payload.dart';
+export 'src/helpers/abi/call_template.dart';
 export 'src/helpers/abi/check_public_key.dart';
+export 'src/helpers/abi/clear_abi_cache.dart';
 export 'src/helpers/abi/create_external_message.dart';
 export 'src/helpers/abi/create_external_message_without_signature.dart';
+export 'src/helpers/abi/create_signed_external_message.dart';
 export 'src/helpers/abi/decode_event.dart';
 export 'src/helpers/abi/decode_input.dart';
+export 'src/helpers/abi/decode_message_body.dart';
 export 'src/helpers/abi/decode_output.dart';
+export 'src/helpers/abi/decode_safe_multisig_transaction.dart';
 export 'src/helpers/abi/decode_transaction.dart';
 export 'src/helpers/abi/decode_transaction_events.dart';
+export 'src/helpers/abi/decode_transaction_with_state.dart';
+export 'src/helpers/abi/encode_external_input.dart';
 export 'src/helpers/abi/encode_internal_input.dart';
+export 'src/helpers/abi/encode_transfer_with_comment.dart';
+export 'src/helpers/abi/execute_transaction_locally.dart';
 export 'src/helpers/abi/get_boc_hash.dart';
+export 'src/helpers/abi/get_cache_stats.dart';
+export 'src/helpers/abi/get_code_salt.dart';
+export 'src/helpers/abi/get_contract_function_selector_from_body.dart';
 export 'src/helpers/abi/get_expected_address.dart';
+export 'src/helpers/abi/get_function_id.dart';
+export 'src/helpers/abi/get_function_signature.dart';
 export 'src/helpers/abi/models/abi_param.dart';
+export 'src/helpers/abi/models/cache_stats.dart';
+export 'src/helpers/abi/models/cache_stats_output.dart';
+export 'src/helpers/abi/models/decoded_call.dart';
 export 'src/helpers/abi/models/decoded_event.dart';
 export 'src/helpers/abi/models/decoded_input.dart';
+export 'src/helpers/abi/models/decoded_message_body.dart';
+export 'src/helpers/abi/models/decoded_out_message.dart';
+export 'src/helpers/abi/models/decoded_out_message_body.dart';
 export 'src/helpers/abi/models/decoded_output.dart';
 export 'src/helpers/abi/models/decoded_transaction.dart';
 export 'src/helpers/abi/models/decoded_transaction_event.dart';
+export 'src/helpers/abi/models/decoded_transaction_event_error.dart';
+export 'src/helpers/abi/models/decoded_transaction_event_result.dart';
+export 'src/helpers/abi/models/decoded_transaction_with_state.dart';
+export 'src/helpers/abi/models/encoded_external_input.dart';
+export 'src/helpers/abi/models/executed_transaction.dart';
+export 'src/helpers/abi/models/execution_context.dart';
 export 'src/helpers/abi/models/execution_output.dart';
+export 'src/helpers/abi/models/expected_address.dart';
 export 'src/helpers/abi/models/function_call.dart';
+export 'src/helpers/abi/models/function_ids.dart';
+export 'src/helpers/abi/models/local_call.dart';
+export 'src/helpers/abi/models/local_transaction_execution.dart';
 export 'src/helpers/abi/models/method_name.dart';
+export 'src/helpers/abi/models/multisig_confirmation.dart';
+export 'src/helpers/abi/models/multisig_submission.dart';
+export 'src/helpers/abi/models/not_accepted_message.dart';
+export 'src/helpers/abi/models/public_key_rejection.dart';
+export 'src/helpers/abi/models/public_key_validation.dart';
+export 'src/helpers/abi/models/responsible_execution_output.dart';
+export 'src/helpers/abi/models/safe_multisig_transaction.dart';
+export 'src/helpers/abi/models/sign_payload.dart';
+export 'src/helpers/abi/models/signature_layout.dart';
+export 'src/helpers/abi/models/simulated_transaction.dart';
 export 'src/helpers/abi/models/tokens_object.dart';
+export 'src/helpers/abi/models/transaction_direction.dart';
+export 'src/helpers/abi/models/unpacked_cell.dart';
+export 'src/helpers/abi/models/unsigned_external_message.dart';
 export 'src/helpers/abi/pack_into_cell.dart';
 export 'src/helpers/abi/parse_known_payload.dart';
+export 'src/helpers/abi/prepare_sign_payload.dart';
 export 'src/helpers/abi/run_local.dart';
+export 'src/helpers/abi/run_local_responsible_value.dart';
+export 'src/helpers/abi/run_local_with_config.dart';
+export 'src/helpers/abi/set_code_salt.dart';
+export 'src/helpers/abi/simulate_transaction.dart';
 export 'src/helpers/abi/unpack_from_cell.dart';
+export 'src/helpers/abi/unpack_from_cell_ex.dart';
+export 'src/helpers/abi/validate_public_key.dart';
+export 'src/helpers/account_visual_seed.dart';
+export 'src/helpers/canonical_json.dart';
+export 'src/helpers/check_address.dart';
 export 'src/helpers/code_to_tvc.dart';
+export 'src/helpers/decode_block_info.dart';
+export 'src/helpers/decode_jetton_burn_notification.dart';
 export 'src/helpers/extract_public_key.dart';
+export 'src/helpers/get_account_cells_total_count.dart';
+export 'src/helpers/get_account_credit_phases.dart';
+export 'src/helpers/get_config_param_17.dart';
+export 'src/helpers/get_ever_wallet_custodians.dart';
+export 'src/helpers/get_message_value.dart';
+export 'src/helpers/get_transaction_aborted.dart';
+export 'src/helpers/get_transaction_fees.dart';
+export 'src/helpers/is_runtime_alive.dart';
+export 'src/helpers/merge_tvc.dart';
+export 'src/helpers/models/aborted_phase.dart';
+export 'src/helpers/models/account_cells_count.dart';
+export 'src/helpers/models/account_visual.dart';
+export 'src/helpers/models/block_info.dart';
+export 'src/helpers/models/block_ref.dart';
+export 'src/helpers/models/checked_address.dart';
+export 'src/helpers/models/credit_phase.dart';
+export 'src/helpers/models/extra_currency.dart';
+export 'src/helpers/models/ffi_call_metrics.dart';
+export 'src/helpers/models/hsl_color.dart';
+export 'src/helpers/models/jetton_burn_notification.dart';
+export 'src/helpers/models/live_subscriptions.dart';
+export 'src/helpers/models/merged_tvc.dart';
+export 'src/helpers/models/message_boc_type.dart';
+export 'src/helpers/models/message_boc_validation.dart';
+export 'src/helpers/models/message_value.dart';
+export 'src/helpers/models/parsed_message.dart';
+export 'src/helpers/models/runtime_metrics.dart';
+export 'src/helpers/models/self_test_check.dart';
 export 'src/helpers/models/splitted_tvc.dart';
+export 'src/helpers/models/stakes_config.dart';
+export 'src/helpers/models/transaction_abort_status.dart';
+export 'src/helpers/models/transaction_fees.dart';
 export 'src/helpers/pack_std_smc_addr.dart';
+export 'src/helpers/pack_token_value.dart';
+export 'src/helpers/parse_message.dart';
 export 'src/helpers/repack_address.dart';
+export 'src/helpers/runtime_metrics.dart';
+export 'src/helpers/self_test.dart';
 export 'src/helpers/split_tvc.dart';
+export 'src/helpers/testable_clock.dart';
 export 'src/helpers/unpack_std_smc_addr.dart';
 export 'src/helpers/validate_address.dart';
+export 'src/helpers/validate_message_boc.dart';
+export 'src/models/cancelled_exception.dart';
 export 'src/models/nekoton_exception.dart';
+export 'src/transport/block_subscription.dart';
+export 'src/transport/get_global_subscription_count.dart';
 export 'src/transport/gql_transport.dart';
 export 'src/transport/jrpc_transport.dart';
+export 'src/transport/models/awaited_message.dart';
+export 'src/transport/models/awaited_transaction_payload.dart';
+export 'src/transport/models/block_limits_params.dart';
+export 'src/transport/models/block_tip.dart';
+export 'src/transport/models/gas_params.dart';
+export 'src/transport/models/history_download_progress.dart';
+export 'src/transport/models/limits_params.dart';
+export 'src/transport/models/network_params.dart';
+export 'src/transport/models/reconnect_backoff.dart';
+export 'src/transport/models/size_limits.dart';
 export 'src/transport/models/transport_type.dart';
+export 'src/transport/models/unfreeze_params.dart';
 export 'src/transport/transport.dart';
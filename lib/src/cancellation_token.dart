@@ -0,0 +1,29 @@
+import 'dart:ffi';
+
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+final _nativeFinalizer =
+    NativeFinalizer(NekotonFlutter.instance().bindings.addresses.nt_cancellation_token_free_ptr);
+
+class CancellationToken implements Finalizable {
+  late final Pointer<Void> _ptr;
+
+  CancellationToken() {
+    final result = executeSync(
+      () => NekotonFlutter.instance().bindings.nt_cancellation_token_create(),
+    );
+
+    _ptr = Pointer.fromAddress(result as int).cast<Void>();
+
+    _nativeFinalizer.attach(this, _ptr);
+  }
+
+  Pointer<Void> get ptr => _ptr;
+
+  void cancel() => executeSync(
+        () => NekotonFlutter.instance().bindings.nt_cancellation_token_cancel(
+              ptr,
+            ),
+      );
+}
@@ -7,7 +7,6 @@ part 'additional_assets.g.dart';
 
 @freezed
 class AdditionalAssets with _$AdditionalAssets {
-  @JsonSerializable(fieldRename: FieldRename.snake)
   const factory AdditionalAssets({
     required List<TokenWalletAsset> tokenWallets,
     required List<DePoolAsset> depools,
@@ -11,6 +11,7 @@ class AssetsList with _$AssetsList implements Comparable<AssetsList> {
     required String name,
     required TonWalletAsset tonWallet,
     required Map<String, AdditionalAssets> additionalAssets,
+    @JsonKey(includeIfNull: false) String? visualSeed,
   }) = _AssetsList;
 
   factory AssetsList.fromJson(Map<String, dynamic> json) => _$AssetsListFromJson(json);
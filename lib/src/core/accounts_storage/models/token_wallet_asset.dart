@@ -6,7 +6,6 @@ part 'token_wallet_asset.g.dart';
 
 @freezed
 class TokenWalletAsset with _$TokenWalletAsset {
-  @JsonSerializable(fieldRename: FieldRename.snake)
   const factory TokenWalletAsset({
     required String rootTokenContract,
   }) = _TokenWalletAsset;
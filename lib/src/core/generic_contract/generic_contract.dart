@@ -5,6 +5,7 @@ import 'dart:isolate';
 
 import 'package:ffi/ffi.dart';
 import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/cancellation_token.dart';
 import 'package:nekoton_flutter/src/core/contract_subscription/contract_subscription.dart';
 import 'package:nekoton_flutter/src/core/generic_contract/models/transaction_execution_options.dart';
 import 'package:nekoton_flutter/src/core/models/contract_state.dart';
@@ -188,11 +189,16 @@ class GenericContract extends ContractSubscription implements Finalizable {
             ),
       );
 
-  Future<void> preloadTransactions(String fromLt) => executeAsync(
+  Future<void> preloadTransactions(
+    String fromLt, {
+    CancellationToken? cancellationToken,
+  }) =>
+      executeAsync(
         (port) => NekotonFlutter.instance().bindings.nt_generic_contract_preload_transactions(
               port,
               ptr,
               fromLt.toNativeUtf8().cast<Char>(),
+              cancellationToken?.ptr ?? nullptr,
             ),
       );
 
@@ -21,6 +21,7 @@ import 'package:nekoton_flutter/src/crypto/models/sign_input.dart';
 import 'package:nekoton_flutter/src/crypto/models/signed_data.dart';
 import 'package:nekoton_flutter/src/crypto/models/signed_data_raw.dart';
 import 'package:nekoton_flutter/src/crypto/models/update_key_input.dart';
+import 'package:nekoton_flutter/src/crypto/password_cache/password.dart';
 import 'package:nekoton_flutter/src/external/ledger_connection.dart';
 import 'package:nekoton_flutter/src/external/storage.dart';
 import 'package:nekoton_flutter/src/ffi_utils.dart';
@@ -185,6 +186,53 @@ class Keystore implements Finalizable {
     return output;
   }
 
+  /// Exports a single encrypted key as a standalone JSON file protected by [exportPassword]
+  Future<String> exportEntry({
+    required String publicKey,
+    required Password password,
+    required String exportPassword,
+  }) async {
+    final passwordStr = jsonEncode(password);
+
+    final result = await executeAsync(
+      (port) => NekotonFlutter.instance().bindings.nt_keystore_export_entry(
+            port,
+            ptr,
+            publicKey.toNativeUtf8().cast<Char>(),
+            passwordStr.toNativeUtf8().cast<Char>(),
+            exportPassword.toNativeUtf8().cast<Char>(),
+          ),
+    );
+
+    final exportedKey = jsonEncode(result);
+
+    return exportedKey;
+  }
+
+  Future<KeyStoreEntry> importEntry({
+    required String exportedKey,
+    required String exportPassword,
+    required Password newPassword,
+  }) =>
+      _updateEntries(() async {
+        final newPasswordStr = jsonEncode(newPassword);
+
+        final result = await executeAsync(
+          (port) => NekotonFlutter.instance().bindings.nt_keystore_import_entry(
+                port,
+                ptr,
+                exportedKey.toNativeUtf8().cast<Char>(),
+                exportPassword.toNativeUtf8().cast<Char>(),
+                newPasswordStr.toNativeUtf8().cast<Char>(),
+              ),
+        );
+
+        final json = result as Map<String, dynamic>;
+        final entry = KeyStoreEntry.fromJson(json);
+
+        return entry;
+      });
+
   Future<List<String>> getPublicKeys(GetPublicKeys input) async {
     final signer = input.toSigner();
     final inputStr = jsonEncode(input);
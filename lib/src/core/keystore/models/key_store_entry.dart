@@ -9,7 +9,6 @@ part 'key_store_entry.g.dart';
 
 @freezed
 class KeyStoreEntry with _$KeyStoreEntry implements Comparable<KeyStoreEntry> {
-  @JsonSerializable(fieldRename: FieldRename.snake)
   const factory KeyStoreEntry({
     required String signerName,
     required String name,
@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'send_journal_entry.freezed.dart';
+part 'send_journal_entry.g.dart';
+
+@freezed
+class SendJournalEntry with _$SendJournalEntry {
+  const factory SendJournalEntry({
+    required String messageHash,
+    required String dst,
+    required int expireAt,
+    required bool completed,
+  }) = _SendJournalEntry;
+
+  factory SendJournalEntry.fromJson(Map<String, dynamic> json) => _$SendJournalEntryFromJson(json);
+}
@@ -0,0 +1,14 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'subscription_alert_payload.freezed.dart';
+part 'subscription_alert_payload.g.dart';
+
+@Freezed(unionKey: 'type')
+class SubscriptionAlertPayload with _$SubscriptionAlertPayload {
+  const factory SubscriptionAlertPayload.errorThreshold({
+    required int count,
+  }) = _SubscriptionAlertPayloadErrorThreshold;
+
+  factory SubscriptionAlertPayload.fromJson(Map<String, dynamic> json) =>
+      _$SubscriptionAlertPayloadFromJson(json);
+}
@@ -0,0 +1,42 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/core/models/send_journal_entry.dart';
+import 'package:nekoton_flutter/src/external/storage.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+/// Journals messages sent by wallets and contracts into [storage], `null` disables journaling
+void setSendJournal(Storage? storage) => executeSync(
+      () => NekotonFlutter.instance().bindings.nt_set_send_journal(
+            storage?.ptr ?? nullptr,
+          ),
+    );
+
+/// Entries whose fate is unknown, they stay until resolved or acknowledged
+Future<List<SendJournalEntry>> getUnresolvedSends(Storage storage) async {
+  final result = await executeAsync(
+    (port) => NekotonFlutter.instance().bindings.nt_get_unresolved_sends(
+          port,
+          storage.ptr,
+        ),
+  );
+
+  final list = result as List<dynamic>;
+  final json = list.cast<Map<String, dynamic>>();
+  final entries = json.map((e) => SendJournalEntry.fromJson(e)).toList();
+
+  return entries;
+}
+
+Future<void> acknowledgeUnresolvedSend({
+  required Storage storage,
+  required String messageHash,
+}) =>
+    executeAsync(
+      (port) => NekotonFlutter.instance().bindings.nt_acknowledge_unresolved_send(
+            port,
+            storage.ptr,
+            messageHash.toNativeUtf8().cast<Char>(),
+          ),
+    );
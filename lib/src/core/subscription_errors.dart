@@ -0,0 +1,44 @@
+import 'dart:convert';
+import 'dart:ffi';
+import 'dart:isolate';
+
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/core/models/subscription_alert_payload.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+/// Number of failed refreshes of a ton wallet, token wallet or generic contract [subscription]
+int getSubscriptionErrorCount(Pointer<Void> subscription) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_subscription_error_count(subscription),
+  );
+
+  final count = result as int;
+
+  return count;
+}
+
+void resetSubscriptionErrorCount(Pointer<Void> subscription) => executeSync(
+      () => NekotonFlutter.instance().bindings.nt_reset_subscription_error_count(subscription),
+    );
+
+/// Posts an alert to [alertPort] once the error count of [subscription] exceeds [threshold],
+/// use [decodeSubscriptionAlert] to read the messages
+void setSubscriptionErrorThreshold({
+  required Pointer<Void> subscription,
+  required int threshold,
+  required SendPort alertPort,
+}) =>
+    executeSync(
+      () => NekotonFlutter.instance().bindings.nt_set_subscription_error_threshold(
+            subscription,
+            threshold,
+            alertPort.nativePort,
+          ),
+    );
+
+SubscriptionAlertPayload decodeSubscriptionAlert(dynamic message) {
+  final json = jsonDecode(message as String) as Map<String, dynamic>;
+  final payload = SubscriptionAlertPayload.fromJson(json);
+
+  return payload;
+}
@@ -0,0 +1,32 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/core/token_wallet/models/token_wallet_details_batch_item.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/transport/transport.dart';
+
+Future<List<TokenWalletDetailsBatchItem>> getTokenWalletDetailsBatch({
+  required Transport transport,
+  required List<String> tokenWallets,
+}) async {
+  final ptr = transport.ptr;
+  final transportTypeStr = jsonEncode(transport.type.toString());
+  final tokenWalletsStr = jsonEncode(tokenWallets);
+
+  final result = await executeAsync(
+    (port) => NekotonFlutter.instance().bindings.nt_get_token_wallet_details_batch(
+          port,
+          ptr,
+          transportTypeStr.toNativeUtf8().cast<Char>(),
+          tokenWalletsStr.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as List<dynamic>;
+  final list = json.cast<Map<String, dynamic>>();
+  final items = list.map((e) => TokenWalletDetailsBatchItem.fromJson(e)).toList();
+
+  return items;
+}
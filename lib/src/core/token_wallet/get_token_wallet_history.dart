@@ -0,0 +1,38 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/core/token_wallet/models/token_wallet_history.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/transport/transport.dart';
+
+/// Transactions of all token wallet versions [owner] has for [rootTokenContract], pass the
+/// returned continuation as [fromLt] to get the next page
+Future<TokenWalletHistory> getTokenWalletHistory({
+  required Transport transport,
+  required String rootTokenContract,
+  required String owner,
+  String? fromLt,
+  required int count,
+}) async {
+  final ptr = transport.ptr;
+  final transportTypeStr = jsonEncode(transport.type.toString());
+
+  final result = await executeAsync(
+    (port) => NekotonFlutter.instance().bindings.nt_token_wallet_history(
+          port,
+          ptr,
+          transportTypeStr.toNativeUtf8().cast<Char>(),
+          rootTokenContract.toNativeUtf8().cast<Char>(),
+          owner.toNativeUtf8().cast<Char>(),
+          fromLt?.toNativeUtf8().cast<Char>() ?? nullptr,
+          count,
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final history = TokenWalletHistory.fromJson(json);
+
+  return history;
+}
@@ -0,0 +1,14 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'bridge_burn.freezed.dart';
+part 'bridge_burn.g.dart';
+
+@freezed
+class BridgeBurn with _$BridgeBurn {
+  const factory BridgeBurn({
+    required String evmAddress,
+    required int chainId,
+  }) = _BridgeBurn;
+
+  factory BridgeBurn.fromJson(Map<String, dynamic> json) => _$BridgeBurnFromJson(json);
+}
@@ -6,7 +6,6 @@ part 'root_token_contract_details.g.dart';
 
 @freezed
 class RootTokenContractDetails with _$RootTokenContractDetails {
-  @JsonSerializable(fieldRename: FieldRename.snake)
   const factory RootTokenContractDetails({
     required TokenWalletVersion version,
     required String name,
@@ -5,7 +5,6 @@ part 'token_swap_back.g.dart';
 
 @freezed
 class TokenSwapBack with _$TokenSwapBack {
-  @JsonSerializable(fieldRename: FieldRename.snake)
   const factory TokenSwapBack({
     required String tokens,
     required String callbackAddress,
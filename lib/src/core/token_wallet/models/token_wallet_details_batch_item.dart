@@ -0,0 +1,21 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/core/token_wallet/models/token_wallet_version.dart';
+
+part 'token_wallet_details_batch_item.freezed.dart';
+part 'token_wallet_details_batch_item.g.dart';
+
+/// Details of a single token wallet, only [error] is set if they couldn't be fetched
+@freezed
+class TokenWalletDetailsBatchItem with _$TokenWalletDetailsBatchItem {
+  const factory TokenWalletDetailsBatchItem({
+    required String address,
+    String? balance,
+    String? root,
+    String? owner,
+    TokenWalletVersion? version,
+    String? error,
+  }) = _TokenWalletDetailsBatchItem;
+
+  factory TokenWalletDetailsBatchItem.fromJson(Map<String, dynamic> json) =>
+      _$TokenWalletDetailsBatchItemFromJson(json);
+}
@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/core/token_wallet/models/token_wallet_history_item.dart';
+
+part 'token_wallet_history.freezed.dart';
+part 'token_wallet_history.g.dart';
+
+@freezed
+class TokenWalletHistory with _$TokenWalletHistory {
+  const factory TokenWalletHistory({
+    required List<TokenWalletHistoryItem> transactions,
+    String? continuation,
+  }) = _TokenWalletHistory;
+
+  factory TokenWalletHistory.fromJson(Map<String, dynamic> json) =>
+      _$TokenWalletHistoryFromJson(json);
+}
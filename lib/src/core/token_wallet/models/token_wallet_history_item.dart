@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/core/models/transaction.dart';
+
+part 'token_wallet_history_item.freezed.dart';
+part 'token_wallet_history_item.g.dart';
+
+@freezed
+class TokenWalletHistoryItem with _$TokenWalletHistoryItem {
+  const factory TokenWalletHistoryItem({
+    required String walletAddress,
+    required Transaction transaction,
+  }) = _TokenWalletHistoryItem;
+
+  factory TokenWalletHistoryItem.fromJson(Map<String, dynamic> json) =>
+      _$TokenWalletHistoryItemFromJson(json);
+}
@@ -6,6 +6,7 @@ import 'dart:isolate';
 import 'package:ffi/ffi.dart';
 import 'package:flutter/foundation.dart';
 import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/cancellation_token.dart';
 import 'package:nekoton_flutter/src/core/contract_subscription/contract_subscription.dart';
 import 'package:nekoton_flutter/src/core/models/contract_state.dart';
 import 'package:nekoton_flutter/src/core/models/internal_message.dart';
@@ -179,6 +180,31 @@ class TokenWallet extends ContractSubscription implements Finalizable {
     return internalMessage;
   }
 
+  /// Sends [tokens] straight to an existing token wallet, unlike [prepareTransfer] which is
+  /// addressed to the owner and deploys the recipient wallet if needed
+  Future<InternalMessage> prepareTransferToWallet({
+    required String destinationWallet,
+    required String tokens,
+    required bool notifyReceiver,
+    String? payload,
+  }) async {
+    final result = await executeAsync(
+      (port) => NekotonFlutter.instance().bindings.nt_token_wallet_prepare_transfer_to_wallet(
+            port,
+            ptr,
+            destinationWallet.toNativeUtf8().cast<Char>(),
+            tokens.toNativeUtf8().cast<Char>(),
+            notifyReceiver ? 1 : 0,
+            payload?.toNativeUtf8().cast<Char>() ?? nullptr,
+          ),
+    );
+
+    final json = result as Map<String, dynamic>;
+    final internalMessage = InternalMessage.fromJson(json);
+
+    return internalMessage;
+  }
+
   @override
   Future<void> refresh() => executeAsync(
         (port) => NekotonFlutter.instance().bindings.nt_token_wallet_refresh(
@@ -187,11 +213,16 @@ class TokenWallet extends ContractSubscription implements Finalizable {
             ),
       );
 
-  Future<void> preloadTransactions(String fromLt) => executeAsync(
+  Future<void> preloadTransactions(
+    String fromLt, {
+    CancellationToken? cancellationToken,
+  }) =>
+      executeAsync(
         (port) => NekotonFlutter.instance().bindings.nt_token_wallet_preload_transactions(
               port,
               ptr,
               fromLt.toNativeUtf8().cast<Char>(),
+              cancellationToken?.ptr ?? nullptr,
             ),
       );
 
@@ -0,0 +1,22 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/core/accounts_storage/models/wallet_type.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+/// Hex encoded code hash of a built-in [walletType], can be used with accounts lookups by code hash
+String computeCodeHashFromAbi(WalletType walletType) {
+  final walletTypeStr = jsonEncode(walletType);
+
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_compute_code_hash_from_abi(
+          walletTypeStr.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final codeHash = result as String;
+
+  return codeHash;
+}
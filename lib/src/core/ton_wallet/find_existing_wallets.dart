@@ -3,6 +3,7 @@ import 'dart:ffi';
 
 import 'package:ffi/ffi.dart';
 import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/cancellation_token.dart';
 import 'package:nekoton_flutter/src/core/accounts_storage/models/wallet_type.dart';
 import 'package:nekoton_flutter/src/core/ton_wallet/models/existing_wallet_info.dart';
 import 'package:nekoton_flutter/src/ffi_utils.dart';
@@ -13,6 +14,7 @@ Future<List<ExistingWalletInfo>> findExistingWallets({
   required String publicKey,
   required int workchainId,
   required List<WalletType> walletTypes,
+  CancellationToken? cancellationToken,
 }) async {
   final ptr = transport.ptr;
   final transportTypeStr = jsonEncode(transport.type.toString());
@@ -26,6 +28,7 @@ Future<List<ExistingWalletInfo>> findExistingWallets({
           publicKey.toNativeUtf8().cast<Char>(),
           workchainId,
           walletTypesStr.toNativeUtf8().cast<Char>(),
+          cancellationToken?.ptr ?? nullptr,
         ),
   );
 
@@ -1,4 +1,5 @@
 import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/core/token_wallet/models/bridge_burn.dart';
 import 'package:nekoton_flutter/src/core/token_wallet/models/token_outgoing_transfer.dart';
 import 'package:nekoton_flutter/src/core/token_wallet/models/token_swap_back.dart';
 
@@ -14,5 +15,7 @@ class KnownPayload with _$KnownPayload {
 
   const factory KnownPayload.tokenSwapBack(TokenSwapBack data) = _KnownPayloadTokenSwapBack;
 
+  const factory KnownPayload.bridgeBurn(BridgeBurn data) = _KnownPayloadBridgeBurn;
+
   factory KnownPayload.fromJson(Map<String, dynamic> json) => _$KnownPayloadFromJson(json);
 }
@@ -5,7 +5,6 @@ part 'multisig_pending_transaction.g.dart';
 
 @freezed
 class MultisigPendingTransaction with _$MultisigPendingTransaction {
-  @JsonSerializable(fieldRename: FieldRename.snake)
   const factory MultisigPendingTransaction({
     required String id,
     required List<String> confirmations,
@@ -18,6 +17,7 @@ class MultisigPendingTransaction with _$MultisigPendingTransaction {
     required int sendFlags,
     required String payload,
     required bool bounce,
+    required int expireAt,
   }) = _MultisigPendingTransaction;
 
   factory MultisigPendingTransaction.fromJson(Map<String, dynamic> json) =>
@@ -5,7 +5,6 @@ part 'ton_wallet_details.g.dart';
 
 @freezed
 class TonWalletDetails with _$TonWalletDetails {
-  @JsonSerializable(fieldRename: FieldRename.snake)
   const factory TonWalletDetails({
     required bool requiresSeparateDeploy,
     required String minAmount,
@@ -0,0 +1,13 @@
+import 'package:nekoton_flutter/src/core/ton_wallet/models/transfer_uri_issue.dart';
+import 'package:nekoton_flutter/src/crypto/unsigned_message.dart';
+
+/// Either a message ready to be signed or the reasons why it was not created
+class TransferFromUriOutput {
+  final UnsignedMessage? unsignedMessage;
+  final List<TransferUriIssue> issues;
+
+  const TransferFromUriOutput({
+    this.unsignedMessage,
+    required this.issues,
+  });
+}
@@ -0,0 +1,39 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'transfer_uri_issue.freezed.dart';
+part 'transfer_uri_issue.g.dart';
+
+@Freezed(unionKey: 'type')
+class TransferUriIssue with _$TransferUriIssue {
+  const factory TransferUriIssue.invalidUri({
+    required String reason,
+  }) = _TransferUriIssueInvalidUri;
+
+  const factory TransferUriIssue.invalidAddress({
+    required String address,
+  }) = _TransferUriIssueInvalidAddress;
+
+  const factory TransferUriIssue.invalidAmount({
+    required String amount,
+  }) = _TransferUriIssueInvalidAmount;
+
+  const factory TransferUriIssue.missingAmount() = _TransferUriIssueMissingAmount;
+
+  const factory TransferUriIssue.unsupportedParameter({
+    required String name,
+  }) = _TransferUriIssueUnsupportedParameter;
+
+  const factory TransferUriIssue.invalidBody({
+    required String reason,
+  }) = _TransferUriIssueInvalidBody;
+
+  const factory TransferUriIssue.deployFirst() = _TransferUriIssueDeployFirst;
+
+  const factory TransferUriIssue.insufficientBalance({
+    required String balance,
+    required String amount,
+    required String fees,
+  }) = _TransferUriIssueInsufficientBalance;
+
+  factory TransferUriIssue.fromJson(Map<String, dynamic> json) => _$TransferUriIssueFromJson(json);
+}
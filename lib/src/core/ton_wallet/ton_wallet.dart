@@ -6,6 +6,7 @@ import 'dart:isolate';
 import 'package:ffi/ffi.dart';
 import 'package:flutter/foundation.dart';
 import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/cancellation_token.dart';
 import 'package:nekoton_flutter/src/core/accounts_storage/models/wallet_type.dart';
 import 'package:nekoton_flutter/src/core/contract_subscription/contract_subscription.dart';
 import 'package:nekoton_flutter/src/core/models/contract_state.dart';
@@ -22,6 +23,8 @@ import 'package:nekoton_flutter/src/core/ton_wallet/models/multisig_pending_tran
 import 'package:nekoton_flutter/src/core/ton_wallet/models/on_ton_wallet_transactions_found_payload.dart';
 import 'package:nekoton_flutter/src/core/ton_wallet/models/ton_wallet_details.dart';
 import 'package:nekoton_flutter/src/core/ton_wallet/models/ton_wallet_transaction_with_data.dart';
+import 'package:nekoton_flutter/src/core/ton_wallet/models/transfer_from_uri_output.dart';
+import 'package:nekoton_flutter/src/core/ton_wallet/models/transfer_uri_issue.dart';
 import 'package:nekoton_flutter/src/core/utils.dart';
 import 'package:nekoton_flutter/src/crypto/models/signed_message.dart';
 import 'package:nekoton_flutter/src/crypto/unsigned_message.dart';
@@ -148,6 +151,17 @@ class TonWallet extends ContractSubscription implements Finalizable {
     return address;
   }
 
+  /// Address the wallet subscription was created for, throws while the wallet is being refreshed
+  String get subscriptionAddress {
+    final result = executeSync(
+      () => NekotonFlutter.instance().bindings.nt_get_wallet_subscription_address(ptr),
+    );
+
+    final address = result as String;
+
+    return address;
+  }
+
   String get publicKey => _publicKey;
 
   Future<String> get __publicKey async {
@@ -340,6 +354,83 @@ class TonWallet extends ContractSubscription implements Finalizable {
     return unsignedMessage;
   }
 
+  /// Prepares a TIP-3.1 transfer of [amount] tokens from [tokenWallet] owned by this wallet,
+  /// [deployWalletValue] is spent on deploying the recipient token wallet if it doesn't exist
+  Future<UnsignedMessage> createTokenTransferMessage({
+    required RawContractState contractState,
+    required String publicKey,
+    required String tokenWallet,
+    required String recipient,
+    required String amount,
+    required String deployWalletValue,
+    required bool notifyReceiver,
+    required String attachedAmount,
+    String? payload,
+    required Expiration expiration,
+  }) async {
+    final contractStateStr = jsonEncode(contractState);
+    final expirationStr = jsonEncode(expiration);
+
+    final result = await executeAsync(
+      (port) => NekotonFlutter.instance().bindings.nt_create_token_transfer_message(
+            port,
+            ptr,
+            contractStateStr.toNativeUtf8().cast<Char>(),
+            publicKey.toNativeUtf8().cast<Char>(),
+            tokenWallet.toNativeUtf8().cast<Char>(),
+            recipient.toNativeUtf8().cast<Char>(),
+            amount.toNativeUtf8().cast<Char>(),
+            deployWalletValue.toNativeUtf8().cast<Char>(),
+            notifyReceiver ? 1 : 0,
+            attachedAmount.toNativeUtf8().cast<Char>(),
+            payload?.toNativeUtf8().cast<Char>() ?? nullptr,
+            expirationStr.toNativeUtf8().cast<Char>(),
+          ),
+    );
+
+    final unsignedMessage = UnsignedMessage(Pointer.fromAddress(result as int).cast<Void>());
+
+    return unsignedMessage;
+  }
+
+  /// Prepares a transfer described by a `ton://transfer/<address>?amount=<nano>` URI
+  Future<TransferFromUriOutput> prepareTransferFromUri({
+    required RawContractState contractState,
+    required String publicKey,
+    required String uri,
+    required Expiration expiration,
+  }) async {
+    final contractStateStr = jsonEncode(contractState);
+    final expirationStr = jsonEncode(expiration);
+
+    final result = await executeAsync(
+      (port) => NekotonFlutter.instance().bindings.nt_ton_wallet_prepare_transfer_from_uri(
+            port,
+            ptr,
+            contractStateStr.toNativeUtf8().cast<Char>(),
+            publicKey.toNativeUtf8().cast<Char>(),
+            uri.toNativeUtf8().cast<Char>(),
+            expirationStr.toNativeUtf8().cast<Char>(),
+          ),
+    );
+
+    final json = result as Map<String, dynamic>;
+    final unsignedMessagePtr = json['unsignedMessage'] as int?;
+    final issues = (json['issues'] as List<dynamic>)
+        .cast<Map<String, dynamic>>()
+        .map((e) => TransferUriIssue.fromJson(e))
+        .toList();
+
+    final output = TransferFromUriOutput(
+      unsignedMessage: unsignedMessagePtr != null
+          ? UnsignedMessage(Pointer.fromAddress(unsignedMessagePtr).cast<Void>())
+          : null,
+      issues: issues,
+    );
+
+    return output;
+  }
+
   Future<UnsignedMessage> prepareConfirmTransaction({
     required RawContractState contractState,
     required String publicKey,
@@ -409,6 +500,47 @@ class TonWallet extends ContractSubscription implements Finalizable {
     return transaction;
   }
 
+  /// Same as [send], but emulates the message against the current account state first.
+  /// [cancellationToken] only takes effect until the message is broadcast.
+  Future<Transaction?> sendWithPreflight(
+    SignedMessage signedMessage, {
+    bool skipPreflightOnFetchFailure = false,
+    CancellationToken? cancellationToken,
+  }) async {
+    final pendingTransaction = await sendWithReliablePolling(() async {
+      final transportPtr = _transport.ptr;
+      final transportTypeStr = jsonEncode(_transport.type.toString());
+      final signedMessageStr = jsonEncode(signedMessage);
+      final optionsStr = jsonEncode({'skipPreflightOnFetchFailure': skipPreflightOnFetchFailure});
+
+      final result = await executeAsync(
+        (port) => NekotonFlutter.instance().bindings.nt_ton_wallet_send_with_preflight(
+              port,
+              ptr,
+              transportPtr,
+              transportTypeStr.toNativeUtf8().cast<Char>(),
+              signedMessageStr.toNativeUtf8().cast<Char>(),
+              optionsStr.toNativeUtf8().cast<Char>(),
+              cancellationToken?.ptr ?? nullptr,
+            ),
+      );
+
+      final json = result as Map<String, dynamic>;
+      final pendingTransaction = PendingTransaction.fromJson(json);
+
+      return pendingTransaction;
+    });
+
+    _pendingTransactionsSubject.tryAdd(await _pendingTransactions);
+
+    final transaction = await _onMessageSentStream
+        .firstWhere((e) => e.pendingTransaction == pendingTransaction)
+        .then((v) => v.transaction)
+        .timeout(pendingTransaction.expireAt.toTimeout());
+
+    return transaction;
+  }
+
   @override
   Future<void> refresh() async {
     await executeAsync(
@@ -423,11 +555,16 @@ class TonWallet extends ContractSubscription implements Finalizable {
     _unconfirmedTransactionsSubject.tryAdd(await _unconfirmedTransactions);
   }
 
-  Future<void> preloadTransactions(String fromLt) => executeAsync(
+  Future<void> preloadTransactions(
+    String fromLt, {
+    CancellationToken? cancellationToken,
+  }) =>
+      executeAsync(
         (port) => NekotonFlutter.instance().bindings.nt_ton_wallet_preload_transactions(
               port,
               ptr,
               fromLt.toNativeUtf8().cast<Char>(),
+              cancellationToken?.ptr ?? nullptr,
             ),
       );
 
@@ -0,0 +1,15 @@
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/crypto/mnemonic/models/keypair.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+/// Random keypair which isn't derived from any phrase
+Keypair generateKeyPair() {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_generate_key_pair(),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final keypair = Keypair.fromJson(json);
+
+  return keypair;
+}
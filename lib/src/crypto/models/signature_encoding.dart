@@ -0,0 +1,12 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'signature_encoding.g.dart';
+
+@JsonEnum(alwaysCreate: true)
+enum SignatureEncoding {
+  hex,
+  base64;
+
+  @override
+  String toString() => _$SignatureEncodingEnumMap[this]!;
+}
@@ -1,7 +1,9 @@
+import 'dart:convert';
 import 'dart:ffi';
 
 import 'package:ffi/ffi.dart';
 import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/crypto/models/signature_encoding.dart';
 import 'package:nekoton_flutter/src/crypto/models/signed_message.dart';
 import 'package:nekoton_flutter/src/ffi_utils.dart';
 
@@ -48,12 +50,18 @@ class UnsignedMessage implements Finalizable {
     return hash;
   }
 
-  Future<SignedMessage> sign(String signature) async {
+  Future<SignedMessage> sign(
+    String signature, [
+    SignatureEncoding signatureEncoding = SignatureEncoding.base64,
+  ]) async {
+    final signatureEncodingStr = jsonEncode(signatureEncoding.toString());
+
     final result = await executeAsync(
       (port) => NekotonFlutter.instance().bindings.nt_unsigned_message_sign(
             port,
             ptr,
             signature.toNativeUtf8().cast<Char>(),
+            signatureEncodingStr.toNativeUtf8().cast<Char>(),
           ),
     );
 
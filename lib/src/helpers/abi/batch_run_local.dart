@@ -0,0 +1,37 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/execution_output.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/local_call.dart';
+
+/// Runs all [calls] against the same account state, each result is either an [ExecutionOutput]
+/// or the error of that call as a string
+Future<List<Object>> batchRunLocal({
+  required String accountStuffBoc,
+  required String contractAbi,
+  required List<LocalCall> calls,
+}) async {
+  final callsStr = jsonEncode(calls);
+
+  final result = await executeAsync(
+    (port) => NekotonFlutter.instance().bindings.nt_batch_run_local(
+          port,
+          accountStuffBoc.toNativeUtf8().cast<Char>(),
+          contractAbi.toNativeUtf8().cast<Char>(),
+          callsStr.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as List<dynamic>;
+  final list = json.cast<Map<String, dynamic>>();
+  final results = list
+      .map<Object>(
+        (e) => e.containsKey('error') ? e['error'] as String : ExecutionOutput.fromJson(e),
+      )
+      .toList();
+
+  return results;
+}
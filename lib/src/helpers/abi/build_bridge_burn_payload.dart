@@ -0,0 +1,21 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+String buildBridgeBurnPayload({
+  required String evmAddress,
+  required int chainId,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_build_bridge_burn_payload(
+          evmAddress.toNativeUtf8().cast<Char>(),
+          chainId,
+        ),
+  );
+
+  final payload = result as String;
+
+  return payload;
+}
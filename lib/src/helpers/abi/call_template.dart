@@ -0,0 +1,51 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+
+final _nativeFinalizer =
+    NativeFinalizer(NekotonFlutter.instance().bindings.addresses.nt_call_template_free_ptr);
+
+class CallTemplate implements Finalizable {
+  final Pointer<Void> _ptr;
+
+  CallTemplate._(this._ptr) {
+    _nativeFinalizer.attach(this, _ptr);
+  }
+
+  factory CallTemplate({
+    required String contractAbi,
+    required String method,
+    required TokensObject fixedInput,
+  }) {
+    final fixedInputStr = jsonEncode(fixedInput);
+
+    final result = executeSync(
+      () => NekotonFlutter.instance().bindings.nt_prepare_call_template(
+            contractAbi.toNativeUtf8().cast<Char>(),
+            method.toNativeUtf8().cast<Char>(),
+            fixedInputStr.toNativeUtf8().cast<Char>(),
+          ),
+    );
+
+    return CallTemplate._(Pointer.fromAddress(result as int).cast<Void>());
+  }
+
+  Pointer<Void> get ptr => _ptr;
+
+  String encode(TokensObject variableInput) {
+    final variableInputStr = jsonEncode(variableInput);
+
+    final result = executeSync(
+      () => NekotonFlutter.instance().bindings.nt_encode_from_template(
+            ptr,
+            variableInputStr.toNativeUtf8().cast<Char>(),
+          ),
+    );
+
+    return result as String;
+  }
+}
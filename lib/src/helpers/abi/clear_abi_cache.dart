@@ -0,0 +1,6 @@
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+void clearAbiCache() => executeSync(
+      () => NekotonFlutter.instance().bindings.nt_clear_abi_cache(),
+    );
@@ -3,16 +3,19 @@ import 'dart:ffi';
 
 import 'package:ffi/ffi.dart';
 import 'package:nekoton_flutter/src/bindings.dart';
-import 'package:nekoton_flutter/src/crypto/models/signed_message.dart';
 import 'package:nekoton_flutter/src/ffi_utils.dart';
 import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/unsigned_external_message.dart';
 
-SignedMessage createExternalMessageWithoutSignature({
+/// [UnsignedExternalMessage.dataHash] and [UnsignedExternalMessage.layout] are null for ABI 1.x
+/// and 2.3, which don't keep the signature in front of the signed data
+UnsignedExternalMessage createExternalMessageWithoutSignature({
   required String dst,
   required String contractAbi,
   required String method,
   String? stateInit,
   required TokensObject input,
+  String? publicKey,
   required int timeout,
 }) {
   final inputStr = jsonEncode(input);
@@ -24,12 +27,13 @@ SignedMessage createExternalMessageWithoutSignature({
           method.toNativeUtf8().cast<Char>(),
           stateInit?.toNativeUtf8().cast<Char>() ?? nullptr,
           inputStr.toNativeUtf8().cast<Char>(),
+          publicKey?.toNativeUtf8().cast<Char>() ?? nullptr,
           timeout,
         ),
   );
 
   final json = result as Map<String, dynamic>;
-  final signedMessage = SignedMessage.fromJson(json);
+  final unsignedExternalMessage = UnsignedExternalMessage.fromJson(json);
 
-  return signedMessage;
+  return unsignedExternalMessage;
 }
@@ -0,0 +1,29 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/crypto/models/signed_message.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+SignedMessage createSignedExternalMessage({
+  required String dst,
+  String? stateInit,
+  required String body,
+  required String signature,
+  required int expireAt,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_create_signed_external_message(
+          dst.toNativeUtf8().cast<Char>(),
+          stateInit?.toNativeUtf8().cast<Char>() ?? nullptr,
+          body.toNativeUtf8().cast<Char>(),
+          signature.toNativeUtf8().cast<Char>(),
+          expireAt,
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final signedMessage = SignedMessage.fromJson(json);
+
+  return signedMessage;
+}
@@ -11,6 +11,7 @@ DecodedEvent? decodeEvent({
   required String messageBody,
   required String contractAbi,
   required MethodName event,
+  bool strict = false,
 }) {
   final eventStr = jsonEncode(event);
 
@@ -19,11 +20,12 @@ DecodedEvent? decodeEvent({
           messageBody.toNativeUtf8().cast<Char>(),
           contractAbi.toNativeUtf8().cast<Char>(),
           eventStr.toNativeUtf8().cast<Char>(),
+          strict ? 1 : 0,
         ),
   );
 
-  final json = result != null ? result as Map<String, dynamic> : null;
-  final decodedEvent = json != null ? DecodedEvent.fromJson(json) : null;
+  final json = result as Map<String, dynamic>;
+  final decodedEvent = json['matched'] == true ? DecodedEvent.fromJson(json) : null;
 
   return decodedEvent;
 }
@@ -12,6 +12,7 @@ DecodedInput? decodeInput({
   required String contractAbi,
   required MethodName method,
   required bool internal,
+  bool strict = false,
 }) {
   final methodStr = jsonEncode(method);
 
@@ -21,11 +22,13 @@ DecodedInput? decodeInput({
           contractAbi.toNativeUtf8().cast<Char>(),
           methodStr.toNativeUtf8().cast<Char>(),
           internal ? 1 : 0,
+          0,
+          strict ? 1 : 0,
         ),
   );
 
-  final json = result != null ? result as Map<String, dynamic> : null;
-  final decodedInput = json != null ? DecodedInput.fromJson(json) : null;
+  final json = result as Map<String, dynamic>;
+  final decodedInput = json['matched'] == true ? DecodedInput.fromJson(json) : null;
 
   return decodedInput;
 }
@@ -0,0 +1,25 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/decoded_message_body.dart';
+
+DecodedMessageBody? decodeMessageBody({
+  required String messageBody,
+  required String contractAbi,
+  required bool internal,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_decode_message_body(
+          messageBody.toNativeUtf8().cast<Char>(),
+          contractAbi.toNativeUtf8().cast<Char>(),
+          internal ? 1 : 0,
+        ),
+  );
+
+  final json = result != null ? result as Map<String, dynamic> : null;
+  final decodedMessageBody = json != null ? DecodedMessageBody.fromJson(json) : null;
+
+  return decodedMessageBody;
+}
@@ -11,6 +11,7 @@ DecodedOutput? decodeOutput({
   required String messageBody,
   required String contractAbi,
   required MethodName method,
+  bool strict = false,
 }) {
   final methodStr = jsonEncode(method);
 
@@ -19,11 +20,12 @@ DecodedOutput? decodeOutput({
           messageBody.toNativeUtf8().cast<Char>(),
           contractAbi.toNativeUtf8().cast<Char>(),
           methodStr.toNativeUtf8().cast<Char>(),
+          strict ? 1 : 0,
         ),
   );
 
-  final json = result != null ? result as Map<String, dynamic> : null;
-  final decodedOutput = json != null ? DecodedOutput.fromJson(json) : null;
+  final json = result as Map<String, dynamic>;
+  final decodedOutput = json['matched'] == true ? DecodedOutput.fromJson(json) : null;
 
   return decodedOutput;
 }
@@ -0,0 +1,32 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/core/models/transaction.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/safe_multisig_transaction.dart';
+
+/// Combines the [submitTransaction] call with its [confirmTransactions] into a single multisig
+/// transaction
+SafeMultisigTransaction decodeSafeMultisigTransaction({
+  required Transaction submitTransaction,
+  required List<Transaction> confirmTransactions,
+  required String contractAbi,
+}) {
+  final submitTransactionStr = jsonEncode(submitTransaction);
+  final confirmTransactionsStr = jsonEncode(confirmTransactions);
+
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_decode_safe_multisig_transaction(
+          submitTransactionStr.toNativeUtf8().cast<Char>(),
+          confirmTransactionsStr.toNativeUtf8().cast<Char>(),
+          contractAbi.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final transaction = SafeMultisigTransaction.fromJson(json);
+
+  return transaction;
+}
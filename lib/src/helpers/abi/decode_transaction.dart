@@ -12,6 +12,7 @@ DecodedTransaction? decodeTransaction({
   required Transaction transaction,
   required String contractAbi,
   required MethodName method,
+  bool strict = false,
 }) {
   final transactionStr = jsonEncode(transaction);
   final methodStr = jsonEncode(method);
@@ -21,6 +22,7 @@ DecodedTransaction? decodeTransaction({
           transactionStr.toNativeUtf8().cast<Char>(),
           contractAbi.toNativeUtf8().cast<Char>(),
           methodStr.toNativeUtf8().cast<Char>(),
+          strict ? 1 : 0,
         ),
   );
 
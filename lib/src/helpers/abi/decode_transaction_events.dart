@@ -5,11 +5,12 @@ import 'package:ffi/ffi.dart';
 import 'package:nekoton_flutter/src/bindings.dart';
 import 'package:nekoton_flutter/src/core/models/transaction.dart';
 import 'package:nekoton_flutter/src/ffi_utils.dart';
-import 'package:nekoton_flutter/src/helpers/abi/models/decoded_transaction_event.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/decoded_transaction_event_result.dart';
 
-List<DecodedTransactionEvent> decodeTransactionEvents({
+List<DecodedTransactionEventResult> decodeTransactionEvents({
   required Transaction transaction,
   required String contractAbi,
+  String? abiVersion,
 }) {
   final transactionStr = jsonEncode(transaction);
 
@@ -17,12 +18,14 @@ List<DecodedTransactionEvent> decodeTransactionEvents({
     () => NekotonFlutter.instance().bindings.nt_decode_transaction_events(
           transactionStr.toNativeUtf8().cast<Char>(),
           contractAbi.toNativeUtf8().cast<Char>(),
+          abiVersion?.toNativeUtf8().cast<Char>() ?? nullptr,
         ),
   );
 
   final json = result as List<dynamic>;
   final list = json.cast<Map<String, dynamic>>();
-  final decodedTransactionEvents = list.map((e) => DecodedTransactionEvent.fromJson(e)).toList();
+  final decodedTransactionEvents =
+      list.map((e) => DecodedTransactionEventResult.fromJson(e)).toList();
 
   return decodedTransactionEvents;
 }
@@ -0,0 +1,31 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/core/models/transaction.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/decoded_transaction_with_state.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/method_name.dart';
+
+DecodedTransactionWithState? decodeTransactionWithState({
+  required Transaction transaction,
+  required String contractAbi,
+  required MethodName method,
+}) {
+  final transactionStr = jsonEncode(transaction);
+  final methodStr = jsonEncode(method);
+
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_decode_transaction_with_state(
+          transactionStr.toNativeUtf8().cast<Char>(),
+          contractAbi.toNativeUtf8().cast<Char>(),
+          methodStr.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result != null ? result as Map<String, dynamic> : null;
+  final decodedTransaction = json != null ? DecodedTransactionWithState.fromJson(json) : null;
+
+  return decodedTransaction;
+}
@@ -0,0 +1,34 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/encoded_external_input.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+
+/// Encodes an unsigned external body with the header expiring after [timeout] seconds
+EncodedExternalInput encodeExternalInput({
+  required String contractAbi,
+  required String method,
+  required TokensObject input,
+  String? publicKey,
+  required int timeout,
+}) {
+  final inputStr = jsonEncode(input);
+
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_encode_external_input(
+          contractAbi.toNativeUtf8().cast<Char>(),
+          method.toNativeUtf8().cast<Char>(),
+          inputStr.toNativeUtf8().cast<Char>(),
+          publicKey?.toNativeUtf8().cast<Char>() ?? nullptr,
+          timeout,
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final encodedExternalInput = EncodedExternalInput.fromJson(json);
+
+  return encodedExternalInput;
+}
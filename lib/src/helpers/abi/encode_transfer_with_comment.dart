@@ -0,0 +1,31 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+/// Encodes the `transfer` call of a token wallet described by [contractAbi] with [comment]
+/// as its payload. Change is sent to [remainingGasTo]
+String encodeTransferWithComment({
+  required String contractAbi,
+  required String recipient,
+  required String amount,
+  required String deployWalletValue,
+  required String remainingGasTo,
+  required bool notify,
+  required String comment,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_encode_transfer_with_comment(
+          contractAbi.toNativeUtf8().cast<Char>(),
+          recipient.toNativeUtf8().cast<Char>(),
+          amount.toNativeUtf8().cast<Char>(),
+          deployWalletValue.toNativeUtf8().cast<Char>(),
+          remainingGasTo.toNativeUtf8().cast<Char>(),
+          notify ? 1 : 0,
+          comment.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  return result as String;
+}
@@ -0,0 +1,31 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/local_transaction_execution.dart';
+
+/// Executes [messageBoc] on top of [accountStuffBoc], which is null for a missing account.
+/// [utime] and [lt] default to the current time and the next lt of the account
+LocalTransactionExecution executeTransactionLocally({
+  String? accountStuffBoc,
+  required String messageBoc,
+  required String configBoc,
+  int? utime,
+  int? lt,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_execute_transaction_locally(
+          accountStuffBoc?.toNativeUtf8().cast<Char>() ?? nullptr,
+          messageBoc.toNativeUtf8().cast<Char>(),
+          configBoc.toNativeUtf8().cast<Char>(),
+          utime ?? 0,
+          lt ?? 0,
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final execution = LocalTransactionExecution.fromJson(json);
+
+  return execution;
+}
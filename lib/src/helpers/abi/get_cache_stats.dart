@@ -0,0 +1,14 @@
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/cache_stats_output.dart';
+
+CacheStatsOutput getCacheStats() {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_cache_stats(),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final cacheStats = CacheStatsOutput.fromJson(json);
+
+  return cacheStats;
+}
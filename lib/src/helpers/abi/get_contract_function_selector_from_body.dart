@@ -0,0 +1,16 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+/// First 32 bits of [body] as `0x` prefixed hex, null if the body is shorter
+String? getContractFunctionSelectorFromBody(String body) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_contract_function_selector_from_body(
+          body.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  return result as String?;
+}
@@ -4,16 +4,17 @@ import 'dart:ffi';
 import 'package:ffi/ffi.dart';
 import 'package:nekoton_flutter/src/bindings.dart';
 import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/expected_address.dart';
 import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
 
-String getExpectedAddress({
+ExpectedAddress getExpectedAddress({
   required String tvc,
   required String contractAbi,
   required int workchainId,
   String? publicKey,
-  required TokensObject initData,
+  TokensObject? initData,
 }) {
-  final initDataStr = jsonEncode(initData);
+  final initDataStr = initData != null ? jsonEncode(initData) : null;
 
   final result = executeSync(
     () => NekotonFlutter.instance().bindings.nt_get_expected_address(
@@ -21,9 +22,12 @@ String getExpectedAddress({
           contractAbi.toNativeUtf8().cast<Char>(),
           workchainId,
           publicKey?.toNativeUtf8().cast<Char>() ?? nullptr,
-          initDataStr.toNativeUtf8().cast<Char>(),
+          initDataStr?.toNativeUtf8().cast<Char>() ?? nullptr,
         ),
   );
 
-  return result as String;
+  final json = result as Map<String, dynamic>;
+  final expectedAddress = ExpectedAddress.fromJson(json);
+
+  return expectedAddress;
 }
@@ -0,0 +1,23 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/function_ids.dart';
+
+FunctionIds getFunctionId({
+  required String contractAbi,
+  required String method,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_function_id(
+          contractAbi.toNativeUtf8().cast<Char>(),
+          method.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final functionIds = FunctionIds.fromJson(json);
+
+  return functionIds;
+}
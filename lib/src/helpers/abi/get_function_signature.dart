@@ -0,0 +1,35 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+/// Input id of [method] as `0x` prefixed hex
+String getFunctionSignature({
+  required String contractAbi,
+  required String method,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_function_signature(
+          contractAbi.toNativeUtf8().cast<Char>(),
+          method.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  return result as String;
+}
+
+/// Id of [event] as `0x` prefixed hex
+String getEventSignature({
+  required String contractAbi,
+  required String event,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_event_signature(
+          contractAbi.toNativeUtf8().cast<Char>(),
+          event.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  return result as String;
+}
@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'cache_stats.freezed.dart';
+part 'cache_stats.g.dart';
+
+@freezed
+class CacheStats with _$CacheStats {
+  const factory CacheStats({
+    required int entries,
+    required int hits,
+    required int misses,
+  }) = _CacheStats;
+
+  factory CacheStats.fromJson(Map<String, dynamic> json) => _$CacheStatsFromJson(json);
+}
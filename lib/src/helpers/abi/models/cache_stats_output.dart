@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/cache_stats.dart';
+
+part 'cache_stats_output.freezed.dart';
+part 'cache_stats_output.g.dart';
+
+@freezed
+class CacheStatsOutput with _$CacheStatsOutput {
+  const factory CacheStatsOutput({
+    required CacheStats abi,
+    required CacheStats getters,
+  }) = _CacheStatsOutput;
+
+  factory CacheStatsOutput.fromJson(Map<String, dynamic> json) => _$CacheStatsOutputFromJson(json);
+}
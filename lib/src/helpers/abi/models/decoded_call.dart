@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+
+part 'decoded_call.freezed.dart';
+part 'decoded_call.g.dart';
+
+@freezed
+class DecodedCall with _$DecodedCall {
+  const factory DecodedCall({
+    required String method,
+    required TokensObject input,
+  }) = _DecodedCall;
+
+  factory DecodedCall.fromJson(Map<String, dynamic> json) => _$DecodedCallFromJson(json);
+}
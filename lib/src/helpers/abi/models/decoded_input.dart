@@ -9,6 +9,9 @@ class DecodedInput with _$DecodedInput {
   const factory DecodedInput({
     required String method,
     required TokensObject input,
+    @Default(0) int trailingBits,
+    @Default(0) int trailingRefs,
+    @Default(<String, String>{}) Map<String, String> valueTypes,
   }) = _DecodedInput;
 
   factory DecodedInput.fromJson(Map<String, dynamic> json) => _$DecodedInputFromJson(json);
@@ -0,0 +1,26 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+
+part 'decoded_message_body.freezed.dart';
+part 'decoded_message_body.g.dart';
+
+@Freezed(unionKey: 'kind')
+class DecodedMessageBody with _$DecodedMessageBody {
+  const factory DecodedMessageBody.input({
+    required String method,
+    required TokensObject input,
+  }) = _DecodedMessageBodyInput;
+
+  const factory DecodedMessageBody.output({
+    required String method,
+    required TokensObject output,
+  }) = _DecodedMessageBodyOutput;
+
+  const factory DecodedMessageBody.event({
+    required String event,
+    required TokensObject data,
+  }) = _DecodedMessageBodyEvent;
+
+  factory DecodedMessageBody.fromJson(Map<String, dynamic> json) =>
+      _$DecodedMessageBodyFromJson(json);
+}
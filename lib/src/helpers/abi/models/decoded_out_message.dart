@@ -0,0 +1,19 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/decoded_out_message_body.dart';
+
+part 'decoded_out_message.freezed.dart';
+part 'decoded_out_message.g.dart';
+
+@freezed
+class DecodedOutMessage with _$DecodedOutMessage {
+  const factory DecodedOutMessage({
+    String? dst,
+    required String value,
+    required bool bounce,
+    String? bodyFunctionId,
+    DecodedOutMessageBody? decoded,
+  }) = _DecodedOutMessage;
+
+  factory DecodedOutMessage.fromJson(Map<String, dynamic> json) =>
+      _$DecodedOutMessageFromJson(json);
+}
@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/decoded_call.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/decoded_event.dart';
+
+part 'decoded_out_message_body.freezed.dart';
+part 'decoded_out_message_body.g.dart';
+
+@Freezed(unionKey: 'type')
+class DecodedOutMessageBody with _$DecodedOutMessageBody {
+  const factory DecodedOutMessageBody.call(DecodedCall data) = _DecodedOutMessageBodyCall;
+
+  const factory DecodedOutMessageBody.event(DecodedEvent data) = _DecodedOutMessageBodyEvent;
+
+  factory DecodedOutMessageBody.fromJson(Map<String, dynamic> json) =>
+      _$DecodedOutMessageBodyFromJson(json);
+}
@@ -1,5 +1,7 @@
 import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/decoded_out_message.dart';
 import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/transaction_direction.dart';
 
 part 'decoded_transaction.freezed.dart';
 part 'decoded_transaction.g.dart';
@@ -10,6 +12,10 @@ class DecodedTransaction with _$DecodedTransaction {
     required String method,
     required TokensObject input,
     required TokensObject output,
+    String? inMsgValue,
+    String? inMsgSender,
+    required TransactionDirection direction,
+    required List<DecodedOutMessage> outMessages,
   }) = _DecodedTransaction;
 
   factory DecodedTransaction.fromJson(Map<String, dynamic> json) =>
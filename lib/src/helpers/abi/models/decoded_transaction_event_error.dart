@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'decoded_transaction_event_error.freezed.dart';
+part 'decoded_transaction_event_error.g.dart';
+
+@freezed
+class DecodedTransactionEventError with _$DecodedTransactionEventError {
+  const factory DecodedTransactionEventError({
+    required String eventName,
+    required String error,
+  }) = _DecodedTransactionEventError;
+
+  factory DecodedTransactionEventError.fromJson(Map<String, dynamic> json) =>
+      _$DecodedTransactionEventErrorFromJson(json);
+}
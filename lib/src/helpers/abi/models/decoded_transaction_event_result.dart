@@ -0,0 +1,18 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/decoded_transaction_event.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/decoded_transaction_event_error.dart';
+
+part 'decoded_transaction_event_result.freezed.dart';
+part 'decoded_transaction_event_result.g.dart';
+
+@Freezed(unionKey: 'type')
+class DecodedTransactionEventResult with _$DecodedTransactionEventResult {
+  const factory DecodedTransactionEventResult.decoded(DecodedTransactionEvent data) =
+      _DecodedTransactionEventResultDecoded;
+
+  const factory DecodedTransactionEventResult.error(DecodedTransactionEventError data) =
+      _DecodedTransactionEventResultError;
+
+  factory DecodedTransactionEventResult.fromJson(Map<String, dynamic> json) =>
+      _$DecodedTransactionEventResultFromJson(json);
+}
@@ -0,0 +1,27 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/decoded_out_message.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/transaction_direction.dart';
+
+part 'decoded_transaction_with_state.freezed.dart';
+part 'decoded_transaction_with_state.g.dart';
+
+@freezed
+class DecodedTransactionWithState with _$DecodedTransactionWithState {
+  const factory DecodedTransactionWithState({
+    required String method,
+    required TokensObject input,
+    required TokensObject output,
+    String? inMsgValue,
+    String? inMsgSender,
+    required TransactionDirection direction,
+    required List<DecodedOutMessage> outMessages,
+    required String totalFees,
+    required bool aborted,
+    required bool destroyed,
+    int? actionResultCode,
+  }) = _DecodedTransactionWithState;
+
+  factory DecodedTransactionWithState.fromJson(Map<String, dynamic> json) =>
+      _$DecodedTransactionWithStateFromJson(json);
+}
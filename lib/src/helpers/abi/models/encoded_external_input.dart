@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'encoded_external_input.freezed.dart';
+part 'encoded_external_input.g.dart';
+
+@freezed
+class EncodedExternalInput with _$EncodedExternalInput {
+  const factory EncodedExternalInput({
+    required String body,
+    required int expireAt,
+  }) = _EncodedExternalInput;
+
+  factory EncodedExternalInput.fromJson(Map<String, dynamic> json) =>
+      _$EncodedExternalInputFromJson(json);
+}
@@ -0,0 +1,18 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'executed_transaction.freezed.dart';
+part 'executed_transaction.g.dart';
+
+/// [transaction] and the updated [account] are BOCs
+@freezed
+class ExecutedTransaction with _$ExecutedTransaction {
+  const factory ExecutedTransaction({
+    required String transaction,
+    required String account,
+    int? exitCode,
+    required bool aborted,
+  }) = _ExecutedTransaction;
+
+  factory ExecutedTransaction.fromJson(Map<String, dynamic> json) =>
+      _$ExecutedTransactionFromJson(json);
+}
@@ -0,0 +1,14 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'execution_context.freezed.dart';
+part 'execution_context.g.dart';
+
+@freezed
+class ExecutionContext with _$ExecutionContext {
+  const factory ExecutionContext({
+    required int now,
+    required String blockLt,
+  }) = _ExecutionContext;
+
+  factory ExecutionContext.fromJson(Map<String, dynamic> json) => _$ExecutionContextFromJson(json);
+}
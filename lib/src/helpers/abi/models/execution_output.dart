@@ -1,4 +1,5 @@
 import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/execution_context.dart';
 import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
 
 part 'execution_output.freezed.dart';
@@ -9,6 +10,10 @@ class ExecutionOutput with _$ExecutionOutput {
   const factory ExecutionOutput({
     @JsonKey(includeIfNull: false) TokensObject? output,
     required int code,
+    /// Only measured by the local VM, `null` for calls made without a blockchain config,
+    /// batch runs and cached results
+    @JsonKey(includeIfNull: false) int? gasUsed,
+    ExecutionContext? context,
   }) = _ExecutionOutput;
 
   factory ExecutionOutput.fromJson(Map<String, dynamic> json) => _$ExecutionOutputFromJson(json);
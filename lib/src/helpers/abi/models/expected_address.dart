@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'expected_address.freezed.dart';
+part 'expected_address.g.dart';
+
+@freezed
+class ExpectedAddress with _$ExpectedAddress {
+  const factory ExpectedAddress({
+    required String address,
+    required String stateInit,
+    required String hash,
+  }) = _ExpectedAddress;
+
+  factory ExpectedAddress.fromJson(Map<String, dynamic> json) => _$ExpectedAddressFromJson(json);
+}
@@ -0,0 +1,14 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'function_ids.freezed.dart';
+part 'function_ids.g.dart';
+
+@freezed
+class FunctionIds with _$FunctionIds {
+  const factory FunctionIds({
+    required int inputId,
+    required int outputId,
+  }) = _FunctionIds;
+
+  factory FunctionIds.fromJson(Map<String, dynamic> json) => _$FunctionIdsFromJson(json);
+}
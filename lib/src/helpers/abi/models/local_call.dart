@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+
+part 'local_call.freezed.dart';
+part 'local_call.g.dart';
+
+@freezed
+class LocalCall with _$LocalCall {
+  const factory LocalCall({
+    required String method,
+    required TokensObject input,
+    @Default(false) bool responsible,
+  }) = _LocalCall;
+
+  factory LocalCall.fromJson(Map<String, dynamic> json) => _$LocalCallFromJson(json);
+}
@@ -0,0 +1,24 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/executed_transaction.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/not_accepted_message.dart';
+
+part 'local_transaction_execution.freezed.dart';
+part 'local_transaction_execution.g.dart';
+
+@Freezed(unionKey: 'type')
+class LocalTransactionExecution with _$LocalTransactionExecution {
+  const factory LocalTransactionExecution.executed(ExecutedTransaction data) =
+      _LocalTransactionExecutionExecuted;
+
+  const factory LocalTransactionExecution.notAccepted(NotAcceptedMessage data) =
+      _LocalTransactionExecutionNotAccepted;
+
+  const factory LocalTransactionExecution.accountNotExists() =
+      _LocalTransactionExecutionAccountNotExists;
+
+  const factory LocalTransactionExecution.accountFrozen() =
+      _LocalTransactionExecutionAccountFrozen;
+
+  factory LocalTransactionExecution.fromJson(Map<String, dynamic> json) =>
+      _$LocalTransactionExecutionFromJson(json);
+}
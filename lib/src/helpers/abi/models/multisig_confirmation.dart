@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'multisig_confirmation.freezed.dart';
+part 'multisig_confirmation.g.dart';
+
+@freezed
+class MultisigConfirmation with _$MultisigConfirmation {
+  const factory MultisigConfirmation({
+    String? transactionId,
+    String? signerPublicKey,
+  }) = _MultisigConfirmation;
+
+  factory MultisigConfirmation.fromJson(Map<String, dynamic> json) =>
+      _$MultisigConfirmationFromJson(json);
+}
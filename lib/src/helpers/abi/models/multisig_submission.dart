@@ -0,0 +1,20 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'multisig_submission.freezed.dart';
+part 'multisig_submission.g.dart';
+
+/// Fields are null if the multisig ABI doesn't have them
+@freezed
+class MultisigSubmission with _$MultisigSubmission {
+  const factory MultisigSubmission({
+    String? transactionId,
+    String? dest,
+    String? value,
+    bool? bounce,
+    bool? allBalance,
+    String? payload,
+  }) = _MultisigSubmission;
+
+  factory MultisigSubmission.fromJson(Map<String, dynamic> json) =>
+      _$MultisigSubmissionFromJson(json);
+}
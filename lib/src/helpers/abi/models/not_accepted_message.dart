@@ -0,0 +1,14 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'not_accepted_message.freezed.dart';
+part 'not_accepted_message.g.dart';
+
+@freezed
+class NotAcceptedMessage with _$NotAcceptedMessage {
+  const factory NotAcceptedMessage({
+    required int exitCode,
+  }) = _NotAcceptedMessage;
+
+  factory NotAcceptedMessage.fromJson(Map<String, dynamic> json) =>
+      _$NotAcceptedMessageFromJson(json);
+}
@@ -0,0 +1,10 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+enum PublicKeyRejection {
+  @JsonValue('notHex')
+  notHex,
+  @JsonValue('wrongLength')
+  wrongLength,
+  @JsonValue('invalidCurvePoint')
+  invalidCurvePoint,
+}
@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/public_key_rejection.dart';
+
+part 'public_key_validation.freezed.dart';
+part 'public_key_validation.g.dart';
+
+@freezed
+class PublicKeyValidation with _$PublicKeyValidation {
+  const factory PublicKeyValidation({
+    required bool valid,
+    PublicKeyRejection? reason,
+  }) = _PublicKeyValidation;
+
+  factory PublicKeyValidation.fromJson(Map<String, dynamic> json) =>
+      _$PublicKeyValidationFromJson(json);
+}
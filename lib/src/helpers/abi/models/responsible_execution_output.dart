@@ -0,0 +1,21 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/execution_context.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+
+part 'responsible_execution_output.freezed.dart';
+part 'responsible_execution_output.g.dart';
+
+/// [responsibleValue] is the amount attached to the answer message, null if none was produced
+@freezed
+class ResponsibleExecutionOutput with _$ResponsibleExecutionOutput {
+  const factory ResponsibleExecutionOutput({
+    @JsonKey(includeIfNull: false) TokensObject? output,
+    required int code,
+    @JsonKey(includeIfNull: false) int? gasUsed,
+    ExecutionContext? context,
+    String? responsibleValue,
+  }) = _ResponsibleExecutionOutput;
+
+  factory ResponsibleExecutionOutput.fromJson(Map<String, dynamic> json) =>
+      _$ResponsibleExecutionOutputFromJson(json);
+}
@@ -0,0 +1,18 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/multisig_confirmation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/multisig_submission.dart';
+
+part 'safe_multisig_transaction.freezed.dart';
+part 'safe_multisig_transaction.g.dart';
+
+@freezed
+class SafeMultisigTransaction with _$SafeMultisigTransaction {
+  const factory SafeMultisigTransaction({
+    required MultisigSubmission submission,
+    required List<MultisigConfirmation> confirmations,
+    required bool executed,
+  }) = _SafeMultisigTransaction;
+
+  factory SafeMultisigTransaction.fromJson(Map<String, dynamic> json) =>
+      _$SafeMultisigTransactionFromJson(json);
+}
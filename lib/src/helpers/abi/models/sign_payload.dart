@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'sign_payload.freezed.dart';
+part 'sign_payload.g.dart';
+
+@freezed
+class SignPayload with _$SignPayload {
+  const factory SignPayload({
+    required String hash,
+    required String body,
+    required int time,
+    required int expireAt,
+  }) = _SignPayload;
+
+  factory SignPayload.fromJson(Map<String, dynamic> json) => _$SignPayloadFromJson(json);
+}
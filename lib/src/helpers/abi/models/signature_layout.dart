@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'signature_layout.freezed.dart';
+part 'signature_layout.g.dart';
+
+@freezed
+class SignatureLayout with _$SignatureLayout {
+  const factory SignatureLayout({
+    required int signatureOffset,
+    required int signatureLength,
+    int? publicKeyOffset,
+    int? publicKeyLength,
+  }) = _SignatureLayout;
+
+  factory SignatureLayout.fromJson(Map<String, dynamic> json) => _$SignatureLayoutFromJson(json);
+}
@@ -0,0 +1,18 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'simulated_transaction.freezed.dart';
+part 'simulated_transaction.g.dart';
+
+@freezed
+class SimulatedTransaction with _$SimulatedTransaction {
+  const factory SimulatedTransaction({
+    int? exitCode,
+    required bool aborted,
+    required String fees,
+    required String newAccountBoc,
+    required List<String> outMessages,
+  }) = _SimulatedTransaction;
+
+  factory SimulatedTransaction.fromJson(Map<String, dynamic> json) =>
+      _$SimulatedTransactionFromJson(json);
+}
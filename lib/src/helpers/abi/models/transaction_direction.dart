@@ -0,0 +1,10 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+enum TransactionDirection {
+  @JsonValue('in')
+  incoming,
+  @JsonValue('out')
+  outgoing,
+  @JsonValue('self')
+  self,
+}
@@ -0,0 +1,17 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+
+part 'unpacked_cell.freezed.dart';
+part 'unpacked_cell.g.dart';
+
+@freezed
+class UnpackedCell with _$UnpackedCell {
+  const factory UnpackedCell({
+    required TokensObject tokens,
+    required int bitsConsumed,
+    required int refsConsumed,
+    required int cellDepth,
+  }) = _UnpackedCell;
+
+  factory UnpackedCell.fromJson(Map<String, dynamic> json) => _$UnpackedCellFromJson(json);
+}
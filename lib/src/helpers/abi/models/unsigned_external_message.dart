@@ -0,0 +1,19 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/signature_layout.dart';
+
+part 'unsigned_external_message.freezed.dart';
+part 'unsigned_external_message.g.dart';
+
+@freezed
+class UnsignedExternalMessage with _$UnsignedExternalMessage {
+  const factory UnsignedExternalMessage({
+    required String hash,
+    required int expireAt,
+    required String boc,
+    String? dataHash,
+    SignatureLayout? layout,
+  }) = _UnsignedExternalMessage;
+
+  factory UnsignedExternalMessage.fromJson(Map<String, dynamic> json) =>
+      _$UnsignedExternalMessageFromJson(json);
+}
@@ -0,0 +1,33 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/sign_payload.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+
+SignPayload prepareSignPayload({
+  required String contractAbi,
+  required String method,
+  required TokensObject input,
+  required String publicKey,
+  required int timeout,
+}) {
+  final inputStr = jsonEncode(input);
+
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_prepare_sign_payload(
+          contractAbi.toNativeUtf8().cast<Char>(),
+          method.toNativeUtf8().cast<Char>(),
+          inputStr.toNativeUtf8().cast<Char>(),
+          publicKey.toNativeUtf8().cast<Char>(),
+          timeout,
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final signPayload = SignPayload.fromJson(json);
+
+  return signPayload;
+}
@@ -7,14 +7,25 @@ import 'package:nekoton_flutter/src/ffi_utils.dart';
 import 'package:nekoton_flutter/src/helpers/abi/models/execution_output.dart';
 import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
 
+/// [libraries] maps hex hashes of library cells to their BOCs. Libraries and [lt] are run by
+/// the local VM which then needs [blockchainConfigBoc] of the network. The local VM is also used
+/// whenever [blockchainConfigBoc] is passed and is the only one reporting gas used.
+/// Results are reused for the same account state unless [noCache] is set, which getters depending
+/// on the current time need
 ExecutionOutput runLocal({
   required String accountStuffBoc,
   required String contractAbi,
   required String method,
   required TokensObject input,
   required bool responsible,
+  Map<String, String>? libraries,
+  String? blockchainConfigBoc,
+  int? timestamp,
+  int? lt,
+  bool noCache = false,
 }) {
   final inputStr = jsonEncode(input);
+  final librariesStr = libraries != null ? jsonEncode(libraries) : null;
 
   final result = executeSync(
     () => NekotonFlutter.instance().bindings.nt_run_local(
@@ -23,6 +34,11 @@ ExecutionOutput runLocal({
           method.toNativeUtf8().cast<Char>(),
           inputStr.toNativeUtf8().cast<Char>(),
           responsible ? 1 : 0,
+          librariesStr?.toNativeUtf8().cast<Char>() ?? nullptr,
+          blockchainConfigBoc?.toNativeUtf8().cast<Char>() ?? nullptr,
+          timestamp ?? 0,
+          lt ?? 0,
+          noCache ? 1 : 0,
         ),
   );
 
@@ -0,0 +1,35 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/responsible_execution_output.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+
+/// Runs a responsible [method] and reports the value attached to its answer. Gas is charged by
+/// [blockchainConfigBoc] of the network when it's passed, by the default config otherwise
+ResponsibleExecutionOutput runLocalResponsibleValue({
+  required String accountStuffBoc,
+  required String contractAbi,
+  required String method,
+  required TokensObject input,
+  String? blockchainConfigBoc,
+}) {
+  final inputStr = jsonEncode(input);
+
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_run_local_responsible_value(
+          accountStuffBoc.toNativeUtf8().cast<Char>(),
+          contractAbi.toNativeUtf8().cast<Char>(),
+          method.toNativeUtf8().cast<Char>(),
+          inputStr.toNativeUtf8().cast<Char>(),
+          blockchainConfigBoc?.toNativeUtf8().cast<Char>() ?? nullptr,
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final output = ResponsibleExecutionOutput.fromJson(json);
+
+  return output;
+}
@@ -0,0 +1,40 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/execution_output.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
+
+/// Runs [method] with the local VM and the network config, [libraries] maps hex hashes of
+/// library cells to their BOCs
+ExecutionOutput runLocalWithConfig({
+  required String accountStuffBoc,
+  required String contractAbi,
+  required String method,
+  required TokensObject input,
+  required bool responsible,
+  required String blockchainConfigBoc,
+  Map<String, String>? libraries,
+}) {
+  final inputStr = jsonEncode(input);
+  final librariesStr = libraries != null ? jsonEncode(libraries) : null;
+
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_run_local_with_config(
+          accountStuffBoc.toNativeUtf8().cast<Char>(),
+          contractAbi.toNativeUtf8().cast<Char>(),
+          method.toNativeUtf8().cast<Char>(),
+          inputStr.toNativeUtf8().cast<Char>(),
+          responsible ? 1 : 0,
+          blockchainConfigBoc.toNativeUtf8().cast<Char>(),
+          librariesStr?.toNativeUtf8().cast<Char>() ?? nullptr,
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final executionOutput = ExecutionOutput.fromJson(json);
+
+  return executionOutput;
+}
@@ -0,0 +1,25 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/simulated_transaction.dart';
+
+SimulatedTransaction simulateTransaction({
+  required String accountStuffBoc,
+  required String messageBoc,
+  String? configBoc,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_simulate_transaction(
+          accountStuffBoc.toNativeUtf8().cast<Char>(),
+          messageBoc.toNativeUtf8().cast<Char>(),
+          configBoc?.toNativeUtf8().cast<Char>() ?? nullptr,
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final simulatedTransaction = SimulatedTransaction.fromJson(json);
+
+  return simulatedTransaction;
+}
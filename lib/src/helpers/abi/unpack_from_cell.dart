@@ -7,10 +7,13 @@ import 'package:nekoton_flutter/src/ffi_utils.dart';
 import 'package:nekoton_flutter/src/helpers/abi/models/abi_param.dart';
 import 'package:nekoton_flutter/src/helpers/abi/models/tokens_object.dart';
 
+/// [asSlice] skips the function id in front of message body params. [unpackFromCellEx] also
+/// reports how much data was read, e.g. to detect trailing data
 TokensObject unpackFromCell({
   required List<AbiParam> params,
   required String boc,
   required bool allowPartial,
+  bool asSlice = false,
 }) {
   final paramsStr = jsonEncode(params);
 
@@ -19,6 +22,7 @@ TokensObject unpackFromCell({
           paramsStr.toNativeUtf8().cast<Char>(),
           boc.toNativeUtf8().cast<Char>(),
           allowPartial ? 1 : 0,
+          asSlice ? 1 : 0,
         ),
   );
 
@@ -0,0 +1,31 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/abi_param.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/unpacked_cell.dart';
+
+UnpackedCell unpackFromCellEx({
+  required List<AbiParam> params,
+  required String boc,
+  required bool allowPartial,
+  bool asSlice = false,
+}) {
+  final paramsStr = jsonEncode(params);
+
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_unpack_from_cell_ex(
+          paramsStr.toNativeUtf8().cast<Char>(),
+          boc.toNativeUtf8().cast<Char>(),
+          allowPartial ? 1 : 0,
+          asSlice ? 1 : 0,
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final unpackedCell = UnpackedCell.fromJson(json);
+
+  return unpackedCell;
+}
@@ -0,0 +1,20 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/abi/models/public_key_validation.dart';
+
+/// Same as `checkPublicKey` but reports why the key was rejected instead of throwing
+PublicKeyValidation validatePublicKey(String publicKey) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_validate_public_key(
+          publicKey.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final validation = PublicKeyValidation.fromJson(json);
+
+  return validation;
+}
@@ -0,0 +1,19 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/account_visual.dart';
+
+AccountVisual accountVisualSeed(String address) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_account_visual_seed(
+          address.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final accountVisual = AccountVisual.fromJson(json);
+
+  return accountVisual;
+}
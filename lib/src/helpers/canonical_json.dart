@@ -0,0 +1,22 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+/// Makes all results serialized with sorted keys and without whitespace
+void setCanonicalJson(bool enabled) => executeSync(
+      () => NekotonFlutter.instance().bindings.nt_set_canonical_json(enabled ? 1 : 0),
+    );
+
+String canonicalizeJson(String json) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_canonicalize_json(
+          json.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final canonicalJson = result as String;
+
+  return canonicalJson;
+}
@@ -0,0 +1,19 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/checked_address.dart';
+
+CheckedAddress checkAddress(String address) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_check_address(
+          address.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final checkedAddress = CheckedAddress.fromJson(json);
+
+  return checkedAddress;
+}
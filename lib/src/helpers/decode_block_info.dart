@@ -0,0 +1,19 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/block_info.dart';
+
+BlockInfo decodeBlockInfo(String blockBoc) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_decode_block_info(
+          blockBoc.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final blockInfo = BlockInfo.fromJson(json);
+
+  return blockInfo;
+}
@@ -0,0 +1,20 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/jetton_burn_notification.dart';
+
+/// Returns null if [body] isn't a jetton burn notification
+JettonBurnNotification? decodeJettonBurnNotification(String body) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_decode_jetton_burn_notification(
+          body.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>?;
+  final notification = json != null ? JettonBurnNotification.fromJson(json) : null;
+
+  return notification;
+}
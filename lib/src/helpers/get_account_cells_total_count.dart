@@ -0,0 +1,19 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/account_cells_count.dart';
+
+AccountCellsCount getAccountCellsTotalCount(String accountStuffBoc) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_account_cells_total_count(
+          accountStuffBoc.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final accountCellsCount = AccountCellsCount.fromJson(json);
+
+  return accountCellsCount;
+}
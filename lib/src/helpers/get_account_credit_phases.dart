@@ -0,0 +1,19 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/credit_phase.dart';
+
+CreditPhase getAccountCreditPhases(String transactionBoc) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_account_credit_phases(
+          transactionBoc.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final creditPhase = CreditPhase.fromJson(json);
+
+  return creditPhase;
+}
@@ -0,0 +1,19 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/stakes_config.dart';
+
+StakesConfig getConfigParam17(String configBoc) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_config_param_17(
+          configBoc.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final stakesConfig = StakesConfig.fromJson(json);
+
+  return stakesConfig;
+}
@@ -0,0 +1,18 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+List<String> getEverWalletCustodians(String accountStuffBoc) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_ever_wallet_custodians(
+          accountStuffBoc.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as List<dynamic>;
+  final custodians = json.cast<String>();
+
+  return custodians;
+}
@@ -0,0 +1,20 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/message_value.dart';
+
+/// Value and fees of an internal message, throws for external ones
+MessageValue getMessageValue(String messageBoc) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_message_value(
+          messageBoc.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final messageValue = MessageValue.fromJson(json);
+
+  return messageValue;
+}
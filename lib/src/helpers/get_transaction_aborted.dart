@@ -0,0 +1,23 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/core/models/transaction.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/transaction_abort_status.dart';
+
+TransactionAbortStatus getTransactionAborted(Transaction transaction) {
+  final transactionStr = jsonEncode(transaction);
+
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_transaction_aborted(
+          transactionStr.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final transactionAbortStatus = TransactionAbortStatus.fromJson(json);
+
+  return transactionAbortStatus;
+}
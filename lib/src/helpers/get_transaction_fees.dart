@@ -0,0 +1,19 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/transaction_fees.dart';
+
+TransactionFees getTransactionFees(String transactionBoc) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_transaction_fees(
+          transactionBoc.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final transactionFees = TransactionFees.fromJson(json);
+
+  return transactionFees;
+}
@@ -0,0 +1,9 @@
+import 'package:nekoton_flutter/src/bindings.dart';
+
+bool isRuntimeAlive() {
+  final result = NekotonFlutter.instance().bindings.nt_is_runtime_alive();
+
+  final isAlive = result != 0;
+
+  return isAlive;
+}
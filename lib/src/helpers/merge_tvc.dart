@@ -0,0 +1,23 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/merged_tvc.dart';
+
+MergedTvc mergeTvc({
+  String? code,
+  String? data,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_merge_tvc(
+          code?.toNativeUtf8().cast<Char>() ?? nullptr,
+          data?.toNativeUtf8().cast<Char>() ?? nullptr,
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final mergedTvc = MergedTvc.fromJson(json);
+
+  return mergedTvc;
+}
@@ -0,0 +1,8 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+enum AbortedPhase {
+  @JsonValue('compute')
+  compute,
+  @JsonValue('action')
+  action,
+}
@@ -0,0 +1,17 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'account_cells_count.freezed.dart';
+part 'account_cells_count.g.dart';
+
+/// [sharedCells] are referenced more than once in the account state tree
+@freezed
+class AccountCellsCount with _$AccountCellsCount {
+  const factory AccountCellsCount({
+    required int totalCells,
+    required int sharedCells,
+    required int ownCells,
+  }) = _AccountCellsCount;
+
+  factory AccountCellsCount.fromJson(Map<String, dynamic> json) =>
+      _$AccountCellsCountFromJson(json);
+}
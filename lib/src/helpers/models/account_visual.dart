@@ -0,0 +1,17 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/models/hsl_color.dart';
+
+part 'account_visual.freezed.dart';
+part 'account_visual.g.dart';
+
+/// Avatar data of an account, [identicon] is a 5x5 grid of filled cells
+@freezed
+class AccountVisual with _$AccountVisual {
+  const factory AccountVisual({
+    required String seed,
+    required HslColor color,
+    required List<List<bool>> identicon,
+  }) = _AccountVisual;
+
+  factory AccountVisual.fromJson(Map<String, dynamic> json) => _$AccountVisualFromJson(json);
+}
@@ -0,0 +1,22 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/models/block_ref.dart';
+
+part 'block_info.freezed.dart';
+part 'block_info.g.dart';
+
+/// [prevRef] has two entries for a block produced right after a shard merge
+@freezed
+class BlockInfo with _$BlockInfo {
+  const factory BlockInfo({
+    required int seqno,
+    required int workchain,
+    required String shard,
+    required int generatedAt,
+    required int minRefMcSeqno,
+    required List<BlockRef> prevRef,
+    required bool afterMerge,
+    required bool afterSplit,
+  }) = _BlockInfo;
+
+  factory BlockInfo.fromJson(Map<String, dynamic> json) => _$BlockInfoFromJson(json);
+}
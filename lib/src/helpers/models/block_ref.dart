@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'block_ref.freezed.dart';
+part 'block_ref.g.dart';
+
+@freezed
+class BlockRef with _$BlockRef {
+  const factory BlockRef({
+    required int seqno,
+    required String endLt,
+    required String rootHash,
+    required String fileHash,
+  }) = _BlockRef;
+
+  factory BlockRef.fromJson(Map<String, dynamic> json) => _$BlockRefFromJson(json);
+}
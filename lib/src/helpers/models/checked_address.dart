@@ -0,0 +1,17 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'checked_address.freezed.dart';
+part 'checked_address.g.dart';
+
+@freezed
+class CheckedAddress with _$CheckedAddress {
+  const factory CheckedAddress({
+    required bool isValid,
+    String? address,
+    int? workchain,
+    bool? bounceable,
+    String? error,
+  }) = _CheckedAddress;
+
+  factory CheckedAddress.fromJson(Map<String, dynamic> json) => _$CheckedAddressFromJson(json);
+}
@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/models/extra_currency.dart';
+
+part 'credit_phase.freezed.dart';
+part 'credit_phase.g.dart';
+
+@freezed
+class CreditPhase with _$CreditPhase {
+  const factory CreditPhase({
+    required String credit,
+    required List<ExtraCurrency> creditOther,
+    required String dueFeesCollected,
+  }) = _CreditPhase;
+
+  factory CreditPhase.fromJson(Map<String, dynamic> json) => _$CreditPhaseFromJson(json);
+}
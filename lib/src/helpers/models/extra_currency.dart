@@ -0,0 +1,14 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'extra_currency.freezed.dart';
+part 'extra_currency.g.dart';
+
+@freezed
+class ExtraCurrency with _$ExtraCurrency {
+  const factory ExtraCurrency({
+    required int currency,
+    required String value,
+  }) = _ExtraCurrency;
+
+  factory ExtraCurrency.fromJson(Map<String, dynamic> json) => _$ExtraCurrencyFromJson(json);
+}
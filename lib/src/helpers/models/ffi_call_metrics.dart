@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'ffi_call_metrics.freezed.dart';
+part 'ffi_call_metrics.g.dart';
+
+@freezed
+class FfiCallMetrics with _$FfiCallMetrics {
+  const factory FfiCallMetrics({
+    required String name,
+    required int count,
+    required int maxUs,
+    required int avgUs,
+  }) = _FfiCallMetrics;
+
+  factory FfiCallMetrics.fromJson(Map<String, dynamic> json) => _$FfiCallMetricsFromJson(json);
+}
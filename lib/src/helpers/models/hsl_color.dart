@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'hsl_color.freezed.dart';
+part 'hsl_color.g.dart';
+
+@freezed
+class HslColor with _$HslColor {
+  const factory HslColor({
+    required int hue,
+    required int saturation,
+    required int lightness,
+  }) = _HslColor;
+
+  factory HslColor.fromJson(Map<String, dynamic> json) => _$HslColorFromJson(json);
+}
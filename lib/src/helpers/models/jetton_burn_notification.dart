@@ -0,0 +1,17 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'jetton_burn_notification.freezed.dart';
+part 'jetton_burn_notification.g.dart';
+
+@freezed
+class JettonBurnNotification with _$JettonBurnNotification {
+  const factory JettonBurnNotification({
+    required String queryId,
+    required String amount,
+    String? sender,
+    String? responseDestination,
+  }) = _JettonBurnNotification;
+
+  factory JettonBurnNotification.fromJson(Map<String, dynamic> json) =>
+      _$JettonBurnNotificationFromJson(json);
+}
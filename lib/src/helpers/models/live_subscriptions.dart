@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'live_subscriptions.freezed.dart';
+part 'live_subscriptions.g.dart';
+
+@freezed
+class LiveSubscriptions with _$LiveSubscriptions {
+  const factory LiveSubscriptions({
+    required int tonWallet,
+    required int tokenWallet,
+    required int genericContract,
+  }) = _LiveSubscriptions;
+
+  factory LiveSubscriptions.fromJson(Map<String, dynamic> json) =>
+      _$LiveSubscriptionsFromJson(json);
+}
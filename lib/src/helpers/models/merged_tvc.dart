@@ -0,0 +1,14 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'merged_tvc.freezed.dart';
+part 'merged_tvc.g.dart';
+
+@freezed
+class MergedTvc with _$MergedTvc {
+  const factory MergedTvc({
+    required String tvc,
+    required String hash,
+  }) = _MergedTvc;
+
+  factory MergedTvc.fromJson(Map<String, dynamic> json) => _$MergedTvcFromJson(json);
+}
@@ -0,0 +1,10 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+enum MessageBocType {
+  @JsonValue('extIn')
+  extIn,
+  @JsonValue('internal')
+  internal,
+  @JsonValue('extOut')
+  extOut,
+}
@@ -0,0 +1,18 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/models/message_boc_type.dart';
+
+part 'message_boc_validation.freezed.dart';
+part 'message_boc_validation.g.dart';
+
+@freezed
+class MessageBocValidation with _$MessageBocValidation {
+  const factory MessageBocValidation({
+    required bool valid,
+    MessageBocType? messageType,
+    int? expireAt,
+    required List<String> errors,
+  }) = _MessageBocValidation;
+
+  factory MessageBocValidation.fromJson(Map<String, dynamic> json) =>
+      _$MessageBocValidationFromJson(json);
+}
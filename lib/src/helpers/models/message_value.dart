@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'message_value.freezed.dart';
+part 'message_value.g.dart';
+
+@freezed
+class MessageValue with _$MessageValue {
+  const factory MessageValue({
+    required String value,
+    required String ihrFee,
+    required String fwdFee,
+  }) = _MessageValue;
+
+  factory MessageValue.fromJson(Map<String, dynamic> json) => _$MessageValueFromJson(json);
+}
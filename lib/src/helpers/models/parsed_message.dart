@@ -0,0 +1,30 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/models/message_boc_type.dart';
+
+part 'parsed_message.freezed.dart';
+part 'parsed_message.g.dart';
+
+/// Header fields which the message type doesn't have are null
+@freezed
+class ParsedMessage with _$ParsedMessage {
+  const factory ParsedMessage({
+    required String hash,
+    required MessageBocType messageType,
+    String? src,
+    String? dst,
+    String? value,
+    bool? bounce,
+    bool? bounced,
+    bool? ihrDisabled,
+    String? ihrFee,
+    String? fwdFee,
+    String? importFee,
+    String? createdLt,
+    int? createdAt,
+    required bool hasStateInit,
+    String? bodyHash,
+    String? bodyBoc,
+  }) = _ParsedMessage;
+
+  factory ParsedMessage.fromJson(Map<String, dynamic> json) => _$ParsedMessageFromJson(json);
+}
@@ -0,0 +1,20 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/models/ffi_call_metrics.dart';
+import 'package:nekoton_flutter/src/helpers/models/live_subscriptions.dart';
+
+part 'runtime_metrics.freezed.dart';
+part 'runtime_metrics.g.dart';
+
+@freezed
+class RuntimeMetrics with _$RuntimeMetrics {
+  const factory RuntimeMetrics({
+    required bool enabled,
+    required int workerThreads,
+    required LiveSubscriptions liveSubscriptions,
+    required int eventsPosted,
+    required int ffiCallsServed,
+    required List<FfiCallMetrics> slowestFfiCalls,
+  }) = _RuntimeMetrics;
+
+  factory RuntimeMetrics.fromJson(Map<String, dynamic> json) => _$RuntimeMetricsFromJson(json);
+}
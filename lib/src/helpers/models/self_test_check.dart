@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'self_test_check.freezed.dart';
+part 'self_test_check.g.dart';
+
+@freezed
+class SelfTestCheck with _$SelfTestCheck {
+  const factory SelfTestCheck({
+    required String name,
+    required bool passed,
+    required int elapsedUs,
+    String? error,
+  }) = _SelfTestCheck;
+
+  factory SelfTestCheck.fromJson(Map<String, dynamic> json) => _$SelfTestCheckFromJson(json);
+}
@@ -0,0 +1,17 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'stakes_config.freezed.dart';
+part 'stakes_config.g.dart';
+
+/// Amounts are in nanotons, [maxStakeFactor] is a 16.16 fixed point number
+@freezed
+class StakesConfig with _$StakesConfig {
+  const factory StakesConfig({
+    required String minStake,
+    required String maxStake,
+    required String minTotalStake,
+    required int maxStakeFactor,
+  }) = _StakesConfig;
+
+  factory StakesConfig.fromJson(Map<String, dynamic> json) => _$StakesConfigFromJson(json);
+}
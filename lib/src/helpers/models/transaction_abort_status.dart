@@ -0,0 +1,17 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/helpers/models/aborted_phase.dart';
+
+part 'transaction_abort_status.freezed.dart';
+part 'transaction_abort_status.g.dart';
+
+@freezed
+class TransactionAbortStatus with _$TransactionAbortStatus {
+  const factory TransactionAbortStatus({
+    required bool aborted,
+    int? exitCode,
+    AbortedPhase? phase,
+  }) = _TransactionAbortStatus;
+
+  factory TransactionAbortStatus.fromJson(Map<String, dynamic> json) =>
+      _$TransactionAbortStatusFromJson(json);
+}
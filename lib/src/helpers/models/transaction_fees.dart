@@ -0,0 +1,17 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'transaction_fees.freezed.dart';
+part 'transaction_fees.g.dart';
+
+@freezed
+class TransactionFees with _$TransactionFees {
+  const factory TransactionFees({
+    required String totalFees,
+    required String storageFee,
+    required String computeFee,
+    required String actionFee,
+    required String forwardFee,
+  }) = _TransactionFees;
+
+  factory TransactionFees.fromJson(Map<String, dynamic> json) => _$TransactionFeesFromJson(json);
+}
@@ -0,0 +1,22 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+/// Converts a display amount like `1.5` into the integer amount of the smallest token units
+String packTokenValue({
+  required String displayAmount,
+  required int decimals,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_pack_token_value(
+          displayAmount.toNativeUtf8().cast<Char>(),
+          decimals,
+        ),
+  );
+
+  final value = result as String;
+
+  return value;
+}
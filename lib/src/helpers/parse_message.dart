@@ -0,0 +1,19 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/parsed_message.dart';
+
+ParsedMessage parseMessage(String boc) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_parse_message(
+          boc.toNativeUtf8().cast<Char>(),
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final parsedMessage = ParsedMessage.fromJson(json);
+
+  return parsedMessage;
+}
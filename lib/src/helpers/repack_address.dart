@@ -4,10 +4,16 @@ import 'package:ffi/ffi.dart';
 import 'package:nekoton_flutter/src/bindings.dart';
 import 'package:nekoton_flutter/src/ffi_utils.dart';
 
-String repackAddress(String address) {
+String repackAddress(
+  String address, {
+  int? workchainId,
+}) {
+  final workchainIdStr = workchainId?.toString();
+
   final result = executeSync(
     () => NekotonFlutter.instance().bindings.nt_repack_address(
           address.toNativeUtf8().cast<Char>(),
+          workchainIdStr?.toNativeUtf8().cast<Char>() ?? nullptr,
         ),
   );
 
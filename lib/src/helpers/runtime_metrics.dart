@@ -0,0 +1,19 @@
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/runtime_metrics.dart';
+
+RuntimeMetrics getRuntimeMetrics() {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_runtime_metrics(),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final metrics = RuntimeMetrics.fromJson(json);
+
+  return metrics;
+}
+
+/// Live subscriptions are always counted, calls and events only while enabled
+void setMetricsEnabled(bool enabled) => executeSync(
+      () => NekotonFlutter.instance().bindings.nt_set_metrics_enabled(enabled ? 1 : 0),
+    );
@@ -0,0 +1,16 @@
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/self_test_check.dart';
+
+/// Runs a set of quick checks against the native library, useful right after it's loaded
+List<SelfTestCheck> selfTest() {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_self_test(),
+  );
+
+  final list = result as List<dynamic>;
+  final json = list.cast<Map<String, dynamic>>();
+  final checks = json.map((e) => SelfTestCheck.fromJson(e)).toList();
+
+  return checks;
+}
@@ -0,0 +1,12 @@
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+/// Shifts the library clock by [offsetMs], only available when built with `testable_clock`
+void setClockOffset(int offsetMs) => executeSync(
+      () => NekotonFlutter.instance().bindings.nt_set_clock_offset(offsetMs),
+    );
+
+/// Removes the offset set with [setClockOffset]
+void resetClock() => executeSync(
+      () => NekotonFlutter.instance().bindings.nt_reset_clock(),
+    );
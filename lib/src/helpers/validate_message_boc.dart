@@ -0,0 +1,26 @@
+import 'dart:ffi';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/helpers/models/message_boc_validation.dart';
+
+/// [expireAt] is only read from the body header when [contractAbi] is provided
+MessageBocValidation validateMessageBoc({
+  required String messageBoc,
+  required String expectedDst,
+  String? contractAbi,
+}) {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_validate_message_boc(
+          messageBoc.toNativeUtf8().cast<Char>(),
+          expectedDst.toNativeUtf8().cast<Char>(),
+          contractAbi?.toNativeUtf8().cast<Char>() ?? nullptr,
+        ),
+  );
+
+  final json = result as Map<String, dynamic>;
+  final validation = MessageBocValidation.fromJson(json);
+
+  return validation;
+}
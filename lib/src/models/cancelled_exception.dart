@@ -0,0 +1,6 @@
+import 'package:nekoton_flutter/src/models/nekoton_exception.dart';
+
+/// Thrown when an async call was cancelled with its `CancellationToken`
+class CancelledException extends NekotonException {
+  CancelledException() : super('Cancelled');
+}
@@ -1,4 +1,5 @@
 import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/models/cancelled_exception.dart';
 import 'package:nekoton_flutter/src/models/nekoton_exception.dart';
 
 part 'execution_result.freezed.dart';
@@ -10,11 +11,14 @@ class ExecutionResult with _$ExecutionResult {
 
   const factory ExecutionResult.err(String data) = _ExecutionResultErr;
 
+  const factory ExecutionResult.cancelled() = _ExecutionResultCancelled;
+
   const ExecutionResult._();
 
   dynamic handle() => when(
         ok: (data) => data,
         err: (data) => throw NekotonException(data),
+        cancelled: () => throw CancelledException(),
       );
 
   factory ExecutionResult.fromJson(Map<String, dynamic> json) => _$ExecutionResultFromJson(json);
@@ -0,0 +1,53 @@
+import 'dart:convert';
+import 'dart:ffi';
+import 'dart:isolate';
+
+import 'package:ffi/ffi.dart';
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/transport/models/block_tip.dart';
+import 'package:nekoton_flutter/src/transport/transport.dart';
+
+final _nativeFinalizer =
+    NativeFinalizer(NekotonFlutter.instance().bindings.addresses.nt_block_subscription_free_ptr);
+
+/// Masterchain tip updates of [Transport], polling stops once the subscription is finalized
+class BlockSubscription implements Finalizable {
+  late final Pointer<Void> _ptr;
+  final _onBlockPort = ReceivePort();
+  late final Stream<BlockTip> onBlockStream;
+
+  BlockSubscription._();
+
+  static BlockSubscription subscribe(Transport transport) {
+    final instance = BlockSubscription._();
+    instance._initialize(transport);
+    return instance;
+  }
+
+  Pointer<Void> get ptr => _ptr;
+
+  Future<void> dispose() async => _onBlockPort.close();
+
+  void _initialize(Transport transport) {
+    onBlockStream = _onBlockPort.cast<String>().map((e) {
+      final json = jsonDecode(e) as Map<String, dynamic>;
+      final payload = BlockTip.fromJson(json);
+      return payload;
+    }).asBroadcastStream();
+
+    final transportTypeStr = jsonEncode(transport.type.toString());
+
+    final result = executeSync(
+      () => NekotonFlutter.instance().bindings.nt_subscribe_blocks(
+            _onBlockPort.sendPort.nativePort,
+            transport.ptr,
+            transportTypeStr.toNativeUtf8().cast<Char>(),
+          ),
+    );
+
+    _ptr = Pointer.fromAddress(result as int).cast<Void>();
+
+    _nativeFinalizer.attach(this, _ptr);
+  }
+}
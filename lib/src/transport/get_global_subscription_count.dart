@@ -0,0 +1,11 @@
+import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/ffi_utils.dart';
+
+/// Number of live subscriptions across all transports
+int getGlobalSubscriptionCount() {
+  final result = executeSync(
+    () => NekotonFlutter.instance().bindings.nt_get_global_subscription_count(),
+  );
+
+  return result as int;
+}
@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'awaited_message.freezed.dart';
+part 'awaited_message.g.dart';
+
+@freezed
+class AwaitedMessage with _$AwaitedMessage {
+  const factory AwaitedMessage({
+    required String hash,
+    required String dst,
+    @JsonKey(includeIfNull: false) int? expireAt,
+  }) = _AwaitedMessage;
+
+  factory AwaitedMessage.fromJson(Map<String, dynamic> json) => _$AwaitedMessageFromJson(json);
+}
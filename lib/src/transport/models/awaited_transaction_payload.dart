@@ -0,0 +1,26 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/core/models/transaction.dart';
+
+part 'awaited_transaction_payload.freezed.dart';
+part 'awaited_transaction_payload.g.dart';
+
+@Freezed(unionKey: 'type')
+class AwaitedTransactionPayload with _$AwaitedTransactionPayload {
+  const factory AwaitedTransactionPayload.found({
+    required String messageHash,
+    required Transaction transaction,
+  }) = _AwaitedTransactionPayloadFound;
+
+  const factory AwaitedTransactionPayload.expired({
+    required String messageHash,
+  }) = _AwaitedTransactionPayloadExpired;
+
+  const factory AwaitedTransactionPayload.completed() = _AwaitedTransactionPayloadCompleted;
+
+  const factory AwaitedTransactionPayload.failed({
+    required String error,
+  }) = _AwaitedTransactionPayloadFailed;
+
+  factory AwaitedTransactionPayload.fromJson(Map<String, dynamic> json) =>
+      _$AwaitedTransactionPayloadFromJson(json);
+}
@@ -0,0 +1,17 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/transport/models/limits_params.dart';
+
+part 'block_limits_params.freezed.dart';
+part 'block_limits_params.g.dart';
+
+@freezed
+class BlockLimitsParams with _$BlockLimitsParams {
+  const factory BlockLimitsParams({
+    required LimitsParams bytes,
+    required LimitsParams gas,
+    required LimitsParams ltDelta,
+  }) = _BlockLimitsParams;
+
+  factory BlockLimitsParams.fromJson(Map<String, dynamic> json) =>
+      _$BlockLimitsParamsFromJson(json);
+}
@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'block_tip.freezed.dart';
+part 'block_tip.g.dart';
+
+@freezed
+class BlockTip with _$BlockTip {
+  const factory BlockTip({
+    @JsonKey(includeIfNull: false) int? seqno,
+    required String endLt,
+    required int genUtime,
+  }) = _BlockTip;
+
+  factory BlockTip.fromJson(Map<String, dynamic> json) => _$BlockTipFromJson(json);
+}
@@ -0,0 +1,21 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'gas_params.freezed.dart';
+part 'gas_params.g.dart';
+
+@freezed
+class GasParams with _$GasParams {
+  const factory GasParams({
+    required int gasPrice,
+    required int gasLimit,
+    required int specialGasLimit,
+    required int gasCredit,
+    required int blockGasLimit,
+    required int freezeDueLimit,
+    required int deleteDueLimit,
+    required int flatGasLimit,
+    required int flatGasPrice,
+  }) = _GasParams;
+
+  factory GasParams.fromJson(Map<String, dynamic> json) => _$GasParamsFromJson(json);
+}
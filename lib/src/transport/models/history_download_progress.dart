@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'history_download_progress.freezed.dart';
+part 'history_download_progress.g.dart';
+
+@freezed
+class HistoryDownloadProgress with _$HistoryDownloadProgress {
+  const factory HistoryDownloadProgress({
+    required int fetched,
+    required int estimatedTotal,
+  }) = _HistoryDownloadProgress;
+
+  factory HistoryDownloadProgress.fromJson(Map<String, dynamic> json) =>
+      _$HistoryDownloadProgressFromJson(json);
+}
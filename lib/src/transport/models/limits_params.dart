@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'limits_params.freezed.dart';
+part 'limits_params.g.dart';
+
+@freezed
+class LimitsParams with _$LimitsParams {
+  const factory LimitsParams({
+    required int underload,
+    required int softLimit,
+    required int hardLimit,
+  }) = _LimitsParams;
+
+  factory LimitsParams.fromJson(Map<String, dynamic> json) => _$LimitsParamsFromJson(json);
+}
@@ -0,0 +1,22 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+import 'package:nekoton_flutter/src/transport/models/block_limits_params.dart';
+import 'package:nekoton_flutter/src/transport/models/gas_params.dart';
+import 'package:nekoton_flutter/src/transport/models/size_limits.dart';
+
+part 'network_params.freezed.dart';
+part 'network_params.g.dart';
+
+@freezed
+class NetworkParams with _$NetworkParams {
+  const factory NetworkParams({
+    required int keyBlockSeqno,
+    required GasParams masterchainGas,
+    required GasParams basechainGas,
+    required BlockLimitsParams masterchainBlockLimits,
+    required BlockLimitsParams basechainBlockLimits,
+    required List<String> fundamentalAddresses,
+    required SizeLimits sizeLimits,
+  }) = _NetworkParams;
+
+  factory NetworkParams.fromJson(Map<String, dynamic> json) => _$NetworkParamsFromJson(json);
+}
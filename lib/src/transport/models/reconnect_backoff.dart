@@ -0,0 +1,16 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'reconnect_backoff.freezed.dart';
+part 'reconnect_backoff.g.dart';
+
+@freezed
+class ReconnectBackoff with _$ReconnectBackoff {
+  const factory ReconnectBackoff({
+    required int initialDelayMs,
+    required int maxDelayMs,
+    required double multiplier,
+    required double jitterFraction,
+  }) = _ReconnectBackoff;
+
+  factory ReconnectBackoff.fromJson(Map<String, dynamic> json) => _$ReconnectBackoffFromJson(json);
+}
@@ -0,0 +1,18 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'size_limits.freezed.dart';
+part 'size_limits.g.dart';
+
+@freezed
+class SizeLimits with _$SizeLimits {
+  const factory SizeLimits({
+    required int maxMsgBits,
+    required int maxMsgCells,
+    required int maxLibraryCells,
+    required int maxVmDataDepth,
+    required int maxExtMsgSize,
+    required int maxExtMsgDepth,
+  }) = _SizeLimits;
+
+  factory SizeLimits.fromJson(Map<String, dynamic> json) => _$SizeLimitsFromJson(json);
+}
@@ -0,0 +1,15 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'unfreeze_params.freezed.dart';
+part 'unfreeze_params.g.dart';
+
+@freezed
+class UnfreezeParams with _$UnfreezeParams {
+  const factory UnfreezeParams({
+    required String stateInit,
+    required String amount,
+    required String duePayment,
+  }) = _UnfreezeParams;
+
+  factory UnfreezeParams.fromJson(Map<String, dynamic> json) => _$UnfreezeParamsFromJson(json);
+}
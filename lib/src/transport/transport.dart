@@ -1,16 +1,25 @@
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi';
+import 'dart:isolate';
 
 import 'package:ffi/ffi.dart';
 import 'package:nekoton_flutter/src/bindings.dart';
+import 'package:nekoton_flutter/src/cancellation_token.dart';
 import 'package:nekoton_flutter/src/core/models/accounts_list.dart';
 import 'package:nekoton_flutter/src/core/models/full_contract_state.dart';
 import 'package:nekoton_flutter/src/core/models/raw_contract_state.dart';
 import 'package:nekoton_flutter/src/core/models/transaction.dart';
 import 'package:nekoton_flutter/src/core/models/transactions_list.dart';
 import 'package:nekoton_flutter/src/ffi_utils.dart';
+import 'package:nekoton_flutter/src/models/cancelled_exception.dart';
+import 'package:nekoton_flutter/src/transport/models/awaited_message.dart';
+import 'package:nekoton_flutter/src/transport/models/awaited_transaction_payload.dart';
+import 'package:nekoton_flutter/src/transport/models/history_download_progress.dart';
+import 'package:nekoton_flutter/src/transport/models/network_params.dart';
+import 'package:nekoton_flutter/src/transport/models/reconnect_backoff.dart';
 import 'package:nekoton_flutter/src/transport/models/transport_type.dart';
+import 'package:nekoton_flutter/src/transport/models/unfreeze_params.dart';
 
 abstract class Transport {
   Pointer<Void> get ptr;
@@ -123,5 +132,194 @@ abstract class Transport {
     return transaction;
   }
 
+  /// Published library cell BOC with given hash, null if there is no such library.
+  /// Only JRPC transport supports it
+  Future<String?> getLibraryCell(String libraryHash) async {
+    final transportTypeStr = jsonEncode(type.toString());
+
+    final result = await executeAsync(
+      (port) => NekotonFlutter.instance().bindings.nt_get_library_cell(
+            port,
+            ptr,
+            transportTypeStr.toNativeUtf8().cast<Char>(),
+            libraryHash.toNativeUtf8().cast<Char>(),
+          ),
+    );
+
+    return result as String?;
+  }
+
+  /// Parameters of the latest key block config, rechecked at most once a minute
+  Future<NetworkParams> getNetworkParams() async {
+    final transportTypeStr = jsonEncode(type.toString());
+
+    final result = await executeAsync(
+      (port) => NekotonFlutter.instance().bindings.nt_get_network_params(
+            port,
+            ptr,
+            transportTypeStr.toNativeUtf8().cast<Char>(),
+          ),
+    );
+
+    final json = result as Map<String, dynamic>;
+    final networkParams = NetworkParams.fromJson(json);
+
+    return networkParams;
+  }
+
+  /// Checks [stateInit] against the frozen account at [address] and estimates the amount
+  /// required to unfreeze it
+  Future<UnfreezeParams> prepareUnfreeze({
+    required String address,
+    required String stateInit,
+  }) async {
+    final transportTypeStr = jsonEncode(type.toString());
+
+    final result = await executeAsync(
+      (port) => NekotonFlutter.instance().bindings.nt_prepare_unfreeze(
+            port,
+            ptr,
+            transportTypeStr.toNativeUtf8().cast<Char>(),
+            address.toNativeUtf8().cast<Char>(),
+            stateInit.toNativeUtf8().cast<Char>(),
+          ),
+    );
+
+    final json = result as Map<String, dynamic>;
+    final unfreezeParams = UnfreezeParams.fromJson(json);
+
+    return unfreezeParams;
+  }
+
+  /// Polls destinations of [messages] until each one is found in a transaction or expires,
+  /// the stream ends with either `completed` or `failed`
+  Stream<AwaitedTransactionPayload> awaitTransactions({
+    required List<AwaitedMessage> messages,
+    required int expireAt,
+    CancellationToken? cancellationToken,
+  }) async* {
+    final port = ReceivePort();
+
+    try {
+      final transportTypeStr = jsonEncode(type.toString());
+      final messagesStr = jsonEncode(messages);
+
+      NekotonFlutter.instance().bindings.nt_await_transactions(
+            port.sendPort.nativePort,
+            ptr,
+            transportTypeStr.toNativeUtf8().cast<Char>(),
+            messagesStr.toNativeUtf8().cast<Char>(),
+            expireAt,
+            cancellationToken?.ptr ?? nullptr,
+          );
+
+      await for (final message in port.cast<String>()) {
+        final json = jsonDecode(message) as Map<String, dynamic>;
+
+        if (json['type'] == 'cancelled') throw CancelledException();
+
+        final payload = AwaitedTransactionPayload.fromJson(json);
+
+        yield payload;
+
+        final isLast = payload.maybeWhen(
+          completed: () => true,
+          failed: (_) => true,
+          orElse: () => false,
+        );
+
+        if (isLast) break;
+      }
+    } finally {
+      port.close();
+    }
+  }
+
+  /// Downloads transactions of [address] between [fromLt] and [toLt] in parallel segments,
+  /// [onBatch] receives pages strictly from the newest to the oldest one.
+  /// Returns the number of fetched transactions
+  Future<int> downloadHistory({
+    required String address,
+    String? fromLt,
+    String? toLt,
+    required int segments,
+    required int maxConcurrentRequests,
+    required void Function(TransactionsList batch) onBatch,
+    void Function(HistoryDownloadProgress progress)? onProgress,
+  }) async {
+    final onBatchPort = ReceivePort();
+    final onProgressPort = ReceivePort();
+
+    try {
+      onBatchPort.cast<String>().listen((e) {
+        final json = jsonDecode(e) as Map<String, dynamic>;
+        onBatch(TransactionsList.fromJson(json));
+      });
+
+      onProgressPort.cast<String>().listen((e) {
+        final json = jsonDecode(e) as Map<String, dynamic>;
+        onProgress?.call(HistoryDownloadProgress.fromJson(json));
+      });
+
+      final transportTypeStr = jsonEncode(type.toString());
+
+      final result = await executeAsync(
+        (port) => NekotonFlutter.instance().bindings.nt_transport_download_history(
+              port,
+              onBatchPort.sendPort.nativePort,
+              onProgressPort.sendPort.nativePort,
+              ptr,
+              transportTypeStr.toNativeUtf8().cast<Char>(),
+              address.toNativeUtf8().cast<Char>(),
+              fromLt?.toNativeUtf8().cast<Char>() ?? nullptr,
+              toLt?.toNativeUtf8().cast<Char>() ?? nullptr,
+              segments,
+              maxConcurrentRequests,
+            ),
+      );
+
+      return result as int;
+    } finally {
+      onBatchPort.close();
+      onProgressPort.close();
+    }
+  }
+
+  /// Delays between repeated requests after connection failures
+  ReconnectBackoff getReconnectBackoff() {
+    final result = executeSync(
+      () => NekotonFlutter.instance().bindings.nt_get_transport_reconnect_backoff(ptr),
+    );
+
+    final json = result as Map<String, dynamic>;
+    final backoff = ReconnectBackoff.fromJson(json);
+
+    return backoff;
+  }
+
+  void setReconnectBackoff(ReconnectBackoff backoff) => executeSync(
+        () => NekotonFlutter.instance().bindings.nt_set_transport_reconnect_backoff(
+              ptr,
+              backoff.initialDelayMs,
+              backoff.maxDelayMs,
+              backoff.multiplier,
+              backoff.jitterFraction,
+            ),
+      );
+
+  /// Number of live subscriptions which use this transport
+  int getSubscriptionCount() {
+    final transportTypeStr = jsonEncode(type.toString());
+
+    final result = executeSync(
+      () => NekotonFlutter.instance().bindings.nt_get_transport_subscription_count(
+            ptr,
+            transportTypeStr.toNativeUtf8().cast<Char>(),
+          ),
+    );
+
+    return result as int;
+  }
+
   Future<void> dispose();
 }
@@ -4,6 +4,26 @@ void nt_store_dart_post_cobject(void *ptr);
 
 void nt_free_cstring(char *ptr);
 
+unsigned int nt_is_runtime_alive(void);
+
+#if defined(NT_TESTABLE_CLOCK)
+char *nt_set_clock_offset(long long offset_ms);
+#endif
+
+#if defined(NT_TESTABLE_CLOCK)
+char *nt_reset_clock(void);
+#endif
+
+char *nt_cancellation_token_create(void);
+
+char *nt_cancellation_token_cancel(void *cancellation_token);
+
+void nt_cancellation_token_free_ptr(void *ptr);
+
+char *nt_set_canonical_json(unsigned int enabled);
+
+char *nt_canonicalize_json(char *json);
+
 void nt_accounts_storage_create(long long result_port, void *storage);
 
 void nt_accounts_storage_entries(long long result_port, void *accounts_storage);
@@ -82,7 +102,8 @@ void nt_generic_contract_refresh(long long result_port, void *generic_contract);
 
 void nt_generic_contract_preload_transactions(long long result_port,
                                               void *generic_contract,
-                                              char *from_lt);
+                                              char *from_lt,
+                                              void *cancellation_token);
 
 void nt_generic_contract_handle_block(long long result_port, void *generic_contract, char *block);
 
@@ -100,6 +121,18 @@ void nt_keystore_update_key(long long result_port, void *keystore, char *signer,
 
 void nt_keystore_export_key(long long result_port, void *keystore, char *signer, char *input);
 
+void nt_keystore_export_entry(long long result_port,
+                              void *keystore,
+                              char *public_key,
+                              char *password,
+                              char *export_password);
+
+void nt_keystore_import_entry(long long result_port,
+                              void *keystore,
+                              char *exported_key,
+                              char *export_password,
+                              char *new_password);
+
 void nt_keystore_get_public_keys(long long result_port, void *keystore, char *signer, char *input);
 
 void nt_keystore_encrypt(long long result_port,
@@ -144,6 +177,20 @@ char *nt_keystore_verify_data(void *connection, char *signers, char *data);
 
 void nt_keystore_free_ptr(void *ptr);
 
+char *nt_set_send_journal(void *storage);
+
+void nt_get_unresolved_sends(long long result_port, void *storage);
+
+void nt_acknowledge_unresolved_send(long long result_port, void *storage, char *message_hash);
+
+char *nt_get_subscription_error_count(void *subscription);
+
+char *nt_reset_subscription_error_count(void *subscription);
+
+char *nt_set_subscription_error_threshold(void *subscription,
+                                          unsigned int threshold,
+                                          long long alert_port);
+
 void nt_token_wallet_subscribe(long long result_port,
                                long long on_balance_changed_port,
                                long long on_transactions_found_port,
@@ -171,9 +218,19 @@ void nt_token_wallet_prepare_transfer(long long result_port,
                                       unsigned int notify_receiver,
                                       char *payload);
 
+void nt_token_wallet_prepare_transfer_to_wallet(long long result_port,
+                                                void *token_wallet,
+                                                char *destination_wallet,
+                                                char *tokens,
+                                                unsigned int notify_receiver,
+                                                char *payload);
+
 void nt_token_wallet_refresh(long long result_port, void *token_wallet);
 
-void nt_token_wallet_preload_transactions(long long result_port, void *token_wallet, char *from_lt);
+void nt_token_wallet_preload_transactions(long long result_port,
+                                          void *token_wallet,
+                                          char *from_lt,
+                                          void *cancellation_token);
 
 void nt_token_wallet_handle_block(long long result_port, void *token_wallet, char *block);
 
@@ -187,11 +244,24 @@ void nt_get_token_wallet_details(long long result_port,
                                  char *transport_type,
                                  char *token_wallet);
 
+void nt_get_token_wallet_details_batch(long long result_port,
+                                       void *transport,
+                                       char *transport_type,
+                                       char *token_wallets);
+
 void nt_get_token_root_details_from_token_wallet(long long result_port,
                                                  void *transport,
                                                  char *transport_type,
                                                  char *token_wallet_address);
 
+void nt_token_wallet_history(long long result_port,
+                             void *transport,
+                             char *transport_type,
+                             char *root_token_contract,
+                             char *owner,
+                             char *from_lt,
+                             unsigned char count);
+
 void nt_token_wallet_free_ptr(void *ptr);
 
 void nt_ton_wallet_subscribe(long long result_port,
@@ -227,6 +297,8 @@ void nt_ton_wallet_workchain(long long result_port, void *ton_wallet);
 
 void nt_ton_wallet_address(long long result_port, void *ton_wallet);
 
+char *nt_get_wallet_subscription_address(void *ton_wallet);
+
 void nt_ton_wallet_public_key(long long result_port, void *ton_wallet);
 
 void nt_ton_wallet_wallet_type(long long result_port, void *ton_wallet);
@@ -261,6 +333,26 @@ void nt_ton_wallet_prepare_transfer(long long result_port,
                                     char *body,
                                     char *expiration);
 
+void nt_ton_wallet_prepare_transfer_from_uri(long long result_port,
+                                             void *ton_wallet,
+                                             char *contract_state,
+                                             char *public_key,
+                                             char *uri,
+                                             char *expiration);
+
+void nt_create_token_transfer_message(long long result_port,
+                                      void *ton_wallet,
+                                      char *contract_state,
+                                      char *public_key,
+                                      char *token_wallet_address,
+                                      char *recipient,
+                                      char *amount,
+                                      char *deploy_wallet_value,
+                                      unsigned int notify_receiver,
+                                      char *attached_amount,
+                                      char *payload,
+                                      char *expiration);
+
 void nt_ton_wallet_prepare_confirm_transaction(long long result_port,
                                                void *ton_wallet,
                                                char *contract_state,
@@ -272,9 +364,20 @@ void nt_ton_wallet_estimate_fees(long long result_port, void *ton_wallet, char *
 
 void nt_ton_wallet_send(long long result_port, void *ton_wallet, char *signed_message);
 
+void nt_ton_wallet_send_with_preflight(long long result_port,
+                                       void *ton_wallet,
+                                       void *transport,
+                                       char *transport_type,
+                                       char *signed_message,
+                                       char *options,
+                                       void *cancellation_token);
+
 void nt_ton_wallet_refresh(long long result_port, void *ton_wallet);
 
-void nt_ton_wallet_preload_transactions(long long result_port, void *ton_wallet, char *from_lt);
+void nt_ton_wallet_preload_transactions(long long result_port,
+                                        void *ton_wallet,
+                                        char *from_lt,
+                                        void *cancellation_token);
 
 void nt_ton_wallet_handle_block(long long result_port, void *ton_wallet, char *block);
 
@@ -283,7 +386,8 @@ void nt_find_existing_wallets(long long result_port,
                               char *transport_type,
                               char *public_key,
                               signed char workchain_id,
-                              char *wallet_types);
+                              char *wallet_types,
+                              void *cancellation_token);
 
 void nt_get_existing_wallet_info(long long result_port,
                                  void *transport,
@@ -295,6 +399,8 @@ void nt_get_wallet_custodians(long long result_port,
                               char *transport_type,
                               char *address);
 
+char *nt_compute_code_hash_from_abi(char *wallet_type);
+
 void nt_ton_wallet_free_ptr(void *ptr);
 
 void nt_unsigned_message_refresh_timeout(long long result_port, void *unsigned_message);
@@ -303,7 +409,10 @@ void nt_unsigned_message_expire_at(long long result_port, void *unsigned_message
 
 void nt_unsigned_message_hash(long long result_port, void *unsigned_message);
 
-void nt_unsigned_message_sign(long long result_port, void *unsigned_message, char *signature);
+void nt_unsigned_message_sign(long long result_port,
+                              void *unsigned_message,
+                              char *signature,
+                              char *signature_encoding);
 
 void nt_unsigned_message_free_ptr(void *ptr);
 
@@ -311,6 +420,8 @@ char *nt_verify_signature(char *public_key, char *data_hash, char *signature);
 
 char *nt_generate_key(char *mnemonic_type);
 
+char *nt_generate_key_pair(void);
+
 char *nt_get_hints(char *input);
 
 char *nt_derive_from_phrase(char *phrase, char *mnemonic_type);
@@ -333,6 +444,14 @@ char *nt_ledger_connection_create(long long get_public_key_port, long long sign_
 
 void nt_ledger_connection_free_ptr(void *ptr);
 
+char *nt_get_transport_reconnect_backoff(void *transport);
+
+char *nt_set_transport_reconnect_backoff(void *transport,
+                                         unsigned int initial_delay_ms,
+                                         unsigned int max_delay_ms,
+                                         float multiplier,
+                                         float jitter_fraction);
+
 char *nt_storage_create(long long get_port,
                         long long set_port,
                         long long set_unchecked_port,
@@ -347,21 +466,87 @@ char *nt_unpack_std_smc_addr(char *packed, unsigned int base64_url);
 
 char *nt_validate_address(char *address);
 
-char *nt_repack_address(char *address);
+char *nt_check_address(char *address);
+
+char *nt_repack_address(char *address, char *workchain_id);
+
+char *nt_account_visual_seed(char *address);
 
 char *nt_extract_public_key(char *boc);
 
+char *nt_get_ever_wallet_custodians(char *account_stuff_boc);
+
 char *nt_code_to_tvc(char *code);
 
 char *nt_split_tvc(char *tvc);
 
+char *nt_merge_tvc(char *code, char *data);
+
+char *nt_get_transaction_fees(char *transaction_boc);
+
+char *nt_get_transaction_aborted(char *transaction);
+
+char *nt_get_account_credit_phases(char *transaction_boc);
+
+char *nt_decode_jetton_burn_notification(char *body);
+
+char *nt_decode_block_info(char *block_boc);
+
+char *nt_pack_token_value(char *display_amount, unsigned char decimals);
+
+char *nt_get_message_value(char *message_boc);
+
+char *nt_parse_message(char *boc);
+
+char *nt_validate_message_boc(char *message_boc, char *expected_dst, char *contract_abi);
+
+char *nt_get_account_cells_total_count(char *account_stuff_boc);
+
+char *nt_get_config_param_17(char *config_boc);
+
 char *nt_check_public_key(char *public_key);
 
+char *nt_validate_public_key(char *public_key);
+
 char *nt_run_local(char *account_stuff_boc,
                    char *contract_abi,
                    char *method,
                    char *input,
-                   unsigned int responsible);
+                   unsigned int responsible,
+                   char *libraries_json,
+                   char *blockchain_config_boc,
+                   unsigned int timestamp,
+                   unsigned long long lt,
+                   unsigned int no_cache);
+
+char *nt_get_cache_stats(void);
+
+char *nt_run_local_responsible_value(char *account_stuff_boc,
+                                     char *contract_abi,
+                                     char *method,
+                                     char *input,
+                                     char *blockchain_config_boc);
+
+char *nt_execute_transaction_locally(char *account_stuff_boc,
+                                     char *message_boc,
+                                     char *config_boc,
+                                     unsigned int utime,
+                                     unsigned long long lt);
+
+char *nt_simulate_transaction(char *account_stuff_boc, char *message_boc, char *config_boc);
+
+void nt_batch_run_local(long long result_port,
+                        char *account_stuff_boc,
+                        char *contract_abi,
+                        char *calls);
+
+char *nt_run_local_with_config(char *account_stuff_boc,
+                               char *contract_abi,
+                               char *method,
+                               char *input,
+                               unsigned int responsible,
+                               char *blockchain_config_boc,
+                               char *libraries);
 
 char *nt_get_expected_address(char *tvc,
                               char *contract_abi,
@@ -371,11 +556,26 @@ char *nt_get_expected_address(char *tvc,
 
 char *nt_encode_internal_input(char *contract_abi, char *method, char *input);
 
+char *nt_encode_transfer_with_comment(char *contract_abi,
+                                      char *recipient,
+                                      char *amount,
+                                      char *deploy_wallet_value,
+                                      char *remaining_gas_to,
+                                      unsigned int notify,
+                                      char *comment);
+
+char *nt_encode_external_input(char *contract_abi,
+                               char *method,
+                               char *input,
+                               char *public_key,
+                               unsigned int timeout);
+
 char *nt_create_external_message_without_signature(char *dst,
                                                    char *contract_abi,
                                                    char *method,
                                                    char *state_init,
                                                    char *input,
+                                                   char *public_key,
                                                    unsigned int timeout);
 
 char *nt_create_external_message(char *dst,
@@ -386,23 +586,87 @@ char *nt_create_external_message(char *dst,
                                  char *public_key,
                                  unsigned int timeout);
 
+char *nt_prepare_sign_payload(char *contract_abi,
+                              char *method,
+                              char *input,
+                              char *public_key,
+                              unsigned int timeout);
+
+char *nt_create_signed_external_message(char *dst,
+                                        char *state_init,
+                                        char *body,
+                                        char *signature,
+                                        unsigned int expire_at);
+
 char *nt_parse_known_payload(char *payload);
 
-char *nt_decode_input(char *message_body, char *contract_abi, char *method, unsigned int internal);
+char *nt_build_bridge_burn_payload(char *evm_address, unsigned int chain_id);
+
+char *nt_get_contract_function_selector_from_body(char *body);
 
-char *nt_decode_event(char *message_body, char *contract_abi, char *event);
+char *nt_get_function_signature(char *contract_abi, char *method);
 
-char *nt_decode_output(char *message_body, char *contract_abi, char *method);
+char *nt_get_function_id(char *contract_abi, char *method);
 
-char *nt_decode_transaction(char *transaction, char *contract_abi, char *method);
+char *nt_get_event_signature(char *contract_abi, char *event);
 
-char *nt_decode_transaction_events(char *transaction, char *contract_abi);
+char *nt_decode_input(char *message_body,
+                      char *contract_abi,
+                      char *method,
+                      unsigned int internal,
+                      unsigned int with_raw_fallback,
+                      unsigned int strict);
+
+char *nt_decode_event(char *message_body, char *contract_abi, char *event, unsigned int strict);
+
+char *nt_decode_message_body(char *message_body, char *contract_abi, unsigned int internal);
+
+char *nt_decode_output(char *message_body, char *contract_abi, char *method, unsigned int strict);
+
+char *nt_decode_transaction(char *transaction,
+                            char *contract_abi,
+                            char *method,
+                            unsigned int strict);
+
+char *nt_decode_transaction_with_state(char *transaction, char *contract_abi, char *method);
+
+char *nt_decode_safe_multisig_transaction(char *submit_transaction,
+                                          char *confirm_transactions,
+                                          char *contract_abi);
+
+char *nt_decode_transaction_events(char *transaction, char *contract_abi, char *abi_version);
 
 char *nt_get_boc_hash(char *boc);
 
+char *nt_get_code_salt(char *code);
+
+char *nt_set_code_salt(char *code, char *salt);
+
 char *nt_pack_into_cell(char *params, char *tokens);
 
-char *nt_unpack_from_cell(char *params, char *boc, unsigned int allow_partial);
+char *nt_unpack_from_cell(char *params,
+                          char *boc,
+                          unsigned int allow_partial,
+                          unsigned int as_slice);
+
+char *nt_unpack_from_cell_ex(char *params,
+                             char *boc,
+                             unsigned int allow_partial,
+                             unsigned int as_slice);
+
+char *nt_clear_abi_cache(void);
+
+char *nt_prepare_call_template(char *contract_abi, char *method, char *fixed_input);
+
+char *nt_encode_from_template(void *call_template, char *variable_input);
+
+void nt_call_template_free_ptr(void *ptr);
+
+char *nt_get_runtime_metrics(void);
+
+char *nt_set_metrics_enabled(unsigned int enabled);
+
+char *nt_self_test(void);
 
 void nt_transport_get_contract_state(long long result_port,
                                      void *transport,
@@ -433,6 +697,30 @@ void nt_transport_get_transaction(long long result_port,
                                   char *transport_type,
                                   char *hash);
 
+void nt_get_library_cell(long long result_port,
+                         void *transport,
+                         char *transport_type,
+                         char *library_hash);
+
+void nt_prepare_unfreeze(long long result_port,
+                         void *transport,
+                         char *transport_type,
+                         char *address,
+                         char *state_init);
+
+void nt_get_network_params(long long result_port, void *transport, char *transport_type);
+
+void nt_await_transactions(long long result_port,
+                           void *transport,
+                           char *transport_type,
+                           char *messages,
+                           unsigned int expire_at,
+                           void *cancellation_token);
+
+char *nt_subscribe_blocks(long long on_block_port, void *transport, char *transport_type);
+
+void nt_block_subscription_free_ptr(void *ptr);
+
 char *nt_gql_transport_create(void *gql_connection);
 
 void nt_gql_transport_get_latest_block_id(long long result_port,
@@ -449,6 +737,21 @@ void nt_gql_transport_wait_for_next_block_id(long long result_port,
 
 void nt_gql_transport_free_ptr(void *ptr);
 
+void nt_transport_download_history(long long result_port,
+                                   long long on_batch_port,
+                                   long long on_progress_port,
+                                   void *transport,
+                                   char *transport_type,
+                                   char *address,
+                                   char *from_lt,
+                                   char *to_lt,
+                                   unsigned int segments,
+                                   unsigned int max_concurrent_requests);
+
 char *nt_jrpc_transport_create(void *jrpc_connection);
 
 void nt_jrpc_transport_free_ptr(void *ptr);
+
+char *nt_get_transport_subscription_count(void *transport, char *transport_type);
+
+char *nt_get_global_subscription_count(void);
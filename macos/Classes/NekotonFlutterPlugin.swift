@@ -24,6 +24,26 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_free_cstring(nil);
 
+    nt_is_runtime_alive();
+
+    #if NT_TESTABLE_CLOCK
+    nt_set_clock_offset(0);
+    #endif
+
+    #if NT_TESTABLE_CLOCK
+    nt_reset_clock();
+    #endif
+
+    nt_cancellation_token_create();
+
+    nt_cancellation_token_cancel(nil);
+
+    nt_cancellation_token_free_ptr(nil);
+
+    nt_set_canonical_json(0);
+
+    nt_canonicalize_json(nil);
+
     nt_accounts_storage_create(0, nil);
 
     nt_accounts_storage_entries(0, nil);
@@ -68,7 +88,7 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_generic_contract_refresh(0, nil);
 
-    nt_generic_contract_preload_transactions(0, nil, nil);
+    nt_generic_contract_preload_transactions(0, nil, nil, nil);
 
     nt_generic_contract_handle_block(0, nil, nil);
 
@@ -86,6 +106,10 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_keystore_export_key(0, nil, nil, nil);
 
+    nt_keystore_export_entry(0, nil, nil, nil, nil);
+
+    nt_keystore_import_entry(0, nil, nil, nil, nil);
+
     nt_keystore_get_public_keys(0, nil, nil, nil);
 
     nt_keystore_encrypt(0, nil, nil, nil, nil, nil, nil);
@@ -112,6 +136,18 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_keystore_free_ptr(nil);
 
+    nt_set_send_journal(nil);
+
+    nt_get_unresolved_sends(0, nil);
+
+    nt_acknowledge_unresolved_send(0, nil, nil);
+
+    nt_get_subscription_error_count(nil);
+
+    nt_reset_subscription_error_count(nil);
+
+    nt_set_subscription_error_threshold(nil, 0, 0);
+
     nt_token_wallet_subscribe(0, 0, 0, nil, nil, nil, nil);
 
     nt_token_wallet_owner(0, nil);
@@ -128,9 +164,11 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_token_wallet_prepare_transfer(0, nil, nil, nil, 0, nil);
 
+    nt_token_wallet_prepare_transfer_to_wallet(0, nil, nil, nil, 0, nil);
+
     nt_token_wallet_refresh(0, nil);
 
-    nt_token_wallet_preload_transactions(0, nil, nil);
+    nt_token_wallet_preload_transactions(0, nil, nil, nil);
 
     nt_token_wallet_handle_block(0, nil, nil);
 
@@ -138,8 +176,12 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_get_token_wallet_details(0, nil, nil, nil);
 
+    nt_get_token_wallet_details_batch(0, nil, nil, nil);
+
     nt_get_token_root_details_from_token_wallet(0, nil, nil, nil);
 
+    nt_token_wallet_history(0, nil, nil, nil, nil, nil, 0);
+
     nt_token_wallet_free_ptr(nil);
 
     nt_ton_wallet_subscribe(0, 0, 0, 0, 0, nil, nil, 0, nil, nil);
@@ -152,6 +194,8 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_ton_wallet_address(0, nil);
 
+    nt_get_wallet_subscription_address(nil);
+
     nt_ton_wallet_public_key(0, nil);
 
     nt_ton_wallet_wallet_type(0, nil);
@@ -174,24 +218,32 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_ton_wallet_prepare_transfer(0, nil, nil, nil, nil, nil, 0, nil, nil);
 
+    nt_ton_wallet_prepare_transfer_from_uri(0, nil, nil, nil, nil, nil);
+
+    nt_create_token_transfer_message(0, nil, nil, nil, nil, nil, nil, nil, 0, nil, nil, nil);
+
     nt_ton_wallet_prepare_confirm_transaction(0, nil, nil, nil, nil, nil);
 
     nt_ton_wallet_estimate_fees(0, nil, nil);
 
     nt_ton_wallet_send(0, nil, nil);
 
+    nt_ton_wallet_send_with_preflight(0, nil, nil, nil, nil, nil, nil);
+
     nt_ton_wallet_refresh(0, nil);
 
-    nt_ton_wallet_preload_transactions(0, nil, nil);
+    nt_ton_wallet_preload_transactions(0, nil, nil, nil);
 
     nt_ton_wallet_handle_block(0, nil, nil);
 
-    nt_find_existing_wallets(0, nil, nil, nil, 0, nil);
+    nt_find_existing_wallets(0, nil, nil, nil, 0, nil, nil);
 
     nt_get_existing_wallet_info(0, nil, nil, nil);
 
     nt_get_wallet_custodians(0, nil, nil, nil);
 
+    nt_compute_code_hash_from_abi(nil);
+
     nt_ton_wallet_free_ptr(nil);
 
     nt_unsigned_message_refresh_timeout(0, nil);
@@ -200,7 +252,7 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_unsigned_message_hash(0, nil);
 
-    nt_unsigned_message_sign(0, nil, nil);
+    nt_unsigned_message_sign(0, nil, nil, nil);
 
     nt_unsigned_message_free_ptr(nil);
 
@@ -208,6 +260,8 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_generate_key(nil);
 
+    nt_generate_key_pair();
+
     nt_get_hints(nil);
 
     nt_derive_from_phrase(nil, nil);
@@ -230,6 +284,10 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_ledger_connection_free_ptr(nil);
 
+    nt_get_transport_reconnect_backoff(nil);
+
+    nt_set_transport_reconnect_backoff(nil, 0, 0, 0, 0);
+
     nt_storage_create(0, 0, 0, 0, 0);
 
     nt_storage_free_ptr(nil);
@@ -240,43 +298,131 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_validate_address(nil);
 
-    nt_repack_address(nil);
+    nt_check_address(nil);
+
+    nt_repack_address(nil, nil);
+
+    nt_account_visual_seed(nil);
 
     nt_extract_public_key(nil);
 
+    nt_get_ever_wallet_custodians(nil);
+
     nt_code_to_tvc(nil);
 
     nt_split_tvc(nil);
 
+    nt_merge_tvc(nil, nil);
+
+    nt_get_transaction_fees(nil);
+
+    nt_get_transaction_aborted(nil);
+
+    nt_get_account_credit_phases(nil);
+
+    nt_decode_jetton_burn_notification(nil);
+
+    nt_decode_block_info(nil);
+
+    nt_pack_token_value(nil, 0);
+
+    nt_get_message_value(nil);
+
+    nt_parse_message(nil);
+
+    nt_validate_message_boc(nil, nil, nil);
+
+    nt_get_account_cells_total_count(nil);
+
+    nt_get_config_param_17(nil);
+
     nt_check_public_key(nil);
 
-    nt_run_local(nil, nil, nil, nil, 0);
+    nt_validate_public_key(nil);
+
+    nt_run_local(nil, nil, nil, nil, 0, nil, nil, 0, 0, 0);
+
+    nt_get_cache_stats();
+
+    nt_run_local_responsible_value(nil, nil, nil, nil, nil);
+
+    nt_execute_transaction_locally(nil, nil, nil, 0, 0);
+
+    nt_simulate_transaction(nil, nil, nil);
+
+    nt_batch_run_local(0, nil, nil, nil);
+
+    nt_run_local_with_config(nil, nil, nil, nil, 0, nil, nil);
 
     nt_get_expected_address(nil, nil, 0, nil, nil);
 
     nt_encode_internal_input(nil, nil, nil);
 
-    nt_create_external_message_without_signature(nil, nil, nil, nil, nil, 0);
+    nt_encode_transfer_with_comment(nil, nil, nil, nil, nil, 0, nil);
+
+    nt_encode_external_input(nil, nil, nil, nil, 0);
+
+    nt_create_external_message_without_signature(nil, nil, nil, nil, nil, nil, 0);
 
     nt_create_external_message(nil, nil, nil, nil, nil, nil, 0);
 
+    nt_prepare_sign_payload(nil, nil, nil, nil, 0);
+
+    nt_create_signed_external_message(nil, nil, nil, nil, 0);
+
     nt_parse_known_payload(nil);
 
-    nt_decode_input(nil, nil, nil, 0);
+    nt_build_bridge_burn_payload(nil, 0);
+
+    nt_get_contract_function_selector_from_body(nil);
+
+    nt_get_function_signature(nil, nil);
+
+    nt_get_function_id(nil, nil);
+
+    nt_get_event_signature(nil, nil);
+
+    nt_decode_input(nil, nil, nil, 0, 0, 0);
+
+    nt_decode_event(nil, nil, nil, 0);
+
+    nt_decode_message_body(nil, nil, 0);
 
-    nt_decode_event(nil, nil, nil);
+    nt_decode_output(nil, nil, nil, 0);
 
-    nt_decode_output(nil, nil, nil);
+    nt_decode_transaction(nil, nil, nil, 0);
 
-    nt_decode_transaction(nil, nil, nil);
+    nt_decode_transaction_with_state(nil, nil, nil);
 
-    nt_decode_transaction_events(nil, nil);
+    nt_decode_safe_multisig_transaction(nil, nil, nil);
+
+    nt_decode_transaction_events(nil, nil, nil);
 
     nt_get_boc_hash(nil);
 
+    nt_get_code_salt(nil);
+
+    nt_set_code_salt(nil, nil);
+
     nt_pack_into_cell(nil, nil);
 
-    nt_unpack_from_cell(nil, nil, 0);
+    nt_unpack_from_cell(nil, nil, 0, 0);
+
+    nt_unpack_from_cell_ex(nil, nil, 0, 0);
+
+    nt_clear_abi_cache();
+
+    nt_prepare_call_template(nil, nil, nil);
+
+    nt_encode_from_template(nil, nil);
+
+    nt_call_template_free_ptr(nil);
+
+    nt_get_runtime_metrics();
+
+    nt_set_metrics_enabled(0);
+
+    nt_self_test();
 
     nt_transport_get_contract_state(0, nil, nil, nil);
 
@@ -288,6 +434,18 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_transport_get_transaction(0, nil, nil, nil);
 
+    nt_get_library_cell(0, nil, nil, nil);
+
+    nt_prepare_unfreeze(0, nil, nil, nil, nil);
+
+    nt_get_network_params(0, nil, nil);
+
+    nt_await_transactions(0, nil, nil, nil, 0, nil);
+
+    nt_subscribe_blocks(0, nil, nil);
+
+    nt_block_subscription_free_ptr(nil);
+
     nt_gql_transport_create(nil);
 
     nt_gql_transport_get_latest_block_id(0, nil, nil);
@@ -298,8 +456,14 @@ public class NekotonFlutterPlugin: NSObject, FlutterPlugin {
 
     nt_gql_transport_free_ptr(nil);
 
+    nt_transport_download_history(0, 0, 0, nil, nil, nil, nil, nil, 0, 0);
+
     nt_jrpc_transport_create(nil);
 
     nt_jrpc_transport_free_ptr(nil);
+
+    nt_get_transport_subscription_count(nil, nil);
+
+    nt_get_global_subscription_count();
   }
 }
@@ -0,0 +1,133 @@
+use std::{
+    future::Future,
+    os::raw::{c_char, c_void},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use tokio::sync::Notify;
+
+use crate::{ffi_timer, HandleError, MatchResult};
+
+/// Token shared by Dart and the tasks of async calls, cancelling it more than once or after
+/// the call has completed does nothing
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<CancellationState>);
+
+#[derive(Default)]
+struct CancellationState {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancellationToken {
+    /// Clones the token behind an optional FFI handle
+    pub unsafe fn from_ptr(ptr: *mut c_void) -> Option<Self> {
+        (!ptr.is_null()).then(|| (&*(ptr as *mut CancellationToken)).clone())
+    }
+
+    pub fn cancel(&self) {
+        if !self.0.cancelled.swap(true, Ordering::SeqCst) {
+            self.0.notify.notify_waiters();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    pub async fn cancelled(&self) {
+        loop {
+            // Registered before the flag check, so a concurrent cancel can't be missed
+            let notified = self.0.notify.notified();
+
+            if self.is_cancelled() {
+                return;
+            }
+
+            notified.await;
+        }
+    }
+
+    /// Drives the future until it completes or the token is cancelled, whichever happens first
+    pub async fn run_until_cancelled<F>(&self, future: F) -> Option<F::Output>
+    where
+        F: Future,
+    {
+        tokio::select! {
+            output = future => Some(output),
+            _ = self.cancelled() => None,
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_cancellation_token_create() -> *mut c_char {
+    let _timer = ffi_timer!("nt_cancellation_token_create");
+
+    fn internal_fn() -> Result<serde_json::Value, String> {
+        let ptr = Box::into_raw(Box::new(CancellationToken::default()));
+
+        serde_json::to_value(ptr as usize).handle_error()
+    }
+
+    internal_fn().match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_cancellation_token_cancel(
+    cancellation_token: *mut c_void,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_cancellation_token_cancel");
+
+    let cancellation_token = &*(cancellation_token as *mut CancellationToken);
+
+    fn internal_fn(cancellation_token: &CancellationToken) -> Result<serde_json::Value, String> {
+        cancellation_token.cancel();
+
+        Ok(serde_json::Value::Null)
+    }
+
+    internal_fn(cancellation_token).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_cancellation_token_free_ptr(ptr: *mut c_void) {
+    println!("nt_cancellation_token_free_ptr");
+    Box::from_raw(ptr as *mut CancellationToken);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn run_until_cancelled_completes_without_cancel() {
+        let cancellation_token = CancellationToken::default();
+
+        let output = cancellation_token.run_until_cancelled(async { 42 }).await;
+
+        assert_eq!(output, Some(42));
+    }
+
+    #[tokio::test]
+    async fn run_until_cancelled_drops_pending_future() {
+        let cancellation_token = CancellationToken::default();
+        cancellation_token.cancel();
+
+        let output = cancellation_token
+            .run_until_cancelled(std::future::pending::<()>())
+            .await;
+
+        assert_eq!(output, None);
+    }
+
+    #[test]
+    fn cancelled_result_is_distinct_from_errors() {
+        let result = unsafe { std::ffi::CString::from_raw(crate::cancelled_result()) };
+
+        assert_eq!(result.to_str().unwrap(), r#"{"type":"cancelled"}"#);
+    }
+}
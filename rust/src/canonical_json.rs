@@ -0,0 +1,112 @@
+use std::{
+    os::raw::{c_char, c_uint},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use serde::Serialize;
+
+use crate::{ffi_timer, HandleError, MatchResult, ToStringFromPtr};
+
+static CANONICAL_JSON_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Serializes value for FFI results and port posts, keys are sorted and whitespace is removed
+/// when canonical output is enabled so equal payloads produce identical bytes
+pub fn to_json_string<T>(value: &T) -> serde_json::Result<String>
+where
+    T: Serialize,
+{
+    match CANONICAL_JSON_ENABLED.load(Ordering::Relaxed) {
+        true => serde_json::to_value(value).map(|e| canonicalize(&e)),
+        false => serde_json::to_string(value),
+    }
+}
+
+fn canonicalize(value: &serde_json::Value) -> String {
+    let mut output = String::new();
+    write_canonical(value, &mut output);
+    output
+}
+
+fn write_canonical(value: &serde_json::Value, output: &mut String) {
+    match value {
+        serde_json::Value::Object(object) => {
+            let mut entries = object.iter().collect::<Vec<_>>();
+            entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+            output.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                output.push_str(&serde_json::Value::String(key.to_owned()).to_string());
+                output.push(':');
+                write_canonical(value, output);
+            }
+            output.push('}');
+        },
+        serde_json::Value::Array(items) => {
+            output.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                write_canonical(item, output);
+            }
+            output.push(']');
+        },
+        value => output.push_str(&value.to_string()),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_set_canonical_json(enabled: c_uint) -> *mut c_char {
+    let _timer = ffi_timer!("nt_set_canonical_json");
+
+    let enabled = enabled != 0;
+
+    fn internal_fn(enabled: bool) -> Result<serde_json::Value, String> {
+        CANONICAL_JSON_ENABLED.store(enabled, Ordering::Relaxed);
+
+        Ok(serde_json::Value::Null)
+    }
+
+    internal_fn(enabled).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_canonicalize_json(json: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_canonicalize_json");
+
+    let json = json.to_string_from_ptr();
+
+    fn internal_fn(json: String) -> Result<serde_json::Value, String> {
+        let value = serde_json::from_str::<serde_json::Value>(&json).handle_error()?;
+
+        Ok(serde_json::Value::String(canonicalize(&value)))
+    }
+
+    internal_fn(json).match_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_output_round_trips() {
+        let value = serde_json::json!({
+            "b": [3, { "z": null, "a": "text with spaces" }],
+            "a": { "nested": true, "count": 18446744073709551615u64 },
+        });
+
+        let canonical = canonicalize(&value);
+        assert_eq!(
+            canonical,
+            r#"{"a":{"count":18446744073709551615,"nested":true},"b":[3,{"a":"text with spaces","z":null}]}"#
+        );
+
+        let parsed = serde_json::from_str::<serde_json::Value>(&canonical).unwrap();
+        assert_eq!(parsed, value);
+        assert_eq!(canonicalize(&parsed), canonical);
+    }
+}
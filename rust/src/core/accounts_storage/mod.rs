@@ -1,4 +1,4 @@
-mod models;
+pub(crate) mod models;
 
 use std::{
     os::raw::{c_char, c_longlong, c_void},
@@ -9,16 +9,19 @@ use allo_isolate::Isolate;
 use nekoton::{core::accounts_storage::AccountsStorage, external::Storage};
 
 use crate::{
-    core::accounts_storage::models::{AccountToAddHelper, AssetsListHelper},
+    core::accounts_storage::models::{AccountToAddHelper, AssetsListOutput},
     external::storage::StorageImpl,
-    parse_address, runtime, HandleError, MatchResult, PostWithResult, ToStringFromPtr, RUNTIME,
+    ffi_timer, parse_address, spawn_ffi_task, HandleError, MatchResult, PostWithResult,
+    ToStringFromPtr,
 };
 
 #[no_mangle]
 pub unsafe extern "C" fn nt_accounts_storage_create(result_port: c_longlong, storage: *mut c_void) {
     let storage = (&*(storage as *mut Arc<StorageImpl>)).clone();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_accounts_storage_create");
+
         async fn internal_fn(storage: Arc<dyn Storage>) -> Result<serde_json::Value, String> {
             let accounts_storage = AccountsStorage::load(storage).await.handle_error()?;
 
@@ -40,7 +43,9 @@ pub unsafe extern "C" fn nt_accounts_storage_entries(
 ) {
     let accounts_storage = &*(accounts_storage as *mut AccountsStorage);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_accounts_storage_entries");
+
         async fn internal_fn(
             accounts_storage: &AccountsStorage,
         ) -> Result<serde_json::Value, String> {
@@ -51,7 +56,7 @@ pub unsafe extern "C" fn nt_accounts_storage_entries(
                 .values()
                 .into_iter()
                 .cloned()
-                .map(AssetsListHelper)
+                .map(AssetsListOutput::from)
                 .collect::<Vec<_>>();
 
             serde_json::to_value(&entries).handle_error()
@@ -73,7 +78,9 @@ pub unsafe extern "C" fn nt_accounts_storage_add_account(
 
     let new_account = new_account.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_accounts_storage_add_account");
+
         async fn internal_fn(
             accounts_storage: &AccountsStorage,
             new_account: String,
@@ -87,7 +94,7 @@ pub unsafe extern "C" fn nt_accounts_storage_add_account(
                 .await
                 .handle_error()?;
 
-            serde_json::to_value(&AssetsListHelper(entry)).handle_error()
+            serde_json::to_value(&AssetsListOutput::from(entry)).handle_error()
         }
 
         let result = internal_fn(accounts_storage, new_account)
@@ -108,7 +115,9 @@ pub unsafe extern "C" fn nt_accounts_storage_add_accounts(
 
     let new_accounts = new_accounts.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_accounts_storage_add_accounts");
+
         async fn internal_fn(
             accounts_storage: &AccountsStorage,
             new_accounts: String,
@@ -124,7 +133,7 @@ pub unsafe extern "C" fn nt_accounts_storage_add_accounts(
                 .await
                 .handle_error()?
                 .into_iter()
-                .map(AssetsListHelper)
+                .map(AssetsListOutput::from)
                 .collect::<Vec<_>>();
 
             serde_json::to_value(&entries).handle_error()
@@ -150,7 +159,9 @@ pub unsafe extern "C" fn nt_accounts_storage_rename_account(
     let account = account.to_string_from_ptr();
     let name = name.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_accounts_storage_rename_account");
+
         async fn internal_fn(
             accounts_storage: &AccountsStorage,
             account: String,
@@ -161,7 +172,7 @@ pub unsafe extern "C" fn nt_accounts_storage_rename_account(
                 .await
                 .handle_error()?;
 
-            serde_json::to_value(&AssetsListHelper(entry)).handle_error()
+            serde_json::to_value(&AssetsListOutput::from(entry)).handle_error()
         }
 
         let result = internal_fn(accounts_storage, account, name)
@@ -186,7 +197,9 @@ pub unsafe extern "C" fn nt_accounts_storage_add_token_wallet(
     let network_group = network_group.to_string_from_ptr();
     let root_token_contract = root_token_contract.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_accounts_storage_add_token_wallet");
+
         async fn internal_fn(
             accounts_storage: &AccountsStorage,
             account: String,
@@ -200,7 +213,7 @@ pub unsafe extern "C" fn nt_accounts_storage_add_token_wallet(
                 .await
                 .handle_error()?;
 
-            serde_json::to_value(&AssetsListHelper(entry)).handle_error()
+            serde_json::to_value(&AssetsListOutput::from(entry)).handle_error()
         }
 
         let result = internal_fn(
@@ -230,7 +243,9 @@ pub unsafe extern "C" fn nt_accounts_storage_remove_token_wallet(
     let network_group = network_group.to_string_from_ptr();
     let root_token_contract = root_token_contract.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_accounts_storage_remove_token_wallet");
+
         async fn internal_fn(
             accounts_storage: &AccountsStorage,
             account: String,
@@ -244,7 +259,7 @@ pub unsafe extern "C" fn nt_accounts_storage_remove_token_wallet(
                 .await
                 .handle_error()?;
 
-            serde_json::to_value(&AssetsListHelper(entry)).handle_error()
+            serde_json::to_value(&AssetsListOutput::from(entry)).handle_error()
         }
 
         let result = internal_fn(
@@ -270,7 +285,9 @@ pub unsafe extern "C" fn nt_accounts_storage_remove_account(
 
     let account = account.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_accounts_storage_remove_account");
+
         async fn internal_fn(
             accounts_storage: &AccountsStorage,
             account: String,
@@ -279,7 +296,7 @@ pub unsafe extern "C" fn nt_accounts_storage_remove_account(
                 .remove_account(&account)
                 .await
                 .handle_error()?
-                .map(AssetsListHelper);
+                .map(AssetsListOutput::from);
 
             serde_json::to_value(entry).handle_error()
         }
@@ -300,7 +317,9 @@ pub unsafe extern "C" fn nt_accounts_storage_remove_accounts(
 
     let accounts = accounts.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_accounts_storage_remove_accounts");
+
         async fn internal_fn(
             accounts_storage: &AccountsStorage,
             accounts: String,
@@ -312,7 +331,7 @@ pub unsafe extern "C" fn nt_accounts_storage_remove_accounts(
                 .await
                 .handle_error()?
                 .into_iter()
-                .map(AssetsListHelper)
+                .map(AssetsListOutput::from)
                 .collect::<Vec<_>>();
 
             serde_json::to_value(&entries).handle_error()
@@ -331,7 +350,9 @@ pub unsafe extern "C" fn nt_accounts_storage_clear(
 ) {
     let accounts_storage = &*(accounts_storage as *mut AccountsStorage);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_accounts_storage_clear");
+
         async fn internal_fn(
             accounts_storage: &AccountsStorage,
         ) -> Result<serde_json::Value, String> {
@@ -353,7 +374,9 @@ pub unsafe extern "C" fn nt_accounts_storage_reload(
 ) {
     let accounts_storage = &*(accounts_storage as *mut AccountsStorage);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_accounts_storage_reload");
+
         async fn internal_fn(
             accounts_storage: &AccountsStorage,
         ) -> Result<serde_json::Value, String> {
@@ -370,6 +393,8 @@ pub unsafe extern "C" fn nt_accounts_storage_reload(
 
 #[no_mangle]
 pub unsafe extern "C" fn nt_accounts_storage_verify_data(data: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_accounts_storage_verify_data");
+
     let data = data.to_string_from_ptr();
 
     fn internal_fn(data: String) -> Result<serde_json::Value, String> {
@@ -1,14 +1,16 @@
 use std::collections::HashMap;
 
 use nekoton::core::{
-    accounts_storage::{AccountToAdd, AdditionalAssets, AssetsList, NetworkGroup, TonWalletAsset},
+    accounts_storage::{
+        AccountToAdd, AdditionalAssets, AssetsList, DePoolAsset, NetworkGroup, TonWalletAsset,
+    },
     ton_wallet::WalletType,
 };
 use nekoton_utils::{serde_address, serde_optional_address, serde_public_key};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use ton_block::MsgAddressInt;
 
-use crate::core::ton_wallet::models::WalletTypeDef;
+use crate::{core::ton_wallet::models::WalletTypeDef, helpers::account_visual_seed};
 
 #[derive(Deserialize)]
 pub struct AccountToAddHelper(#[serde(with = "AccountToAddDef")] pub AccountToAdd);
@@ -32,15 +34,78 @@ pub struct AccountToAddDef {
 #[derive(Serialize)]
 pub struct AssetsListHelper(#[serde(with = "AssetsListDef")] pub AssetsList);
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetsListOutput {
+    #[serde(flatten)]
+    pub entry: AssetsListHelper,
+    pub visual_seed: String,
+}
+
+impl From<AssetsList> for AssetsListOutput {
+    fn from(entry: AssetsList) -> Self {
+        let visual_seed = hex::encode(account_visual_seed(&entry.ton_wallet.address));
+
+        Self {
+            entry: AssetsListHelper(entry),
+            visual_seed,
+        }
+    }
+}
+
 #[derive(Serialize)]
 #[serde(remote = "AssetsList", rename_all = "camelCase")]
 pub struct AssetsListDef {
     pub name: String,
     #[serde(with = "TonWalletAssetDef")]
     pub ton_wallet: TonWalletAsset,
+    #[serde(serialize_with = "serialize_additional_assets")]
     pub additional_assets: HashMap<NetworkGroup, AdditionalAssets>,
 }
 
+fn serialize_additional_assets<S>(
+    additional_assets: &HashMap<NetworkGroup, AdditionalAssets>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_map(
+        additional_assets
+            .iter()
+            .map(|(group, assets)| (group, AdditionalAssetsOutput::from(assets))),
+    )
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdditionalAssetsOutput<'a> {
+    pub token_wallets: Vec<TokenWalletAssetOutput>,
+    pub depools: &'a [DePoolAsset],
+}
+
+impl<'a> From<&'a AdditionalAssets> for AdditionalAssetsOutput<'a> {
+    fn from(assets: &'a AdditionalAssets) -> Self {
+        Self {
+            token_wallets: assets
+                .token_wallets
+                .iter()
+                .map(|e| TokenWalletAssetOutput {
+                    root_token_contract: e.root_token_contract.clone(),
+                })
+                .collect(),
+            depools: &assets.depools,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenWalletAssetOutput {
+    #[serde(with = "serde_address")]
+    pub root_token_contract: MsgAddressInt,
+}
+
 #[derive(Serialize)]
 #[serde(remote = "TonWalletAsset", rename_all = "camelCase")]
 pub struct TonWalletAssetDef {
@@ -51,3 +116,73 @@ pub struct TonWalletAssetDef {
     #[serde(with = "WalletTypeDef")]
     pub contract: WalletType,
 }
+
+#[cfg(test)]
+mod tests {
+    use nekoton::core::{accounts_storage::TokenWalletAsset, ton_wallet::MultisigType};
+
+    use super::*;
+
+    #[test]
+    fn assets_list_schema() {
+        let address = MsgAddressInt::default();
+        let public_key = ed25519_dalek::PublicKey::from_bytes(
+            &hex::decode("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+                .unwrap(),
+        )
+        .unwrap();
+
+        let mut additional_assets = HashMap::new();
+        additional_assets.insert(
+            "mainnet_venom".to_owned(),
+            AdditionalAssets {
+                token_wallets: vec![TokenWalletAsset {
+                    root_token_contract: address.clone(),
+                }],
+                depools: Vec::new(),
+            },
+        );
+
+        let output = AssetsListOutput::from(AssetsList {
+            name: "Main".to_owned(),
+            ton_wallet: TonWalletAsset {
+                address: address.clone(),
+                public_key,
+                contract: WalletType::Multisig(MultisigType::SafeMultisigWallet),
+            },
+            additional_assets,
+        });
+
+        let json = serde_json::to_value(&output).unwrap();
+
+        let mut keys = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(
+            keys,
+            ["additionalAssets", "name", "tonWallet", "visualSeed"]
+        );
+
+        let mut keys = json["tonWallet"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(keys, ["address", "contract", "publicKey"]);
+
+        // Network group names are data and keep their case
+        assert_eq!(
+            json["additionalAssets"]["mainnet_venom"],
+            serde_json::json!({
+                "tokenWallets": [{ "rootTokenContract": address.to_string() }],
+                "depools": [],
+            })
+        );
+    }
+}
@@ -2,12 +2,23 @@ use allo_isolate::Isolate;
 use async_trait::async_trait;
 use nekoton::core::{
     generic_contract::GenericContractSubscriptionHandler,
-    models::{ContractState, PendingTransaction, Transaction, TransactionsBatchInfo},
+    models::{
+        ContractState, PendingTransaction, Transaction, TransactionsBatchInfo,
+        TransactionsBatchType,
+    },
 };
 
-use crate::core::models::{
-    OnMessageExpiredPayload, OnMessageSentPayload, OnStateChangedPayload,
-    OnTransactionsFoundPayload,
+use crate::{
+    canonical_json::to_json_string,
+    core::{
+        models::{
+            OnMessageExpiredPayload, OnMessageSentPayload, OnStateChangedPayload,
+            OnTransactionsFoundPayload,
+        },
+        send_journal,
+    },
+    helpers::abi::getter_cache,
+    metrics::{self, SubscriptionKind},
 };
 
 pub struct GenericContractSubscriptionHandlerImpl {
@@ -24,6 +35,8 @@ impl GenericContractSubscriptionHandlerImpl {
         on_state_changed_port: i64,
         on_transactions_found_port: i64,
     ) -> Self {
+        metrics::subscription_created(SubscriptionKind::GenericContract);
+
         Self {
             on_message_sent_port: Isolate::new(on_message_sent_port),
             on_message_expired_port: Isolate::new(on_message_expired_port),
@@ -33,6 +46,12 @@ impl GenericContractSubscriptionHandlerImpl {
     }
 }
 
+impl Drop for GenericContractSubscriptionHandlerImpl {
+    fn drop(&mut self) {
+        metrics::subscription_dropped(SubscriptionKind::GenericContract);
+    }
+}
+
 #[async_trait]
 impl GenericContractSubscriptionHandler for GenericContractSubscriptionHandlerImpl {
     fn on_message_sent(
@@ -40,28 +59,38 @@ impl GenericContractSubscriptionHandler for GenericContractSubscriptionHandlerIm
         pending_transaction: PendingTransaction,
         transaction: Option<Transaction>,
     ) {
-        let payload = serde_json::to_string(&OnMessageSentPayload {
+        send_journal::record_send_resolved(&pending_transaction.message_hash);
+
+        let payload = to_json_string(&OnMessageSentPayload {
             pending_transaction,
             transaction,
         })
         .unwrap();
 
         self.on_message_sent_port.post(payload);
+
+        metrics::record_event_posted();
     }
 
     fn on_message_expired(&self, pending_transaction: PendingTransaction) {
-        let payload = serde_json::to_string(&OnMessageExpiredPayload {
+        send_journal::record_send_resolved(&pending_transaction.message_hash);
+
+        let payload = to_json_string(&OnMessageExpiredPayload {
             pending_transaction,
         })
         .unwrap();
 
         self.on_message_expired_port.post(payload);
+
+        metrics::record_event_posted();
     }
 
     fn on_state_changed(&self, new_state: ContractState) {
-        let payload = serde_json::to_string(&OnStateChangedPayload { new_state }).unwrap();
+        let payload = to_json_string(&OnStateChangedPayload { new_state }).unwrap();
 
         self.on_state_changed_port.post(payload);
+
+        metrics::record_event_posted();
     }
 
     fn on_transactions_found(
@@ -69,12 +98,21 @@ impl GenericContractSubscriptionHandler for GenericContractSubscriptionHandlerIm
         transactions: Vec<Transaction>,
         batch_info: TransactionsBatchInfo,
     ) {
-        let payload = serde_json::to_string(&OnTransactionsFoundPayload {
+        if matches!(batch_info.batch_type, TransactionsBatchType::New) {
+            transactions
+                .iter()
+                .filter_map(|e| e.in_msg.dst.as_ref())
+                .for_each(getter_cache::invalidate_address);
+        }
+
+        let payload = to_json_string(&OnTransactionsFoundPayload {
             transactions,
             batch_info,
         })
         .unwrap();
 
         self.on_transactions_found_port.post(payload);
+
+        metrics::record_event_posted();
     }
 }
@@ -15,9 +15,21 @@ use tokio::sync::RwLock;
 use ton_block::{Block, Deserializable};
 
 use crate::{
-    clock, core::generic_contract::handler::GenericContractSubscriptionHandlerImpl, parse_address,
-    runtime, transport::match_transport, HandleError, MatchResult, PostWithResult, ToStringFromPtr,
-    CLOCK, RUNTIME,
+    cancellation::CancellationToken,
+    clock,
+    core::{
+        generic_contract::handler::GenericContractSubscriptionHandlerImpl,
+        send_journal,
+        subscription_errors::{remove_subscription_error_counter, track_subscription_result},
+    },
+    ffi_timer, parse_address, spawn_cancellable_ffi_task, spawn_ffi_task,
+    transport::{
+        match_transport,
+        subscription_counts::{
+            track_subscription_created, track_subscription_destroyed, transport_key,
+        },
+    },
+    HandleError, MatchResult, PostWithResult, ToStringFromPtr, CLOCK,
 };
 
 #[no_mangle]
@@ -38,7 +50,9 @@ pub unsafe extern "C" fn nt_generic_contract_subscribe(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_generic_contract_subscribe");
+
         async fn internal_fn(
             on_message_sent_port: i64,
             on_message_expired_port: i64,
@@ -48,6 +62,8 @@ pub unsafe extern "C" fn nt_generic_contract_subscribe(
             address: String,
             preload_transactions: bool,
         ) -> Result<serde_json::Value, String> {
+            let transport_key = transport_key(&transport);
+
             let address = parse_address(&address)?;
 
             let handler = Arc::new(GenericContractSubscriptionHandlerImpl::new(
@@ -69,6 +85,8 @@ pub unsafe extern "C" fn nt_generic_contract_subscribe(
 
             let ptr = Box::into_raw(Box::new(Arc::new(RwLock::new(generic_contract))));
 
+            track_subscription_created(ptr as usize, transport_key);
+
             serde_json::to_value(ptr as usize).handle_error()
         }
 
@@ -95,7 +113,9 @@ pub unsafe extern "C" fn nt_generic_contract_address(
 ) {
     let generic_contract = &*(generic_contract as *mut RwLock<GenericContract>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_generic_contract_address");
+
         fn internal_fn(generic_contract: &GenericContract) -> Result<serde_json::Value, String> {
             let address = generic_contract.address().to_string();
 
@@ -117,7 +137,9 @@ pub unsafe extern "C" fn nt_generic_contract_contract_state(
 ) {
     let generic_contract = &*(generic_contract as *mut RwLock<GenericContract>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_generic_contract_contract_state");
+
         fn internal_fn(generic_contract: &GenericContract) -> Result<serde_json::Value, String> {
             let contract_state = generic_contract.contract_state();
 
@@ -139,7 +161,9 @@ pub unsafe extern "C" fn nt_generic_contract_pending_transactions(
 ) {
     let generic_contract = &*(generic_contract as *mut RwLock<GenericContract>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_generic_contract_pending_transactions");
+
         fn internal_fn(generic_contract: &GenericContract) -> Result<serde_json::Value, String> {
             let pending_transactions = generic_contract.pending_transactions();
 
@@ -161,7 +185,9 @@ pub unsafe extern "C" fn nt_generic_contract_polling_method(
 ) {
     let generic_contract = &*(generic_contract as *mut RwLock<GenericContract>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_generic_contract_polling_method");
+
         fn internal_fn(generic_contract: &GenericContract) -> Result<serde_json::Value, String> {
             let polling_method = generic_contract.polling_method();
 
@@ -186,7 +212,9 @@ pub unsafe extern "C" fn nt_generic_contract_estimate_fees(
 
     let signed_message = signed_message.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_generic_contract_estimate_fees");
+
         async fn internal_fn(
             generic_contract: &mut GenericContract,
             signed_message: String,
@@ -224,7 +252,9 @@ pub unsafe extern "C" fn nt_generic_contract_send(
 
     let signed_message = signed_message.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_generic_contract_send");
+
         async fn internal_fn(
             generic_contract: &mut GenericContract,
             signed_message: String,
@@ -232,11 +262,16 @@ pub unsafe extern "C" fn nt_generic_contract_send(
             let signed_message =
                 serde_json::from_str::<SignedMessage>(&signed_message).handle_error()?;
 
+            send_journal::record_send_started(&signed_message.message, signed_message.expire_at)
+                .await?;
+
             let pending_transaction = generic_contract
                 .send(&signed_message.message, signed_message.expire_at)
                 .await
                 .handle_error()?;
 
+            send_journal::record_send_finished(&signed_message.message).await;
+
             serde_json::to_value(&pending_transaction).handle_error()
         }
 
@@ -262,7 +297,9 @@ pub unsafe extern "C" fn nt_generic_contract_execute_transaction_locally(
     let signed_message = signed_message.to_string_from_ptr();
     let options = options.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_generic_contract_execute_transaction_locally");
+
         async fn internal_fn(
             generic_contract: &mut GenericContract,
             signed_message: String,
@@ -298,9 +335,13 @@ pub unsafe extern "C" fn nt_generic_contract_refresh(
     result_port: c_longlong,
     generic_contract: *mut c_void,
 ) {
+    let subscription = generic_contract as usize;
+
     let generic_contract = &*(generic_contract as *mut RwLock<GenericContract>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_generic_contract_refresh");
+
         async fn internal_fn(
             generic_contract: &mut GenericContract,
         ) -> Result<serde_json::Value, String> {
@@ -311,7 +352,11 @@ pub unsafe extern "C" fn nt_generic_contract_refresh(
 
         let mut generic_contract = generic_contract.write().await;
 
-        let result = internal_fn(&mut generic_contract).await.match_result();
+        let result = internal_fn(&mut generic_contract).await;
+
+        track_subscription_result(subscription, &result);
+
+        let result = result.match_result();
 
         Isolate::new(result_port).post_with_result(result).unwrap();
     });
@@ -322,12 +367,19 @@ pub unsafe extern "C" fn nt_generic_contract_preload_transactions(
     result_port: c_longlong,
     generic_contract: *mut c_void,
     from_lt: *mut c_char,
+    cancellation_token: *mut c_void,
 ) {
+    let subscription = generic_contract as usize;
+
     let generic_contract = &*(generic_contract as *mut RwLock<GenericContract>);
 
     let from_lt = from_lt.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    let cancellation_token = CancellationToken::from_ptr(cancellation_token);
+
+    spawn_cancellable_ffi_task(result_port, cancellation_token, async move {
+        let _timer = ffi_timer!("nt_generic_contract_preload_transactions");
+
         async fn internal_fn(
             generic_contract: &mut GenericContract,
             from_lt: String,
@@ -344,9 +396,11 @@ pub unsafe extern "C" fn nt_generic_contract_preload_transactions(
 
         let mut generic_contract = generic_contract.write().await;
 
-        let result = internal_fn(&mut generic_contract, from_lt)
-            .await
-            .match_result();
+        let result = internal_fn(&mut generic_contract, from_lt).await;
+
+        track_subscription_result(subscription, &result);
+
+        let result = result.match_result();
 
         Isolate::new(result_port).post_with_result(result).unwrap();
     });
@@ -358,11 +412,15 @@ pub unsafe extern "C" fn nt_generic_contract_handle_block(
     generic_contract: *mut c_void,
     block: *mut c_char,
 ) {
+    let subscription = generic_contract as usize;
+
     let generic_contract = &*(generic_contract as *mut RwLock<GenericContract>);
 
     let block = block.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_generic_contract_handle_block");
+
         async fn internal_fn(
             generic_contract: &mut GenericContract,
             block: String,
@@ -376,9 +434,11 @@ pub unsafe extern "C" fn nt_generic_contract_handle_block(
 
         let mut generic_contract = generic_contract.write().await;
 
-        let result = internal_fn(&mut generic_contract, block)
-            .await
-            .match_result();
+        let result = internal_fn(&mut generic_contract, block).await;
+
+        track_subscription_result(subscription, &result);
+
+        let result = result.match_result();
 
         Isolate::new(result_port).post_with_result(result).unwrap();
     });
@@ -387,5 +447,7 @@ pub unsafe extern "C" fn nt_generic_contract_handle_block(
 #[no_mangle]
 pub unsafe extern "C" fn nt_generic_contract_free_ptr(ptr: *mut c_void) {
     println!("nt_generic_contract_free_ptr");
+    remove_subscription_error_counter(ptr as usize);
+    track_subscription_destroyed(ptr as usize);
     Box::from_raw(ptr as *mut Arc<RwLock<GenericContract>>);
 }
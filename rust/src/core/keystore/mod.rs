@@ -1,3 +1,5 @@
+pub(crate) mod models;
+
 use std::{
     os::raw::{c_char, c_longlong, c_ulonglong, c_void},
     sync::Arc,
@@ -8,28 +10,31 @@ use allo_isolate::Isolate;
 use nekoton::{
     core::keystore::{KeyStore, KeyStoreBuilder},
     crypto::{
-        DerivedKeyCreateInput, DerivedKeyExportParams, DerivedKeyGetPublicKeys,
+        derive_from_phrase, DerivedKeyCreateInput, DerivedKeyExportParams, DerivedKeyGetPublicKeys,
         DerivedKeySignParams, DerivedKeySigner, DerivedKeyUpdateParams, EncryptedData,
         EncryptedKeyCreateInput, EncryptedKeyGetPublicKeys, EncryptedKeyPassword,
         EncryptedKeySigner, EncryptedKeyUpdateParams, EncryptionAlgorithm, LedgerKeyCreateInput,
-        LedgerKeyGetPublicKeys, LedgerKeySigner, LedgerSignInput, LedgerUpdateKeyInput, Signature,
+        LedgerKeyGetPublicKeys, LedgerKeySigner, LedgerSignInput, LedgerUpdateKeyInput, Password,
+        Signature,
     },
     external::Storage,
 };
 use sha2::Digest;
 
 use crate::{
+    core::keystore::models::KeyStoreEntryOutput,
     crypto::{
         derived_key::DERIVED_KEY_SIGNER_NAME,
         encrypted_key::{
-            EncryptedKeyCreateInputHelper, EncryptedKeyExportOutputHelper,
-            ENCRYPTED_KEY_SIGNER_NAME,
+            decrypt_exported_key, encrypt_exported_key, EncryptedKeyCreateInputHelper,
+            EncryptedKeyExportOutputHelper, ExportedKey, ENCRYPTED_KEY_SIGNER_NAME,
         },
         ledger_key::LEDGER_KEY_SIGNER_NAME,
         models::{SignatureParts, SignedData, SignedDataRaw},
     },
     external::{ledger_connection::LedgerConnectionImpl, storage::StorageImpl},
-    parse_public_key, runtime, HandleError, MatchResult, PostWithResult, ToStringFromPtr, RUNTIME,
+    ffi_timer, parse_public_key, spawn_ffi_task, HandleError, MatchResult, PostWithResult,
+    ToStringFromPtr,
 };
 
 #[no_mangle]
@@ -48,7 +53,9 @@ pub unsafe extern "C" fn nt_keystore_create(
 
     let signers = signers.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_create");
+
         async fn internal_fn(
             storage: Arc<dyn Storage>,
             connection: Option<Arc<LedgerConnectionImpl>>,
@@ -77,10 +84,17 @@ pub unsafe extern "C" fn nt_keystore_create(
 pub unsafe extern "C" fn nt_keystore_entries(result_port: c_longlong, keystore: *mut c_void) {
     let keystore = &*(keystore as *mut KeyStore);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_entries");
+
         async fn internal_fn(keystore: &KeyStore) -> Result<serde_json::Value, String> {
             let entries = keystore.get_entries().await;
 
+            let entries = entries
+                .into_iter()
+                .map(KeyStoreEntryOutput::from)
+                .collect::<Vec<_>>();
+
             serde_json::to_value(&entries).handle_error()
         }
 
@@ -102,7 +116,9 @@ pub unsafe extern "C" fn nt_keystore_add_key(
     let signer = signer.to_string_from_ptr();
     let input = input.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_add_key");
+
         async fn internal_fn(
             keystore: &KeyStore,
             signer: String,
@@ -139,7 +155,7 @@ pub unsafe extern "C" fn nt_keystore_add_key(
                 panic!()
             };
 
-            serde_json::to_value(&entry).handle_error()
+            serde_json::to_value(&KeyStoreEntryOutput::from(entry)).handle_error()
         }
 
         let result = internal_fn(keystore, signer, input).await.match_result();
@@ -160,7 +176,9 @@ pub unsafe extern "C" fn nt_keystore_add_keys(
     let signer = signer.to_string_from_ptr();
     let input = input.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_add_keys");
+
         async fn internal_fn(
             keystore: &KeyStore,
             signer: String,
@@ -201,6 +219,11 @@ pub unsafe extern "C" fn nt_keystore_add_keys(
                 panic!()
             };
 
+            let entries = entries
+                .into_iter()
+                .map(KeyStoreEntryOutput::from)
+                .collect::<Vec<_>>();
+
             serde_json::to_value(&entries).handle_error()
         }
 
@@ -222,7 +245,9 @@ pub unsafe extern "C" fn nt_keystore_update_key(
     let signer = signer.to_string_from_ptr();
     let input = input.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_update_key");
+
         async fn internal_fn(
             keystore: &KeyStore,
             signer: String,
@@ -255,7 +280,7 @@ pub unsafe extern "C" fn nt_keystore_update_key(
                 panic!()
             };
 
-            serde_json::to_value(&entry).handle_error()
+            serde_json::to_value(&KeyStoreEntryOutput::from(entry)).handle_error()
         }
 
         let result = internal_fn(keystore, signer, input).await.match_result();
@@ -276,7 +301,9 @@ pub unsafe extern "C" fn nt_keystore_export_key(
     let signer = signer.to_string_from_ptr();
     let input = input.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_export_key");
+
         async fn internal_fn(
             keystore: &KeyStore,
             signer: String,
@@ -312,6 +339,109 @@ pub unsafe extern "C" fn nt_keystore_export_key(
     });
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_keystore_export_entry(
+    result_port: c_longlong,
+    keystore: *mut c_void,
+    public_key: *mut c_char,
+    password: *mut c_char,
+    export_password: *mut c_char,
+) {
+    let keystore = &*(keystore as *mut KeyStore);
+
+    let public_key = public_key.to_string_from_ptr();
+    let password = password.to_string_from_ptr();
+    let export_password = export_password.to_string_from_ptr();
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_export_entry");
+
+        async fn internal_fn(
+            keystore: &KeyStore,
+            public_key: String,
+            password: String,
+            export_password: String,
+        ) -> Result<serde_json::Value, String> {
+            let public_key = parse_public_key(&public_key)?;
+            let password = serde_json::from_str::<Password>(&password).handle_error()?;
+
+            let output = keystore
+                .export_key::<EncryptedKeySigner>(EncryptedKeyPassword {
+                    public_key,
+                    password,
+                })
+                .await
+                .handle_error()?;
+
+            let exported_key = encrypt_exported_key(&public_key, output, &export_password)?;
+
+            serde_json::to_value(&exported_key).handle_error()
+        }
+
+        let result = internal_fn(keystore, public_key, password, export_password)
+            .await
+            .match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_keystore_import_entry(
+    result_port: c_longlong,
+    keystore: *mut c_void,
+    exported_key: *mut c_char,
+    export_password: *mut c_char,
+    new_password: *mut c_char,
+) {
+    let keystore = &*(keystore as *mut KeyStore);
+
+    let exported_key = exported_key.to_string_from_ptr();
+    let export_password = export_password.to_string_from_ptr();
+    let new_password = new_password.to_string_from_ptr();
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_import_entry");
+
+        async fn internal_fn(
+            keystore: &KeyStore,
+            exported_key: String,
+            export_password: String,
+            new_password: String,
+        ) -> Result<serde_json::Value, String> {
+            let exported_key = serde_json::from_str::<ExportedKey>(&exported_key).handle_error()?;
+            let new_password = serde_json::from_str::<Password>(&new_password).handle_error()?;
+
+            let output = decrypt_exported_key(&exported_key, &export_password)?;
+
+            let keypair = derive_from_phrase(output.phrase.unsecure(), output.mnemonic_type)
+                .handle_error()?;
+
+            if hex::encode(keypair.public.as_bytes()) != exported_key.public_key {
+                return Err("Public key mismatch").handle_error();
+            }
+
+            let entry = keystore
+                .add_key::<EncryptedKeySigner>(EncryptedKeyCreateInput {
+                    name: None,
+                    phrase: output.phrase,
+                    mnemonic_type: output.mnemonic_type,
+                    password: new_password,
+                })
+                .await
+                .handle_error()?;
+
+            serde_json::to_value(&KeyStoreEntryOutput::from(entry)).handle_error()
+        }
+
+        let result = internal_fn(keystore, exported_key, export_password, new_password)
+            .await
+            .match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_keystore_get_public_keys(
     result_port: c_longlong,
@@ -324,7 +454,9 @@ pub unsafe extern "C" fn nt_keystore_get_public_keys(
     let signer = signer.to_string_from_ptr();
     let input = input.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_get_public_keys");
+
         async fn internal_fn(
             keystore: &KeyStore,
             signer: String,
@@ -398,7 +530,9 @@ pub unsafe extern "C" fn nt_keystore_encrypt(
     let algorithm = algorithm.to_string_from_ptr();
     let input = input.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_encrypt");
+
         async fn internal_fn(
             keystore: &KeyStore,
             signer: String,
@@ -468,7 +602,9 @@ pub unsafe extern "C" fn nt_keystore_decrypt(
     let data = data.to_string_from_ptr();
     let input = input.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_decrypt");
+
         async fn internal_fn(
             keystore: &KeyStore,
             signer: String,
@@ -529,7 +665,9 @@ pub unsafe extern "C" fn nt_keystore_sign(
     let data = data.to_string_from_ptr();
     let input = input.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_sign");
+
         async fn internal_fn(
             keystore: &KeyStore,
             signer: String,
@@ -567,7 +705,9 @@ pub unsafe extern "C" fn nt_keystore_sign_data(
     let data = data.to_string_from_ptr();
     let input = input.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_sign_data");
+
         async fn internal_fn(
             keystore: &KeyStore,
             signer: String,
@@ -614,7 +754,9 @@ pub unsafe extern "C" fn nt_keystore_sign_data_raw(
     let data = data.to_string_from_ptr();
     let input = input.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_sign_data_raw");
+
         async fn internal_fn(
             keystore: &KeyStore,
             signer: String,
@@ -655,7 +797,9 @@ pub unsafe extern "C" fn nt_keystore_remove_key(
 
     let public_key = public_key.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_remove_key");
+
         async fn internal_fn(
             keystore: &KeyStore,
             public_key: String,
@@ -664,6 +808,8 @@ pub unsafe extern "C" fn nt_keystore_remove_key(
 
             let entry = keystore.remove_key(&public_key).await.handle_error()?;
 
+            let entry = entry.map(KeyStoreEntryOutput::from);
+
             serde_json::to_value(entry).handle_error()
         }
 
@@ -683,7 +829,9 @@ pub unsafe extern "C" fn nt_keystore_remove_keys(
 
     let public_keys = public_keys.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_remove_keys");
+
         async fn internal_fn(
             keystore: &KeyStore,
             public_keys: String,
@@ -696,6 +844,11 @@ pub unsafe extern "C" fn nt_keystore_remove_keys(
 
             let entries = keystore.remove_keys(&public_keys).await.handle_error()?;
 
+            let entries = entries
+                .into_iter()
+                .map(KeyStoreEntryOutput::from)
+                .collect::<Vec<_>>();
+
             serde_json::to_value(&entries).handle_error()
         }
 
@@ -711,6 +864,8 @@ pub unsafe extern "C" fn nt_keystore_is_password_cached(
     public_key: *mut c_char,
     duration: c_ulonglong,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_keystore_is_password_cached");
+
     let keystore = &*(keystore as *mut KeyStore);
 
     let public_key = public_key.to_string_from_ptr();
@@ -736,7 +891,9 @@ pub unsafe extern "C" fn nt_keystore_is_password_cached(
 pub unsafe extern "C" fn nt_keystore_clear(result_port: c_longlong, keystore: *mut c_void) {
     let keystore = &*(keystore as *mut KeyStore);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_clear");
+
         async fn internal_fn(keystore: &KeyStore) -> Result<serde_json::Value, String> {
             keystore.clear().await.handle_error()?;
 
@@ -753,7 +910,9 @@ pub unsafe extern "C" fn nt_keystore_clear(result_port: c_longlong, keystore: *m
 pub unsafe extern "C" fn nt_keystore_reload(result_port: c_longlong, keystore: *mut c_void) {
     let keystore = &*(keystore as *mut KeyStore);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_keystore_reload");
+
         async fn internal_fn(keystore: &KeyStore) -> Result<serde_json::Value, String> {
             keystore.reload().await.handle_error()?;
 
@@ -772,6 +931,8 @@ pub unsafe extern "C" fn nt_keystore_verify_data(
     signers: *mut c_char,
     data: *mut c_char,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_keystore_verify_data");
+
     let connection = if !connection.is_null() {
         Some((&*(connection as *mut Arc<LedgerConnectionImpl>)).clone())
     } else {
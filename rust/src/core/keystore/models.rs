@@ -0,0 +1,24 @@
+use nekoton::core::keystore::KeyStoreEntry;
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyStoreEntryOutput {
+    pub signer_name: String,
+    pub name: String,
+    pub public_key: String,
+    pub master_key: String,
+    pub account_id: u32,
+}
+
+impl From<KeyStoreEntry> for KeyStoreEntryOutput {
+    fn from(entry: KeyStoreEntry) -> Self {
+        Self {
+            signer_name: entry.signer_name.to_string(),
+            name: entry.name.to_string(),
+            public_key: hex::encode(entry.public_key.as_bytes()),
+            master_key: hex::encode(entry.master_key.as_bytes()),
+            account_id: u32::from(entry.account_id),
+        }
+    }
+}
@@ -2,5 +2,9 @@ mod accounts_storage;
 mod generic_contract;
 mod keystore;
 mod models;
+#[cfg(test)]
+mod schema_snapshot;
+mod send_journal;
+mod subscription_errors;
 mod token_wallet;
-mod ton_wallet;
+pub(crate) mod ton_wallet;
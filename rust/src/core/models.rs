@@ -28,3 +28,9 @@ pub struct OnTransactionsFoundPayload<T> {
     pub transactions: Vec<T>,
     pub batch_info: TransactionsBatchInfo,
 }
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum SubscriptionAlertPayload {
+    ErrorThreshold { count: u32 },
+}
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use nekoton::core::{
+    accounts_storage::{AdditionalAssets, AssetsList, TokenWalletAsset, TonWalletAsset},
+    models::TokenWalletVersion,
+    ton_wallet::{MultisigType, WalletType},
+};
+use serde::Serialize;
+use ton_block::MsgAddressInt;
+
+use crate::core::{
+    accounts_storage::models::AssetsListOutput,
+    keystore::models::KeyStoreEntryOutput,
+    token_wallet::models::{RootTokenContractDetailsOutput, TokenWalletDetailsBatchItem},
+    ton_wallet::models::TonWalletDetailsOutput,
+};
+
+/// Field names and JSON types of the output models owned by this crate. Update it together with
+/// the Dart models when a change is intended
+const SCHEMA_SNAPSHOT: &str = include_str!("schema_snapshot.json");
+
+/// Replaces values with their JSON type names, arrays keep the schema of their first item
+fn schema(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Null => "null".into(),
+        serde_json::Value::Bool(_) => "bool".into(),
+        serde_json::Value::Number(_) => "number".into(),
+        serde_json::Value::String(_) => "string".into(),
+        serde_json::Value::Array(array) => array.first().map(schema).into_iter().collect(),
+        serde_json::Value::Object(object) => object
+            .iter()
+            .map(|(key, value)| (key.to_owned(), schema(value)))
+            .collect(),
+    }
+}
+
+fn public_key() -> ed25519_dalek::PublicKey {
+    let secret = ed25519_dalek::SecretKey::from_bytes(&[7; 32]).unwrap();
+    ed25519_dalek::PublicKey::from(&secret)
+}
+
+fn assets_list() -> AssetsListOutput {
+    let mut additional_assets = HashMap::new();
+    additional_assets.insert(
+        "mainnet".to_owned(),
+        AdditionalAssets {
+            token_wallets: vec![TokenWalletAsset {
+                root_token_contract: MsgAddressInt::default(),
+            }],
+            depools: Vec::new(),
+        },
+    );
+
+    AssetsListOutput::from(AssetsList {
+        name: "Main".to_owned(),
+        ton_wallet: TonWalletAsset {
+            address: MsgAddressInt::default(),
+            public_key: public_key(),
+            contract: WalletType::Multisig(MultisigType::SafeMultisigWallet),
+        },
+        additional_assets,
+    })
+}
+
+fn samples() -> Vec<(&'static str, serde_json::Value)> {
+    fn sample<T: Serialize>(name: &'static str, value: T) -> (&'static str, serde_json::Value) {
+        (name, serde_json::to_value(&value).unwrap())
+    }
+
+    vec![
+        sample("AssetsListOutput", assets_list()),
+        sample(
+            "KeyStoreEntryOutput",
+            KeyStoreEntryOutput {
+                signer_name: "encrypted_key".to_owned(),
+                name: "Main".to_owned(),
+                public_key: hex::encode(public_key().as_bytes()),
+                master_key: hex::encode(public_key().as_bytes()),
+                account_id: 0,
+            },
+        ),
+        sample(
+            "RootTokenContractDetailsOutput",
+            RootTokenContractDetailsOutput {
+                version: TokenWalletVersion::Tip3,
+                name: "Wrapped EVER".to_owned(),
+                symbol: "WEVER".to_owned(),
+                decimals: 9,
+                owner_address: MsgAddressInt::default().to_string(),
+                total_supply: "1000".to_owned(),
+            },
+        ),
+        sample(
+            "TokenWalletDetailsBatchItem.Details",
+            TokenWalletDetailsBatchItem::Details {
+                address: MsgAddressInt::default().to_string(),
+                balance: "1000".to_owned(),
+                root: MsgAddressInt::default(),
+                owner: MsgAddressInt::default(),
+                version: TokenWalletVersion::Tip3,
+            },
+        ),
+        sample(
+            "TokenWalletDetailsBatchItem.Error",
+            TokenWalletDetailsBatchItem::Error {
+                address: MsgAddressInt::default().to_string(),
+                error: "Account not exists".to_owned(),
+            },
+        ),
+        sample(
+            "TonWalletDetailsOutput",
+            TonWalletDetailsOutput {
+                requires_separate_deploy: true,
+                min_amount: "1000".to_owned(),
+                supports_payload: true,
+                supports_multiple_owners: true,
+                expiration_time: 3600,
+            },
+        ),
+    ]
+}
+
+#[test]
+fn output_models_match_snapshot() {
+    let snapshot = serde_json::from_str::<serde_json::Value>(SCHEMA_SNAPSHOT).unwrap();
+    let snapshot = snapshot.as_object().unwrap();
+
+    let samples = samples();
+
+    for (name, value) in &samples {
+        assert_eq!(
+            Some(&schema(value)),
+            snapshot.get(*name),
+            "Schema of {} changed, update schema_snapshot.json and the Dart model if intended",
+            name
+        );
+    }
+
+    let mut names = samples.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+    names.sort_unstable();
+
+    let mut snapshot_names = snapshot.keys().map(String::as_str).collect::<Vec<_>>();
+    snapshot_names.sort_unstable();
+
+    assert_eq!(
+        names, snapshot_names,
+        "Every snapshot entry must have a sample"
+    );
+}
+
+#[test]
+fn schema_keeps_structure() {
+    let value = serde_json::json!({
+        "name": "Main",
+        "items": [{ "amount": 1, "bounce": false }],
+        "empty": [],
+        "comment": null,
+    });
+
+    assert_eq!(
+        schema(&value),
+        serde_json::json!({
+            "name": "string",
+            "items": [{ "amount": "number", "bounce": "bool" }],
+            "empty": [],
+            "comment": "null",
+        })
+    );
+}
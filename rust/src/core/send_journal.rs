@@ -0,0 +1,211 @@
+use std::{
+    collections::BTreeMap,
+    os::raw::{c_char, c_longlong, c_void},
+    sync::{Arc, Mutex},
+};
+
+use allo_isolate::Isolate;
+use lazy_static::lazy_static;
+use nekoton::external::Storage;
+use serde::{Deserialize, Serialize};
+use ton_block::Serializable;
+use ton_types::UInt256;
+
+use crate::{
+    external::storage::StorageImpl, ffi_timer, runtime, spawn_ffi_task, HandleError, MatchResult,
+    PostWithResult, ToStringFromPtr,
+};
+
+const SEND_JOURNAL_KEY: &str = "send_journal";
+
+lazy_static! {
+    static ref SEND_JOURNAL_STORAGE: Mutex<Option<Arc<StorageImpl>>> = Mutex::new(None);
+    static ref SEND_JOURNAL_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+}
+
+/// Journal entries keyed by message hash
+type SendJournal = BTreeMap<String, SendJournalEntry>;
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SendJournalEntry {
+    pub message_hash: String,
+    pub dst: String,
+    pub expire_at: u32,
+    pub completed: bool,
+}
+
+/// Records send intent before the message reaches the network, fails if the journal can't be written
+pub async fn record_send_started(
+    message: &ton_block::Message,
+    expire_at: u32,
+) -> Result<(), String> {
+    let message_hash = message_hash(message)?;
+
+    let dst = message.dst().map(|e| e.to_string()).unwrap_or_default();
+
+    update_journal(|journal| {
+        journal.insert(
+            message_hash.to_owned(),
+            SendJournalEntry {
+                message_hash,
+                dst,
+                expire_at,
+                completed: false,
+            },
+        );
+    })
+    .await
+}
+
+/// Marks that the network call returned, the entry stays until the message is resolved or
+/// acknowledged
+pub async fn record_send_finished(message: &ton_block::Message) {
+    let message_hash = match message_hash(message) {
+        Ok(message_hash) => message_hash,
+        Err(_) => return,
+    };
+
+    let _ = update_journal(|journal| {
+        if let Some(entry) = journal.get_mut(&message_hash) {
+            entry.completed = true;
+        }
+    })
+    .await;
+}
+
+/// Prunes the entry of a message which the subscription either found in a transaction or saw
+/// expire in a later block
+pub fn record_send_resolved(message_hash: &UInt256) {
+    if SEND_JOURNAL_STORAGE.lock().unwrap().is_none() {
+        return;
+    }
+
+    let message_hash = message_hash.to_hex_string();
+
+    let runtime = match runtime() {
+        Ok(runtime) => runtime,
+        Err(_) => return,
+    };
+
+    runtime.spawn(async move {
+        let _ = update_journal(|journal| {
+            journal.remove(&message_hash);
+        })
+        .await;
+    });
+}
+
+async fn update_journal<F>(f: F) -> Result<(), String>
+where
+    F: FnOnce(&mut SendJournal),
+{
+    let storage = SEND_JOURNAL_STORAGE.lock().unwrap().clone();
+
+    let storage = match storage {
+        Some(storage) => storage,
+        None => return Ok(()),
+    };
+
+    let _guard = SEND_JOURNAL_LOCK.lock().await;
+
+    let mut journal = read_journal(storage.as_ref()).await?;
+
+    f(&mut journal);
+
+    let journal = serde_json::to_string(&journal).handle_error()?;
+
+    storage.set(SEND_JOURNAL_KEY, &journal).await.handle_error()
+}
+
+/// Reads the journal as is, a passed `expire_at` alone doesn't tell whether the message landed
+async fn read_journal(storage: &StorageImpl) -> Result<SendJournal, String> {
+    match storage.get(SEND_JOURNAL_KEY).await.handle_error()? {
+        Some(journal) => serde_json::from_str::<SendJournal>(&journal).handle_error(),
+        None => Ok(SendJournal::new()),
+    }
+}
+
+fn message_hash(message: &ton_block::Message) -> Result<String, String> {
+    message
+        .serialize()
+        .map(|e| e.repr_hash().to_hex_string())
+        .handle_error()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_set_send_journal(storage: *mut c_void) -> *mut c_char {
+    let _timer = ffi_timer!("nt_set_send_journal");
+
+    let storage = (!storage.is_null()).then(|| (&*(storage as *mut Arc<StorageImpl>)).clone());
+
+    fn internal_fn(storage: Option<Arc<StorageImpl>>) -> Result<serde_json::Value, String> {
+        *SEND_JOURNAL_STORAGE.lock().unwrap() = storage;
+
+        Ok(serde_json::Value::Null)
+    }
+
+    internal_fn(storage).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_unresolved_sends(result_port: c_longlong, storage: *mut c_void) {
+    let storage = (&*(storage as *mut Arc<StorageImpl>)).clone();
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_get_unresolved_sends");
+
+        async fn internal_fn(storage: Arc<StorageImpl>) -> Result<serde_json::Value, String> {
+            let _guard = SEND_JOURNAL_LOCK.lock().await;
+
+            let journal = read_journal(storage.as_ref()).await?;
+
+            let unresolved_sends = journal.into_values().collect::<Vec<_>>();
+
+            serde_json::to_value(&unresolved_sends).handle_error()
+        }
+
+        let result = internal_fn(storage).await.match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_acknowledge_unresolved_send(
+    result_port: c_longlong,
+    storage: *mut c_void,
+    message_hash: *mut c_char,
+) {
+    let storage = (&*(storage as *mut Arc<StorageImpl>)).clone();
+
+    let message_hash = message_hash.to_string_from_ptr();
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_acknowledge_unresolved_send");
+
+        async fn internal_fn(
+            storage: Arc<StorageImpl>,
+            message_hash: String,
+        ) -> Result<serde_json::Value, String> {
+            let _guard = SEND_JOURNAL_LOCK.lock().await;
+
+            let mut journal = read_journal(storage.as_ref()).await?;
+
+            if journal.remove(&message_hash).is_some() {
+                let journal = serde_json::to_string(&journal).handle_error()?;
+
+                storage
+                    .set(SEND_JOURNAL_KEY, &journal)
+                    .await
+                    .handle_error()?;
+            }
+
+            Ok(serde_json::Value::Null)
+        }
+
+        let result = internal_fn(storage, message_hash).await.match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
@@ -0,0 +1,118 @@
+use std::{
+    collections::HashMap,
+    os::raw::{c_char, c_longlong, c_uint, c_void},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use allo_isolate::Isolate;
+use lazy_static::lazy_static;
+
+use crate::{
+    canonical_json::to_json_string, core::models::SubscriptionAlertPayload, ffi_timer, HandleError,
+    MatchResult,
+};
+
+lazy_static! {
+    static ref ERROR_COUNTERS: Mutex<HashMap<usize, ErrorCounter>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Default)]
+struct ErrorCounter {
+    count: Arc<AtomicU32>,
+    threshold: Option<(u32, Isolate)>,
+}
+
+pub fn track_subscription_result<T>(subscription: usize, result: &Result<T, String>) {
+    if result.is_ok() {
+        return;
+    }
+
+    let mut error_counters = ERROR_COUNTERS.lock().unwrap();
+
+    let error_counter = error_counters.entry(subscription).or_default();
+
+    let count = error_counter.count.fetch_add(1, Ordering::SeqCst) + 1;
+
+    if let Some((threshold, port)) = error_counter.threshold {
+        if count == threshold + 1 {
+            let payload =
+                to_json_string(&SubscriptionAlertPayload::ErrorThreshold { count }).unwrap();
+
+            port.post(payload);
+        }
+    }
+}
+
+pub fn remove_subscription_error_counter(subscription: usize) {
+    ERROR_COUNTERS.lock().unwrap().remove(&subscription);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_subscription_error_count(subscription: *mut c_void) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_subscription_error_count");
+
+    let subscription = subscription as usize;
+
+    fn internal_fn(subscription: usize) -> Result<serde_json::Value, String> {
+        let count = ERROR_COUNTERS
+            .lock()
+            .unwrap()
+            .get(&subscription)
+            .map(|e| e.count.load(Ordering::SeqCst))
+            .unwrap_or_default();
+
+        serde_json::to_value(count).handle_error()
+    }
+
+    internal_fn(subscription).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_reset_subscription_error_count(
+    subscription: *mut c_void,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_reset_subscription_error_count");
+
+    let subscription = subscription as usize;
+
+    fn internal_fn(subscription: usize) -> Result<serde_json::Value, String> {
+        if let Some(error_counter) = ERROR_COUNTERS.lock().unwrap().get(&subscription) {
+            error_counter.count.store(0, Ordering::SeqCst);
+        }
+
+        Ok(serde_json::Value::Null)
+    }
+
+    internal_fn(subscription).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_set_subscription_error_threshold(
+    subscription: *mut c_void,
+    threshold: c_uint,
+    alert_port: c_longlong,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_set_subscription_error_threshold");
+
+    let subscription = subscription as usize;
+
+    fn internal_fn(
+        subscription: usize,
+        threshold: u32,
+        alert_port: i64,
+    ) -> Result<serde_json::Value, String> {
+        ERROR_COUNTERS
+            .lock()
+            .unwrap()
+            .entry(subscription)
+            .or_default()
+            .threshold = Some((threshold, Isolate::new(alert_port)));
+
+        Ok(serde_json::Value::Null)
+    }
+
+    internal_fn(subscription, threshold, alert_port).match_result()
+}
@@ -1,13 +1,18 @@
 use allo_isolate::Isolate;
 use async_trait::async_trait;
 use nekoton::core::{
-    models::{TokenWalletTransaction, TransactionWithData, TransactionsBatchInfo},
+    models::{
+        TokenWalletTransaction, TransactionWithData, TransactionsBatchInfo, TransactionsBatchType,
+    },
     token_wallet::TokenWalletSubscriptionHandler,
 };
 use nekoton_abi::num_bigint::BigUint;
 
-use crate::core::{
-    models::OnTransactionsFoundPayload, token_wallet::models::OnBalanceChangedPayload,
+use crate::{
+    canonical_json::to_json_string,
+    core::{models::OnTransactionsFoundPayload, token_wallet::models::OnBalanceChangedPayload},
+    helpers::{abi::getter_cache, camel_case::to_camel_case_value},
+    metrics::{self, SubscriptionKind},
 };
 
 pub struct TokenWalletSubscriptionHandlerImpl {
@@ -17,6 +22,8 @@ pub struct TokenWalletSubscriptionHandlerImpl {
 
 impl TokenWalletSubscriptionHandlerImpl {
     pub fn new(on_balance_changed_port: i64, on_transactions_found_port: i64) -> Self {
+        metrics::subscription_created(SubscriptionKind::TokenWallet);
+
         Self {
             on_balance_changed_port: Isolate::new(on_balance_changed_port),
             on_transactions_found_port: Isolate::new(on_transactions_found_port),
@@ -24,15 +31,23 @@ impl TokenWalletSubscriptionHandlerImpl {
     }
 }
 
+impl Drop for TokenWalletSubscriptionHandlerImpl {
+    fn drop(&mut self) {
+        metrics::subscription_dropped(SubscriptionKind::TokenWallet);
+    }
+}
+
 #[async_trait]
 impl TokenWalletSubscriptionHandler for TokenWalletSubscriptionHandlerImpl {
     fn on_balance_changed(&self, balance: BigUint) {
-        let payload = serde_json::to_string(&OnBalanceChangedPayload {
+        let payload = to_json_string(&OnBalanceChangedPayload {
             balance: balance.to_string(),
         })
         .unwrap();
 
         self.on_balance_changed_port.post(payload);
+
+        metrics::record_event_posted();
     }
 
     fn on_transactions_found(
@@ -40,12 +55,22 @@ impl TokenWalletSubscriptionHandler for TokenWalletSubscriptionHandlerImpl {
         transactions: Vec<TransactionWithData<TokenWalletTransaction>>,
         batch_info: TransactionsBatchInfo,
     ) {
-        let payload = serde_json::to_string(&OnTransactionsFoundPayload {
+        if matches!(batch_info.batch_type, TransactionsBatchType::New) {
+            transactions
+                .iter()
+                .filter_map(|e| e.transaction.in_msg.dst.as_ref())
+                .for_each(getter_cache::invalidate_address);
+        }
+
+        let payload = to_camel_case_value(&OnTransactionsFoundPayload {
             transactions,
             batch_info,
         })
+        .and_then(|e| to_json_string(&e))
         .unwrap();
 
         self.on_transactions_found_port.post(payload);
+
+        metrics::record_event_posted();
     }
 }
@@ -2,7 +2,8 @@ mod handler;
 pub(crate) mod models;
 
 use std::{
-    os::raw::{c_char, c_longlong, c_uint, c_void},
+    convert::TryFrom,
+    os::raw::{c_char, c_longlong, c_uchar, c_uint, c_void},
     str::FromStr,
     sync::Arc,
 };
@@ -10,22 +11,39 @@ use std::{
 use allo_isolate::Isolate;
 use nekoton::{
     core::{
-        models::TransferRecipient,
+        models::{TokenWalletVersion, Transaction, TransferRecipient},
         token_wallet::{
             get_token_root_details, get_token_root_details_from_token_wallet,
-            get_token_wallet_details, TokenWallet,
+            get_token_wallet_details, RootTokenContractState, TokenWallet,
         },
     },
-    transport::Transport,
+    transport::{models::RawContractState, Transport},
 };
 use nekoton_abi::{create_boc_or_comment_payload, num_bigint::BigUint};
-use tokio::sync::RwLock;
+use tokio::sync::{RwLock, Semaphore};
 use ton_block::{Block, Deserializable};
 
 use crate::{
-    clock, core::token_wallet::handler::TokenWalletSubscriptionHandlerImpl, parse_address, runtime,
-    transport::match_transport, HandleError, MatchResult, PostWithResult, ToOptionalStringFromPtr,
-    ToStringFromPtr, CLOCK, RUNTIME,
+    cancellation::CancellationToken,
+    clock,
+    core::{
+        subscription_errors::{remove_subscription_error_counter, track_subscription_result},
+        token_wallet::{
+            handler::TokenWalletSubscriptionHandlerImpl,
+            models::{
+                RootTokenContractDetailsOutput, TokenWalletDetailsBatchItem, TokenWalletHistory,
+                TokenWalletHistoryItem,
+            },
+        },
+    },
+    ffi_timer, parse_address, spawn_cancellable_ffi_task, spawn_ffi_task,
+    transport::{
+        match_transport,
+        subscription_counts::{
+            track_subscription_created, track_subscription_destroyed, transport_key,
+        },
+    },
+    HandleError, MatchResult, PostWithResult, ToOptionalStringFromPtr, ToStringFromPtr, CLOCK,
 };
 
 #[no_mangle]
@@ -44,7 +62,9 @@ pub unsafe extern "C" fn nt_token_wallet_subscribe(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_subscribe");
+
         async fn internal_fn(
             on_balance_changed_port: i64,
             on_transactions_found_port: i64,
@@ -52,6 +72,8 @@ pub unsafe extern "C" fn nt_token_wallet_subscribe(
             owner: String,
             root_token_contract: String,
         ) -> Result<serde_json::Value, String> {
+            let transport_key = transport_key(&transport);
+
             let owner = parse_address(&owner)?;
 
             let root_token_contract = parse_address(&root_token_contract)?;
@@ -68,6 +90,8 @@ pub unsafe extern "C" fn nt_token_wallet_subscribe(
 
             let ptr = Box::into_raw(Box::new(RwLock::new(token_wallet)));
 
+            track_subscription_created(ptr as usize, transport_key);
+
             serde_json::to_value(ptr as usize).handle_error()
         }
 
@@ -89,7 +113,9 @@ pub unsafe extern "C" fn nt_token_wallet_subscribe(
 pub unsafe extern "C" fn nt_token_wallet_owner(result_port: c_longlong, token_wallet: *mut c_void) {
     let token_wallet = &*(token_wallet as *mut RwLock<TokenWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_owner");
+
         fn internal_fn(token_wallet: &TokenWallet) -> Result<serde_json::Value, String> {
             let owner = token_wallet.owner().to_string();
 
@@ -111,7 +137,9 @@ pub unsafe extern "C" fn nt_token_wallet_address(
 ) {
     let token_wallet = &*(token_wallet as *mut RwLock<TokenWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_address");
+
         fn internal_fn(token_wallet: &TokenWallet) -> Result<serde_json::Value, String> {
             let address = token_wallet.address().to_string();
 
@@ -133,7 +161,9 @@ pub unsafe extern "C" fn nt_token_wallet_symbol(
 ) {
     let token_wallet = &*(token_wallet as *mut RwLock<TokenWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_symbol");
+
         fn internal_fn(token_wallet: &TokenWallet) -> Result<serde_json::Value, String> {
             let symbol = token_wallet.symbol();
 
@@ -155,7 +185,9 @@ pub unsafe extern "C" fn nt_token_wallet_version(
 ) {
     let token_wallet = &*(token_wallet as *mut RwLock<TokenWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_version");
+
         fn internal_fn(token_wallet: &TokenWallet) -> Result<serde_json::Value, String> {
             let version = token_wallet.version();
 
@@ -177,7 +209,9 @@ pub unsafe extern "C" fn nt_token_wallet_balance(
 ) {
     let token_wallet = &*(token_wallet as *mut RwLock<TokenWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_balance");
+
         fn internal_fn(token_wallet: &TokenWallet) -> Result<serde_json::Value, String> {
             let balance = token_wallet.balance().to_string();
 
@@ -199,7 +233,9 @@ pub unsafe extern "C" fn nt_token_wallet_contract_state(
 ) {
     let token_wallet = &*(token_wallet as *mut RwLock<TokenWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_contract_state");
+
         fn internal_fn(token_wallet: &TokenWallet) -> Result<serde_json::Value, String> {
             let contract_state = token_wallet.contract_state();
 
@@ -230,7 +266,9 @@ pub unsafe extern "C" fn nt_token_wallet_prepare_transfer(
     let notify_receiver = notify_receiver != 0;
     let payload = payload.to_optional_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_prepare_transfer");
+
         async fn internal_fn(
             token_wallet: &TokenWallet,
             destination: String,
@@ -242,20 +280,7 @@ pub unsafe extern "C" fn nt_token_wallet_prepare_transfer(
 
             let destination = TransferRecipient::OwnerWallet(destination);
 
-            let tokens = BigUint::from_str(&tokens).handle_error()?;
-
-            let payload = match payload {
-                Some(payload) => create_boc_or_comment_payload(&payload)
-                    .handle_error()?
-                    .into_cell(),
-                None => ton_types::Cell::default(),
-            };
-
-            let internal_message = token_wallet
-                .prepare_transfer(destination, tokens, notify_receiver, payload)
-                .handle_error()?;
-
-            serde_json::to_value(&internal_message).handle_error()
+            prepare_transfer(token_wallet, destination, tokens, notify_receiver, payload)
         }
 
         let token_wallet = token_wallet.read().await;
@@ -268,14 +293,91 @@ pub unsafe extern "C" fn nt_token_wallet_prepare_transfer(
     });
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_token_wallet_prepare_transfer_to_wallet(
+    result_port: c_longlong,
+    token_wallet: *mut c_void,
+    destination_wallet: *mut c_char,
+    tokens: *mut c_char,
+    notify_receiver: c_uint,
+    payload: *mut c_char,
+) {
+    let token_wallet = &*(token_wallet as *mut RwLock<TokenWallet>);
+
+    let destination_wallet = destination_wallet.to_string_from_ptr();
+    let tokens = tokens.to_string_from_ptr();
+    let notify_receiver = notify_receiver != 0;
+    let payload = payload.to_optional_string_from_ptr();
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_prepare_transfer_to_wallet");
+
+        async fn internal_fn(
+            token_wallet: &TokenWallet,
+            destination_wallet: String,
+            tokens: String,
+            notify_receiver: bool,
+            payload: Option<String>,
+        ) -> Result<serde_json::Value, String> {
+            let destination_wallet = parse_address(&destination_wallet)?;
+
+            let destination = TransferRecipient::TokenWallet(destination_wallet);
+
+            prepare_transfer(token_wallet, destination, tokens, notify_receiver, payload)
+        }
+
+        let token_wallet = token_wallet.read().await;
+
+        let result = internal_fn(
+            &token_wallet,
+            destination_wallet,
+            tokens,
+            notify_receiver,
+            payload,
+        )
+        .await
+        .match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
+/// Transfer to an owner deploys the recipient wallet if needed, one to a wallet expects it to exist
+fn prepare_transfer(
+    token_wallet: &TokenWallet,
+    destination: TransferRecipient,
+    tokens: String,
+    notify_receiver: bool,
+    payload: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let tokens = BigUint::from_str(&tokens).handle_error()?;
+
+    let payload = match payload {
+        Some(payload) => create_boc_or_comment_payload(&payload)
+            .handle_error()?
+            .into_cell(),
+        None => ton_types::Cell::default(),
+    };
+
+    let internal_message = token_wallet
+        .prepare_transfer(destination, tokens, notify_receiver, payload)
+        .handle_error()?;
+
+    serde_json::to_value(&internal_message).handle_error()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_token_wallet_refresh(
     result_port: c_longlong,
     token_wallet: *mut c_void,
 ) {
+    let subscription = token_wallet as usize;
+
     let token_wallet = &*(token_wallet as *mut RwLock<TokenWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_refresh");
+
         async fn internal_fn(token_wallet: &mut TokenWallet) -> Result<serde_json::Value, String> {
             token_wallet.refresh().await.handle_error()?;
 
@@ -284,7 +386,11 @@ pub unsafe extern "C" fn nt_token_wallet_refresh(
 
         let mut token_wallet = token_wallet.write().await;
 
-        let result = internal_fn(&mut token_wallet).await.match_result();
+        let result = internal_fn(&mut token_wallet).await;
+
+        track_subscription_result(subscription, &result);
+
+        let result = result.match_result();
 
         Isolate::new(result_port).post_with_result(result).unwrap();
     });
@@ -295,12 +401,19 @@ pub unsafe extern "C" fn nt_token_wallet_preload_transactions(
     result_port: c_longlong,
     token_wallet: *mut c_void,
     from_lt: *mut c_char,
+    cancellation_token: *mut c_void,
 ) {
+    let subscription = token_wallet as usize;
+
     let token_wallet = &*(token_wallet as *mut RwLock<TokenWallet>);
 
     let from_lt = from_lt.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    let cancellation_token = CancellationToken::from_ptr(cancellation_token);
+
+    spawn_cancellable_ffi_task(result_port, cancellation_token, async move {
+        let _timer = ffi_timer!("nt_token_wallet_preload_transactions");
+
         async fn internal_fn(
             token_wallet: &mut TokenWallet,
             from_lt: String,
@@ -317,7 +430,11 @@ pub unsafe extern "C" fn nt_token_wallet_preload_transactions(
 
         let mut token_wallet = token_wallet.write().await;
 
-        let result = internal_fn(&mut token_wallet, from_lt).await.match_result();
+        let result = internal_fn(&mut token_wallet, from_lt).await;
+
+        track_subscription_result(subscription, &result);
+
+        let result = result.match_result();
 
         Isolate::new(result_port).post_with_result(result).unwrap();
     });
@@ -329,11 +446,15 @@ pub unsafe extern "C" fn nt_token_wallet_handle_block(
     token_wallet: *mut c_void,
     block: *mut c_char,
 ) {
+    let subscription = token_wallet as usize;
+
     let token_wallet = &*(token_wallet as *mut RwLock<TokenWallet>);
 
     let block = block.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_handle_block");
+
         async fn internal_fn(
             token_wallet: &mut TokenWallet,
             block: String,
@@ -347,7 +468,11 @@ pub unsafe extern "C" fn nt_token_wallet_handle_block(
 
         let mut token_wallet = token_wallet.write().await;
 
-        let result = internal_fn(&mut token_wallet, block).await.match_result();
+        let result = internal_fn(&mut token_wallet, block).await;
+
+        track_subscription_result(subscription, &result);
+
+        let result = result.match_result();
 
         Isolate::new(result_port).post_with_result(result).unwrap();
     });
@@ -365,7 +490,9 @@ pub unsafe extern "C" fn nt_get_token_root_details(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_get_token_root_details");
+
         async fn internal_fn(
             transport: Arc<dyn Transport>,
             root_token_contract: String,
@@ -377,6 +504,8 @@ pub unsafe extern "C" fn nt_get_token_root_details(
                     .await
                     .handle_error()?;
 
+            let token_root_details = RootTokenContractDetailsOutput::from(token_root_details);
+
             serde_json::to_value(&token_root_details).handle_error()
         }
 
@@ -400,7 +529,9 @@ pub unsafe extern "C" fn nt_get_token_wallet_details(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_get_token_wallet_details");
+
         async fn internal_fn(
             transport: Arc<dyn Transport>,
             token_wallet: String,
@@ -412,6 +543,8 @@ pub unsafe extern "C" fn nt_get_token_wallet_details(
                     .await
                     .handle_error()?;
 
+            let details = (details.0, RootTokenContractDetailsOutput::from(details.1));
+
             serde_json::to_value(&details).handle_error()
         }
 
@@ -421,6 +554,81 @@ pub unsafe extern "C" fn nt_get_token_wallet_details(
     });
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_token_wallet_details_batch(
+    result_port: c_longlong,
+    transport: *mut c_void,
+    transport_type: *mut c_char,
+    token_wallets: *mut c_char,
+) {
+    let transport_type = transport_type.to_string_from_ptr();
+    let token_wallets = token_wallets.to_string_from_ptr();
+
+    let transport = match_transport(transport, &transport_type);
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_get_token_wallet_details_batch");
+
+        async fn internal_fn(
+            transport: Arc<dyn Transport>,
+            token_wallets: String,
+        ) -> Result<serde_json::Value, String> {
+            let token_wallets =
+                serde_json::from_str::<Vec<String>>(&token_wallets).handle_error()?;
+
+            let semaphore = Arc::new(Semaphore::new(10));
+
+            let handles = token_wallets
+                .into_iter()
+                .map(|address| {
+                    let transport = transport.clone();
+                    let semaphore = semaphore.clone();
+
+                    tokio::spawn(async move {
+                        let _permit = semaphore.acquire().await;
+
+                        let details = match parse_address(&address) {
+                            Ok(token_wallet) => get_token_wallet_details(
+                                clock!().as_ref(),
+                                transport.as_ref(),
+                                &token_wallet,
+                            )
+                            .await
+                            .handle_error(),
+                            Err(err) => Err(err),
+                        };
+
+                        match details {
+                            Ok((wallet_details, root_details)) => {
+                                TokenWalletDetailsBatchItem::Details {
+                                    address,
+                                    balance: wallet_details.balance.to_string(),
+                                    root: wallet_details.root_address,
+                                    owner: wallet_details.owner_address,
+                                    version: root_details.version,
+                                }
+                            },
+                            Err(error) => TokenWalletDetailsBatchItem::Error { address, error },
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let mut items = Vec::with_capacity(handles.len());
+
+            for handle in handles {
+                items.push(handle.await.handle_error()?);
+            }
+
+            serde_json::to_value(&items).handle_error()
+        }
+
+        let result = internal_fn(transport, token_wallets).await.match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_get_token_root_details_from_token_wallet(
     result_port: c_longlong,
@@ -433,7 +641,9 @@ pub unsafe extern "C" fn nt_get_token_root_details_from_token_wallet(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_get_token_root_details_from_token_wallet");
+
         async fn internal_fn(
             transport: Arc<dyn Transport>,
             token_wallet_address: String,
@@ -448,7 +658,10 @@ pub unsafe extern "C" fn nt_get_token_root_details_from_token_wallet(
             .await
             .handle_error()?;
 
-            let details = (details.0.to_string(), details.1);
+            let details = (
+                details.0.to_string(),
+                RootTokenContractDetailsOutput::from(details.1),
+            );
 
             serde_json::to_value(&details).handle_error()
         }
@@ -461,8 +674,123 @@ pub unsafe extern "C" fn nt_get_token_root_details_from_token_wallet(
     });
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_token_wallet_history(
+    result_port: c_longlong,
+    transport: *mut c_void,
+    transport_type: *mut c_char,
+    root_token_contract: *mut c_char,
+    owner: *mut c_char,
+    from_lt: *mut c_char,
+    count: c_uchar,
+) {
+    let transport_type = transport_type.to_string_from_ptr();
+    let root_token_contract = root_token_contract.to_string_from_ptr();
+    let owner = owner.to_string_from_ptr();
+    let from_lt = from_lt.to_optional_string_from_ptr();
+
+    let transport = match_transport(transport, &transport_type);
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_token_wallet_history");
+
+        async fn internal_fn(
+            transport: Arc<dyn Transport>,
+            root_token_contract: String,
+            owner: String,
+            from_lt: Option<String>,
+            count: u8,
+        ) -> Result<serde_json::Value, String> {
+            let root_token_contract = parse_address(&root_token_contract)?;
+            let owner = parse_address(&owner)?;
+
+            let from_lt = from_lt
+                .map(|e| e.parse::<u64>())
+                .transpose()
+                .handle_error()?
+                .unwrap_or(u64::MAX);
+
+            let root_contract = match transport
+                .get_contract_state(&root_token_contract)
+                .await
+                .handle_error()?
+            {
+                RawContractState::Exists(contract) => contract,
+                RawContractState::NotExists => {
+                    return Err("Root token contract not exists").handle_error()
+                },
+            };
+
+            let root_contract_state = RootTokenContractState(&root_contract);
+
+            let mut wallet_addresses = Vec::new();
+
+            for version in [TokenWalletVersion::Tip3, TokenWalletVersion::OldTip3v4] {
+                if let Ok(address) =
+                    root_contract_state.get_wallet_address(clock!().as_ref(), version, &owner)
+                {
+                    if !wallet_addresses.contains(&address) {
+                        wallet_addresses.push(address);
+                    }
+                }
+            }
+
+            if wallet_addresses.is_empty() {
+                return Err("Failed to compute token wallet address").handle_error();
+            }
+
+            let mut has_more = false;
+            let mut items = Vec::new();
+
+            for wallet_address in wallet_addresses {
+                let raw_transactions = transport
+                    .get_transactions(&wallet_address, from_lt, count)
+                    .await
+                    .handle_error()?;
+
+                has_more |= raw_transactions.len() >= count as usize;
+
+                items.extend(
+                    raw_transactions
+                        .into_iter()
+                        .filter_map(|e| Transaction::try_from((e.hash, e.data)).ok())
+                        .map(|transaction| TokenWalletHistoryItem {
+                            wallet_address: wallet_address.to_owned(),
+                            transaction,
+                        }),
+                );
+            }
+
+            items.sort_by(|a, b| b.transaction.id.lt.cmp(&a.transaction.id.lt));
+
+            has_more |= items.len() > count as usize;
+            items.truncate(count as usize);
+
+            let continuation = match items.last() {
+                Some(last) if has_more => Some((last.transaction.id.lt - 1).to_string()),
+                _ => None,
+            };
+
+            let history = TokenWalletHistory {
+                transactions: items,
+                continuation,
+            };
+
+            serde_json::to_value(&history).handle_error()
+        }
+
+        let result = internal_fn(transport, root_token_contract, owner, from_lt, count)
+            .await
+            .match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_token_wallet_free_ptr(ptr: *mut c_void) {
     println!("nt_token_wallet_free_ptr");
+    remove_subscription_error_counter(ptr as usize);
+    track_subscription_destroyed(ptr as usize);
     Box::from_raw(ptr as *mut Arc<RwLock<TokenWallet>>);
 }
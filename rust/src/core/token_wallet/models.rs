@@ -1,6 +1,66 @@
+use nekoton::core::models::{RootTokenContractDetails, TokenWalletVersion, Transaction};
+use nekoton_utils::serde_address;
 use serde::Serialize;
+use ton_block::MsgAddressInt;
 
 #[derive(Serialize)]
 pub struct OnBalanceChangedPayload {
     pub balance: String,
 }
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenWalletHistoryItem {
+    #[serde(with = "serde_address")]
+    pub wallet_address: MsgAddressInt,
+    pub transaction: Transaction,
+}
+
+#[derive(Serialize)]
+pub struct TokenWalletHistory {
+    pub transactions: Vec<TokenWalletHistoryItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum TokenWalletDetailsBatchItem {
+    Details {
+        address: String,
+        balance: String,
+        #[serde(with = "serde_address")]
+        root: MsgAddressInt,
+        #[serde(with = "serde_address")]
+        owner: MsgAddressInt,
+        version: TokenWalletVersion,
+    },
+    Error {
+        address: String,
+        error: String,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RootTokenContractDetailsOutput {
+    pub version: TokenWalletVersion,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub owner_address: String,
+    pub total_supply: String,
+}
+
+impl From<RootTokenContractDetails> for RootTokenContractDetailsOutput {
+    fn from(details: RootTokenContractDetails) -> Self {
+        Self {
+            version: details.version,
+            name: details.name,
+            symbol: details.symbol,
+            decimals: details.decimals,
+            owner_address: details.owner_address.to_string(),
+            total_supply: details.total_supply.to_string(),
+        }
+    }
+}
@@ -3,14 +3,22 @@ use async_trait::async_trait;
 use nekoton::core::{
     models::{
         ContractState, PendingTransaction, Transaction, TransactionAdditionalInfo,
-        TransactionWithData, TransactionsBatchInfo,
+        TransactionWithData, TransactionsBatchInfo, TransactionsBatchType,
     },
     ton_wallet::TonWalletSubscriptionHandler,
 };
 
-use crate::core::models::{
-    OnMessageExpiredPayload, OnMessageSentPayload, OnStateChangedPayload,
-    OnTransactionsFoundPayload,
+use crate::{
+    canonical_json::to_json_string,
+    core::{
+        models::{
+            OnMessageExpiredPayload, OnMessageSentPayload, OnStateChangedPayload,
+            OnTransactionsFoundPayload,
+        },
+        send_journal,
+    },
+    helpers::{abi::getter_cache, camel_case::to_camel_case_value},
+    metrics::{self, SubscriptionKind},
 };
 
 pub struct TonWalletSubscriptionHandlerImpl {
@@ -27,6 +35,8 @@ impl TonWalletSubscriptionHandlerImpl {
         on_state_changed_port: i64,
         on_transactions_found_port: i64,
     ) -> Self {
+        metrics::subscription_created(SubscriptionKind::TonWallet);
+
         Self {
             on_message_sent_port: Isolate::new(on_message_sent_port),
             on_message_expired_port: Isolate::new(on_message_expired_port),
@@ -36,6 +46,12 @@ impl TonWalletSubscriptionHandlerImpl {
     }
 }
 
+impl Drop for TonWalletSubscriptionHandlerImpl {
+    fn drop(&mut self) {
+        metrics::subscription_dropped(SubscriptionKind::TonWallet);
+    }
+}
+
 #[async_trait]
 impl TonWalletSubscriptionHandler for TonWalletSubscriptionHandlerImpl {
     fn on_message_sent(
@@ -43,28 +59,38 @@ impl TonWalletSubscriptionHandler for TonWalletSubscriptionHandlerImpl {
         pending_transaction: PendingTransaction,
         transaction: Option<Transaction>,
     ) {
-        let payload = serde_json::to_string(&OnMessageSentPayload {
+        send_journal::record_send_resolved(&pending_transaction.message_hash);
+
+        let payload = to_json_string(&OnMessageSentPayload {
             pending_transaction,
             transaction,
         })
         .unwrap();
 
         self.on_message_sent_port.post(payload);
+
+        metrics::record_event_posted();
     }
 
     fn on_message_expired(&self, pending_transaction: PendingTransaction) {
-        let payload = serde_json::to_string(&OnMessageExpiredPayload {
+        send_journal::record_send_resolved(&pending_transaction.message_hash);
+
+        let payload = to_json_string(&OnMessageExpiredPayload {
             pending_transaction,
         })
         .unwrap();
 
         self.on_message_expired_port.post(payload);
+
+        metrics::record_event_posted();
     }
 
     fn on_state_changed(&self, new_state: ContractState) {
-        let payload = serde_json::to_string(&OnStateChangedPayload { new_state }).unwrap();
+        let payload = to_json_string(&OnStateChangedPayload { new_state }).unwrap();
 
         self.on_state_changed_port.post(payload);
+
+        metrics::record_event_posted();
     }
 
     fn on_transactions_found(
@@ -72,12 +98,22 @@ impl TonWalletSubscriptionHandler for TonWalletSubscriptionHandlerImpl {
         transactions: Vec<TransactionWithData<TransactionAdditionalInfo>>,
         batch_info: TransactionsBatchInfo,
     ) {
-        let payload = serde_json::to_string(&OnTransactionsFoundPayload {
+        if matches!(batch_info.batch_type, TransactionsBatchType::New) {
+            transactions
+                .iter()
+                .filter_map(|e| e.transaction.in_msg.dst.as_ref())
+                .for_each(getter_cache::invalidate_address);
+        }
+
+        let payload = to_camel_case_value(&OnTransactionsFoundPayload {
             transactions,
             batch_info,
         })
+        .and_then(|e| to_json_string(&e))
         .unwrap();
 
         self.on_transactions_found_port.post(payload);
+
+        metrics::record_event_posted();
     }
 }
@@ -1,5 +1,6 @@
 mod handler;
 pub(crate) mod models;
+mod transfer_uri;
 
 use std::{
     os::raw::{c_char, c_longlong, c_schar, c_uchar, c_uint, c_void},
@@ -12,26 +13,48 @@ use nekoton::{
         models::{Expiration, MessageFlags},
         ton_wallet::{
             extract_wallet_init_data, find_existing_wallets, get_wallet_custodians,
-            ExistingWalletInfo, Gift, TonWallet, TransferAction,
+            ExistingWalletInfo, Gift, MultisigType, TonWallet, TransferAction, WalletType,
         },
     },
     crypto::SignedMessage,
-    transport::Transport,
+    transport::{models::RawContractState, Transport},
 };
 use nekoton_abi::create_boc_or_comment_payload;
+use nekoton_contracts::tip3_1::token_wallet_contract;
 use tokio::sync::RwLock;
-use ton_block::{Block, Deserializable};
+use ton_block::{Account, Block, Deserializable, MsgAddressInt};
+use ton_executor::BlockchainConfig;
 
 use crate::{
-    clock,
-    core::ton_wallet::{
-        handler::TonWalletSubscriptionHandlerImpl,
-        models::{ExistingWalletInfoHelper, WalletTypeHelper},
+    cancellation::CancellationToken,
+    cancelled_result, clock,
+    core::{
+        send_journal,
+        subscription_errors::{remove_subscription_error_counter, track_subscription_result},
+        ton_wallet::{
+            handler::TonWalletSubscriptionHandlerImpl,
+            models::{
+                ExistingWalletInfoHelper, PreflightOptions, TonWalletDetailsOutput,
+                TransferFromUriOutput, TransferUriIssue, UnconfirmedTransaction, WalletTypeHelper,
+            },
+            transfer_uri::parse_transfer_uri,
+        },
+    },
+    ffi_timer,
+    helpers::{
+        abi::{models::LocalTransactionExecution, tvm},
+        camel_case::to_camel_case_value,
+    },
+    parse_address, parse_public_key, spawn_cancellable_ffi_task, spawn_ffi_task,
+    transport::{
+        match_transport,
+        models::RawContractStateHelper,
+        network_params::get_network_config,
+        subscription_counts::{
+            track_subscription_created, track_subscription_destroyed, transport_key,
+        },
     },
-    parse_address, parse_public_key, runtime,
-    transport::{match_transport, models::RawContractStateHelper},
     HandleError, MatchResult, PostWithResult, ToOptionalStringFromPtr, ToStringFromPtr, CLOCK,
-    RUNTIME,
 };
 
 #[no_mangle]
@@ -53,7 +76,9 @@ pub unsafe extern "C" fn nt_ton_wallet_subscribe(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_subscribe");
+
         async fn internal_fn(
             on_message_sent_port: i64,
             on_message_expired_port: i64,
@@ -64,6 +89,8 @@ pub unsafe extern "C" fn nt_ton_wallet_subscribe(
             public_key: String,
             contract: String,
         ) -> Result<serde_json::Value, String> {
+            let transport_key = transport_key(&transport);
+
             let public_key = parse_public_key(&public_key)?;
 
             let contract = serde_json::from_str::<WalletTypeHelper>(&contract)
@@ -90,6 +117,8 @@ pub unsafe extern "C" fn nt_ton_wallet_subscribe(
 
             let ptr = Box::into_raw(Box::new(RwLock::new(ton_wallet)));
 
+            track_subscription_created(ptr as usize, transport_key);
+
             serde_json::to_value(ptr as usize).handle_error()
         }
 
@@ -126,7 +155,9 @@ pub unsafe extern "C" fn nt_ton_wallet_subscribe_by_address(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_subscribe_by_address");
+
         async fn internal_fn(
             on_message_sent_port: i64,
             on_message_expired_port: i64,
@@ -135,6 +166,8 @@ pub unsafe extern "C" fn nt_ton_wallet_subscribe_by_address(
             transport: Arc<dyn Transport>,
             address: String,
         ) -> Result<serde_json::Value, String> {
+            let transport_key = transport_key(&transport);
+
             let address = parse_address(&address)?;
 
             let handler = Arc::new(TonWalletSubscriptionHandlerImpl::new(
@@ -150,6 +183,8 @@ pub unsafe extern "C" fn nt_ton_wallet_subscribe_by_address(
 
             let ptr = Box::into_raw(Box::new(RwLock::new(ton_wallet)));
 
+            track_subscription_created(ptr as usize, transport_key);
+
             serde_json::to_value(ptr as usize).handle_error()
         }
 
@@ -184,7 +219,9 @@ pub unsafe extern "C" fn nt_ton_wallet_subscribe_by_existing(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_subscribe_by_existing");
+
         async fn internal_fn(
             on_message_sent_port: i64,
             on_message_expired_port: i64,
@@ -193,6 +230,8 @@ pub unsafe extern "C" fn nt_ton_wallet_subscribe_by_existing(
             transport: Arc<dyn Transport>,
             existing_wallet: String,
         ) -> Result<serde_json::Value, String> {
+            let transport_key = transport_key(&transport);
+
             let existing_wallet =
                 serde_json::from_str::<ExistingWalletInfoHelper>(&existing_wallet)
                     .map(|ExistingWalletInfoHelper(existing_wallet_info)| existing_wallet_info)
@@ -212,6 +251,8 @@ pub unsafe extern "C" fn nt_ton_wallet_subscribe_by_existing(
 
             let ptr = Box::into_raw(Box::new(RwLock::new(ton_wallet)));
 
+            track_subscription_created(ptr as usize, transport_key);
+
             serde_json::to_value(ptr as usize).handle_error()
         }
 
@@ -234,7 +275,9 @@ pub unsafe extern "C" fn nt_ton_wallet_subscribe_by_existing(
 pub unsafe extern "C" fn nt_ton_wallet_workchain(result_port: c_longlong, ton_wallet: *mut c_void) {
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_workchain");
+
         fn internal_fn(ton_wallet: &TonWallet) -> Result<serde_json::Value, String> {
             let workchain = ton_wallet.workchain();
 
@@ -253,7 +296,9 @@ pub unsafe extern "C" fn nt_ton_wallet_workchain(result_port: c_longlong, ton_wa
 pub unsafe extern "C" fn nt_ton_wallet_address(result_port: c_longlong, ton_wallet: *mut c_void) {
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_address");
+
         fn internal_fn(ton_wallet: &TonWallet) -> Result<serde_json::Value, String> {
             let address = ton_wallet.address().to_string();
 
@@ -268,6 +313,27 @@ pub unsafe extern "C" fn nt_ton_wallet_address(result_port: c_longlong, ton_wall
     });
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_wallet_subscription_address(
+    ton_wallet: *mut c_void,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_wallet_subscription_address");
+
+    let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
+
+    fn internal_fn(ton_wallet: &RwLock<TonWallet>) -> Result<serde_json::Value, String> {
+        // Called from the UI thread, so a wallet locked by a pending refresh is reported
+        // instead of waited for
+        let ton_wallet = ton_wallet.try_read().handle_error()?;
+
+        let address = ton_wallet.address().to_string();
+
+        serde_json::to_value(address).handle_error()
+    }
+
+    internal_fn(ton_wallet).match_result()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_ton_wallet_public_key(
     result_port: c_longlong,
@@ -275,7 +341,9 @@ pub unsafe extern "C" fn nt_ton_wallet_public_key(
 ) {
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_public_key");
+
         fn internal_fn(ton_wallet: &TonWallet) -> Result<serde_json::Value, String> {
             let public_key = ton_wallet.public_key();
 
@@ -299,7 +367,9 @@ pub unsafe extern "C" fn nt_ton_wallet_wallet_type(
 ) {
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_wallet_type");
+
         fn internal_fn(ton_wallet: &TonWallet) -> Result<serde_json::Value, String> {
             let contract = ton_wallet.wallet_type();
 
@@ -321,7 +391,9 @@ pub unsafe extern "C" fn nt_ton_wallet_contract_state(
 ) {
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_contract_state");
+
         fn internal_fn(ton_wallet: &TonWallet) -> Result<serde_json::Value, String> {
             let contract_state = ton_wallet.contract_state();
 
@@ -343,7 +415,9 @@ pub unsafe extern "C" fn nt_ton_wallet_pending_transactions(
 ) {
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_pending_transactions");
+
         fn internal_fn(ton_wallet: &TonWallet) -> Result<serde_json::Value, String> {
             let pending_transactions = ton_wallet.pending_transactions();
 
@@ -365,7 +439,9 @@ pub unsafe extern "C" fn nt_ton_wallet_polling_method(
 ) {
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_polling_method");
+
         fn internal_fn(ton_wallet: &TonWallet) -> Result<serde_json::Value, String> {
             let polling_method = ton_wallet.polling_method();
 
@@ -384,9 +460,11 @@ pub unsafe extern "C" fn nt_ton_wallet_polling_method(
 pub unsafe extern "C" fn nt_ton_wallet_details(result_port: c_longlong, ton_wallet: *mut c_void) {
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_details");
+
         fn internal_fn(ton_wallet: &TonWallet) -> Result<serde_json::Value, String> {
-            let details = ton_wallet.details();
+            let details = TonWalletDetailsOutput::from(ton_wallet.details());
 
             serde_json::to_value(&details).handle_error()
         }
@@ -406,11 +484,25 @@ pub unsafe extern "C" fn nt_ton_wallet_unconfirmed_transactions(
 ) {
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_unconfirmed_transactions");
+
         fn internal_fn(ton_wallet: &TonWallet) -> Result<serde_json::Value, String> {
-            let unconfirmed_transactions = ton_wallet.get_unconfirmed_transactions();
+            let lifetime = match ton_wallet.wallet_type() {
+                WalletType::Multisig(multisig_type) => multisig_transaction_lifetime(multisig_type),
+                _ => 0,
+            };
 
-            serde_json::to_value(&unconfirmed_transactions).handle_error()
+            let unconfirmed_transactions = ton_wallet
+                .get_unconfirmed_transactions()
+                .iter()
+                .map(|transaction| UnconfirmedTransaction {
+                    transaction,
+                    expire_at: (transaction.id >> 32) as u32 + lifetime,
+                })
+                .collect::<Vec<_>>();
+
+            to_camel_case_value(&unconfirmed_transactions).handle_error()
         }
 
         let ton_wallet = ton_wallet.read().await;
@@ -428,7 +520,9 @@ pub unsafe extern "C" fn nt_ton_wallet_custodians(
 ) {
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_custodians");
+
         fn internal_fn(ton_wallet: &TonWallet) -> Result<serde_json::Value, String> {
             let custodians = ton_wallet
                 .get_custodians()
@@ -456,7 +550,9 @@ pub unsafe extern "C" fn nt_ton_wallet_prepare_deploy(
 
     let expiration = expiration.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_prepare_deploy");
+
         fn internal_fn(
             ton_wallet: &TonWallet,
             expiration: String,
@@ -491,7 +587,9 @@ pub unsafe extern "C" fn nt_ton_wallet_prepare_deploy_with_multiple_owners(
     let expiration = expiration.to_string_from_ptr();
     let custodians = custodians.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_prepare_deploy_with_multiple_owners");
+
         fn internal_fn(
             ton_wallet: &TonWallet,
             expiration: String,
@@ -544,7 +642,9 @@ pub unsafe extern "C" fn nt_ton_wallet_prepare_transfer(
     let body = body.to_optional_string_from_ptr();
     let expiration = expiration.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_prepare_transfer");
+
         fn internal_fn(
             ton_wallet: &mut TonWallet,
             contract_state: String,
@@ -622,6 +722,259 @@ pub unsafe extern "C" fn nt_ton_wallet_prepare_transfer(
     });
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_ton_wallet_prepare_transfer_from_uri(
+    result_port: c_longlong,
+    ton_wallet: *mut c_void,
+    contract_state: *mut c_char,
+    public_key: *mut c_char,
+    uri: *mut c_char,
+    expiration: *mut c_char,
+) {
+    let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
+
+    let contract_state = contract_state.to_string_from_ptr();
+    let public_key = public_key.to_string_from_ptr();
+    let uri = uri.to_string_from_ptr();
+    let expiration = expiration.to_string_from_ptr();
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_prepare_transfer_from_uri");
+
+        async fn internal_fn(
+            ton_wallet: &mut TonWallet,
+            contract_state: String,
+            public_key: String,
+            uri: String,
+            expiration: String,
+        ) -> Result<serde_json::Value, String> {
+            let transfer_uri = match parse_transfer_uri(&uri) {
+                Ok(transfer_uri) => transfer_uri,
+                Err(issues) => {
+                    let output = TransferFromUriOutput {
+                        unsigned_message: None,
+                        issues,
+                    };
+
+                    return serde_json::to_value(&output).handle_error();
+                },
+            };
+
+            let contract_state = serde_json::from_str::<RawContractStateHelper>(&contract_state)
+                .map(|RawContractStateHelper(raw_contract_state)| raw_contract_state)
+                .handle_error()?;
+
+            let current_state = match contract_state {
+                nekoton::transport::models::RawContractState::NotExists => {
+                    return Err("Not exists").handle_error()
+                },
+                nekoton::transport::models::RawContractState::Exists(contract) => contract.account,
+            };
+
+            let public_key = parse_public_key(&public_key)?;
+
+            let expiration = serde_json::from_str::<Expiration>(&expiration).handle_error()?;
+
+            let gift = Gift {
+                flags: MessageFlags::default().into(),
+                bounce: false,
+                destination: transfer_uri.destination,
+                amount: transfer_uri.amount,
+                body: transfer_uri.body,
+                state_init: None,
+            };
+
+            let action = ton_wallet
+                .prepare_transfer(&current_state, &public_key, gift, expiration)
+                .handle_error()?;
+
+            let unsigned_message = match action {
+                TransferAction::DeployFirst => {
+                    let output = TransferFromUriOutput {
+                        unsigned_message: None,
+                        issues: vec![TransferUriIssue::DeployFirst],
+                    };
+
+                    return serde_json::to_value(&output).handle_error();
+                },
+                TransferAction::Sign(unsigned_message) => unsigned_message,
+            };
+
+            // Fees do not depend on the signature value, so a zeroed one is enough for estimation
+            let message = unsigned_message
+                .sign(&[0; ed25519_dalek::SIGNATURE_LENGTH])
+                .handle_error()?
+                .message;
+
+            let fees = u128::from(ton_wallet.estimate_fees(&message).await.handle_error()?);
+            let balance = u128::from(ton_wallet.contract_state().balance);
+            let amount = u128::from(transfer_uri.amount);
+
+            let output = if amount + fees > balance {
+                TransferFromUriOutput {
+                    unsigned_message: None,
+                    issues: vec![TransferUriIssue::InsufficientBalance {
+                        balance: balance.to_string(),
+                        amount: amount.to_string(),
+                        fees: fees.to_string(),
+                    }],
+                }
+            } else {
+                let ptr = Box::into_raw(Box::new(RwLock::new(unsigned_message)));
+
+                TransferFromUriOutput {
+                    unsigned_message: Some(ptr as usize),
+                    issues: Vec::new(),
+                }
+            };
+
+            serde_json::to_value(&output).handle_error()
+        }
+
+        let mut ton_wallet = ton_wallet.write().await;
+
+        let result = internal_fn(&mut ton_wallet, contract_state, public_key, uri, expiration)
+            .await
+            .match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_create_token_transfer_message(
+    result_port: c_longlong,
+    ton_wallet: *mut c_void,
+    contract_state: *mut c_char,
+    public_key: *mut c_char,
+    token_wallet_address: *mut c_char,
+    recipient: *mut c_char,
+    amount: *mut c_char,
+    deploy_wallet_value: *mut c_char,
+    notify_receiver: c_uint,
+    attached_amount: *mut c_char,
+    payload: *mut c_char,
+    expiration: *mut c_char,
+) {
+    let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
+
+    let contract_state = contract_state.to_string_from_ptr();
+    let public_key = public_key.to_string_from_ptr();
+    let token_wallet_address = token_wallet_address.to_string_from_ptr();
+    let recipient = recipient.to_string_from_ptr();
+    let amount = amount.to_string_from_ptr();
+    let deploy_wallet_value = deploy_wallet_value.to_string_from_ptr();
+    let attached_amount = attached_amount.to_string_from_ptr();
+    let payload = payload.to_optional_string_from_ptr();
+    let expiration = expiration.to_string_from_ptr();
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_create_token_transfer_message");
+
+        fn internal_fn(
+            ton_wallet: &mut TonWallet,
+            contract_state: String,
+            public_key: String,
+            token_wallet_address: String,
+            recipient: String,
+            amount: String,
+            deploy_wallet_value: String,
+            notify_receiver: bool,
+            attached_amount: String,
+            payload: Option<String>,
+            expiration: String,
+        ) -> Result<serde_json::Value, String> {
+            let contract_state = serde_json::from_str::<RawContractStateHelper>(&contract_state)
+                .map(|RawContractStateHelper(raw_contract_state)| raw_contract_state)
+                .handle_error()?;
+
+            let current_state = match contract_state {
+                nekoton::transport::models::RawContractState::NotExists => {
+                    return Err("Not exists").handle_error()
+                },
+                nekoton::transport::models::RawContractState::Exists(contract) => contract.account,
+            };
+
+            let public_key = parse_public_key(&public_key)?;
+
+            let token_wallet_address = parse_address(&token_wallet_address)?;
+
+            let recipient = parse_address(&recipient)?;
+
+            let attached_amount = attached_amount.parse::<u64>().handle_error()?;
+
+            let payload = match payload {
+                Some(payload) => create_boc_or_comment_payload(&payload)
+                    .handle_error()?
+                    .into_cell(),
+                None => ton_types::Cell::default(),
+            };
+
+            let expiration = serde_json::from_str::<Expiration>(&expiration).handle_error()?;
+
+            let transfer = token_wallet_contract::transfer();
+
+            let transfer_input = serde_json::json!({
+                "amount": amount,
+                "recipient": recipient.to_string(),
+                "deployWalletValue": deploy_wallet_value,
+                "remainingGasTo": ton_wallet.address().to_string(),
+                "notify": notify_receiver,
+                "payload": base64::encode(ton_types::serialize_toc(&payload).handle_error()?),
+            });
+            let transfer_input =
+                nekoton_abi::parse_abi_tokens(&transfer.inputs, transfer_input).handle_error()?;
+
+            let body = transfer
+                .encode_internal_input(&transfer_input)
+                .and_then(|e| e.into_cell())
+                .handle_error()?;
+
+            // Wallet specific encoding, seqno and custodians are handled by the wallet itself
+            let gift = Gift {
+                flags: MessageFlags::default().into(),
+                bounce: true,
+                destination: token_wallet_address,
+                amount: attached_amount,
+                body: Some(body.into()),
+                state_init: None,
+            };
+
+            let action = ton_wallet
+                .prepare_transfer(&current_state, &public_key, gift, expiration)
+                .handle_error()?;
+
+            let unsigned_message = match action {
+                TransferAction::DeployFirst => return Err("Deploy first").handle_error(),
+                TransferAction::Sign(unsigned_message) => unsigned_message,
+            };
+
+            let ptr = Box::into_raw(Box::new(RwLock::new(unsigned_message)));
+
+            serde_json::to_value(ptr as usize).handle_error()
+        }
+
+        let mut ton_wallet = ton_wallet.write().await;
+
+        let result = internal_fn(
+            &mut ton_wallet,
+            contract_state,
+            public_key,
+            token_wallet_address,
+            recipient,
+            amount,
+            deploy_wallet_value,
+            notify_receiver != 0,
+            attached_amount,
+            payload,
+            expiration,
+        )
+        .match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_ton_wallet_prepare_confirm_transaction(
     result_port: c_longlong,
@@ -638,7 +991,9 @@ pub unsafe extern "C" fn nt_ton_wallet_prepare_confirm_transaction(
     let transaction_id = transaction_id.to_string_from_ptr();
     let expiration = expiration.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_prepare_confirm_transaction");
+
         fn internal_fn(
             ton_wallet: &TonWallet,
             contract_state: String,
@@ -702,7 +1057,9 @@ pub unsafe extern "C" fn nt_ton_wallet_estimate_fees(
 
     let signed_message = signed_message.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_estimate_fees");
+
         async fn internal_fn(
             ton_wallet: &mut TonWallet,
             signed_message: String,
@@ -740,7 +1097,9 @@ pub unsafe extern "C" fn nt_ton_wallet_send(
 
     let signed_message = signed_message.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_send");
+
         async fn internal_fn(
             ton_wallet: &mut TonWallet,
             signed_message: String,
@@ -748,11 +1107,16 @@ pub unsafe extern "C" fn nt_ton_wallet_send(
             let signed_message =
                 serde_json::from_str::<SignedMessage>(&signed_message).handle_error()?;
 
+            send_journal::record_send_started(&signed_message.message, signed_message.expire_at)
+                .await?;
+
             let pending_transaction = ton_wallet
                 .send(&signed_message.message, signed_message.expire_at)
                 .await
                 .handle_error()?;
 
+            send_journal::record_send_finished(&signed_message.message).await;
+
             serde_json::to_value(&pending_transaction).handle_error()
         }
 
@@ -766,11 +1130,156 @@ pub unsafe extern "C" fn nt_ton_wallet_send(
     });
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_ton_wallet_send_with_preflight(
+    result_port: c_longlong,
+    ton_wallet: *mut c_void,
+    transport: *mut c_void,
+    transport_type: *mut c_char,
+    signed_message: *mut c_char,
+    options: *mut c_char,
+    cancellation_token: *mut c_void,
+) {
+    let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
+
+    let transport_type = transport_type.to_string_from_ptr();
+    let signed_message = signed_message.to_string_from_ptr();
+    let options = options.to_optional_string_from_ptr();
+
+    let transport = match_transport(transport, &transport_type);
+
+    let cancellation_token = CancellationToken::from_ptr(cancellation_token);
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_send_with_preflight");
+
+        async fn preflight_fn(
+            ton_wallet: &RwLock<TonWallet>,
+            transport: Arc<dyn Transport>,
+            signed_message: String,
+            options: Option<String>,
+        ) -> Result<SignedMessage, String> {
+            let signed_message =
+                serde_json::from_str::<SignedMessage>(&signed_message).handle_error()?;
+
+            let options = options
+                .map(|e| serde_json::from_str::<PreflightOptions>(&e))
+                .transpose()
+                .handle_error()?
+                .unwrap_or_default();
+
+            let address = ton_wallet.read().await.address().to_owned();
+
+            match fetch_preflight_environment(&transport, &address).await {
+                Ok((account, config)) => preflight(account, &signed_message.message, config)?,
+                Err(_) if options.skip_preflight_on_fetch_failure => {},
+                Err(err) => return Err(err),
+            }
+
+            Ok(signed_message)
+        }
+
+        async fn internal_fn(
+            ton_wallet: &RwLock<TonWallet>,
+            signed_message: Result<SignedMessage, String>,
+        ) -> Result<serde_json::Value, String> {
+            let signed_message = signed_message?;
+
+            send_journal::record_send_started(&signed_message.message, signed_message.expire_at)
+                .await?;
+
+            let pending_transaction = ton_wallet
+                .write()
+                .await
+                .send(&signed_message.message, signed_message.expire_at)
+                .await
+                .handle_error()?;
+
+            send_journal::record_send_finished(&signed_message.message).await;
+
+            serde_json::to_value(&pending_transaction).handle_error()
+        }
+
+        let preflight = preflight_fn(ton_wallet, transport, signed_message, options);
+
+        // Cancellation is only honoured before the message is journaled and broadcast
+        let signed_message = match cancellation_token {
+            Some(cancellation_token) => {
+                match cancellation_token.run_until_cancelled(preflight).await {
+                    Some(signed_message) => signed_message,
+                    None => {
+                        Isolate::new(result_port)
+                            .post_with_result(cancelled_result())
+                            .unwrap();
+                        return;
+                    },
+                }
+            },
+            None => preflight.await,
+        };
+
+        let result = internal_fn(ton_wallet, signed_message).await.match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
+/// Fetches the current on-chain account state, bypassing the subscription, and the latest config
+async fn fetch_preflight_environment(
+    transport: &Arc<dyn Transport>,
+    address: &MsgAddressInt,
+) -> Result<(Account, BlockchainConfig), String> {
+    let account = match transport.get_contract_state(address).await.handle_error()? {
+        RawContractState::Exists(contract) => Account::Account(contract.account),
+        RawContractState::NotExists => Account::AccountNone,
+    };
+
+    let config = get_network_config(transport).await?.config.clone();
+
+    Ok((account, config))
+}
+
+/// Emulates the message against the fetched state, failing with the emulated exit code
+fn preflight(
+    account: Account,
+    message: &ton_block::Message,
+    config: BlockchainConfig,
+) -> Result<(), String> {
+    let utime = clock!().now_sec_u64() as u32;
+
+    let lt = match &account {
+        Account::Account(account_stuff) => tvm::local_execution_lt(account_stuff),
+        Account::AccountNone => 0,
+    };
+
+    match tvm::execute_transaction(account, message, config, utime, lt)? {
+        LocalTransactionExecution::Executed { aborted: false, .. } => Ok(()),
+        LocalTransactionExecution::Executed { exit_code, .. } => match exit_code {
+            Some(exit_code) => Err(format!("Preflight failed with exit code {}", exit_code)),
+            None => Err("Preflight failed: compute phase skipped").handle_error(),
+        },
+        LocalTransactionExecution::NotAccepted { exit_code } => Err(format!(
+            "Preflight failed: message not accepted, exit code {}",
+            exit_code
+        )),
+        LocalTransactionExecution::AccountNotExists => {
+            Err("Preflight failed: account not exists").handle_error()
+        },
+        LocalTransactionExecution::AccountFrozen => {
+            Err("Preflight failed: account is frozen").handle_error()
+        },
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_ton_wallet_refresh(result_port: c_longlong, ton_wallet: *mut c_void) {
+    let subscription = ton_wallet as usize;
+
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_refresh");
+
         async fn internal_fn(ton_wallet: &mut TonWallet) -> Result<serde_json::Value, String> {
             ton_wallet.refresh().await.handle_error()?;
 
@@ -779,7 +1288,11 @@ pub unsafe extern "C" fn nt_ton_wallet_refresh(result_port: c_longlong, ton_wall
 
         let mut ton_wallet = ton_wallet.write().await;
 
-        let result = internal_fn(&mut ton_wallet).await.match_result();
+        let result = internal_fn(&mut ton_wallet).await;
+
+        track_subscription_result(subscription, &result);
+
+        let result = result.match_result();
 
         Isolate::new(result_port).post_with_result(result).unwrap();
     });
@@ -790,12 +1303,19 @@ pub unsafe extern "C" fn nt_ton_wallet_preload_transactions(
     result_port: c_longlong,
     ton_wallet: *mut c_void,
     from_lt: *mut c_char,
+    cancellation_token: *mut c_void,
 ) {
+    let subscription = ton_wallet as usize;
+
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
     let from_lt = from_lt.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    let cancellation_token = CancellationToken::from_ptr(cancellation_token);
+
+    spawn_cancellable_ffi_task(result_port, cancellation_token, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_preload_transactions");
+
         async fn internal_fn(
             ton_wallet: &mut TonWallet,
             from_lt: String,
@@ -812,7 +1332,11 @@ pub unsafe extern "C" fn nt_ton_wallet_preload_transactions(
 
         let mut ton_wallet = ton_wallet.write().await;
 
-        let result = internal_fn(&mut ton_wallet, from_lt).await.match_result();
+        let result = internal_fn(&mut ton_wallet, from_lt).await;
+
+        track_subscription_result(subscription, &result);
+
+        let result = result.match_result();
 
         Isolate::new(result_port).post_with_result(result).unwrap();
     });
@@ -824,11 +1348,15 @@ pub unsafe extern "C" fn nt_ton_wallet_handle_block(
     ton_wallet: *mut c_void,
     block: *mut c_char,
 ) {
+    let subscription = ton_wallet as usize;
+
     let ton_wallet = &*(ton_wallet as *mut RwLock<TonWallet>);
 
     let block = block.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_ton_wallet_handle_block");
+
         async fn internal_fn(
             ton_wallet: &mut TonWallet,
             block: String,
@@ -842,7 +1370,11 @@ pub unsafe extern "C" fn nt_ton_wallet_handle_block(
 
         let mut ton_wallet = ton_wallet.write().await;
 
-        let result = internal_fn(&mut ton_wallet, block).await.match_result();
+        let result = internal_fn(&mut ton_wallet, block).await;
+
+        track_subscription_result(subscription, &result);
+
+        let result = result.match_result();
 
         Isolate::new(result_port).post_with_result(result).unwrap();
     });
@@ -856,6 +1388,7 @@ pub unsafe extern "C" fn nt_find_existing_wallets(
     public_key: *mut c_char,
     workchain_id: c_schar,
     wallet_types: *mut c_char,
+    cancellation_token: *mut c_void,
 ) {
     let transport_type = transport_type.to_string_from_ptr();
     let public_key = public_key.to_string_from_ptr();
@@ -863,7 +1396,11 @@ pub unsafe extern "C" fn nt_find_existing_wallets(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    let cancellation_token = CancellationToken::from_ptr(cancellation_token);
+
+    spawn_cancellable_ffi_task(result_port, cancellation_token, async move {
+        let _timer = ffi_timer!("nt_find_existing_wallets");
+
         async fn internal_fn(
             transport: Arc<dyn Transport>,
             public_key: String,
@@ -909,7 +1446,9 @@ pub unsafe extern "C" fn nt_get_existing_wallet_info(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_get_existing_wallet_info");
+
         async fn internal_fn(
             transport: Arc<dyn Transport>,
             address: String,
@@ -959,7 +1498,9 @@ pub unsafe extern "C" fn nt_get_wallet_custodians(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_get_wallet_custodians");
+
         async fn internal_fn(
             transport: Arc<dyn Transport>,
             address: String,
@@ -1001,8 +1542,39 @@ pub unsafe extern "C" fn nt_get_wallet_custodians(
     });
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_compute_code_hash_from_abi(wallet_type: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_compute_code_hash_from_abi");
+
+    let wallet_type = wallet_type.to_string_from_ptr();
+
+    fn internal_fn(wallet_type: String) -> Result<serde_json::Value, String> {
+        let wallet_type = serde_json::from_str::<WalletTypeHelper>(&wallet_type)
+            .map(|WalletTypeHelper(wallet_type)| wallet_type)
+            .handle_error()?;
+
+        // Code hashes of built-in wallets are constants, so no code cell is deserialized here
+        let code_hash = hex::encode(wallet_type.code_hash());
+
+        serde_json::to_value(code_hash).handle_error()
+    }
+
+    internal_fn(wallet_type).match_result()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_ton_wallet_free_ptr(ptr: *mut c_void) {
     println!("nt_ton_wallet_free_ptr");
+    remove_subscription_error_counter(ptr as usize);
+    track_subscription_destroyed(ptr as usize);
     Box::from_raw(ptr as *mut Arc<RwLock<TonWallet>>);
 }
+
+/// Pending transaction id keeps its creation time in the upper 32 bits, while the lifetime
+/// is fixed by the contract code
+fn multisig_transaction_lifetime(multisig_type: MultisigType) -> u32 {
+    match multisig_type {
+        MultisigType::SafeMultisigWallet24h | MultisigType::SetcodeMultisigWallet24h => 86400,
+        _ => 3600,
+    }
+}
@@ -1,6 +1,6 @@
 use nekoton::core::{
-    models::ContractState,
-    ton_wallet::{ExistingWalletInfo, MultisigType, WalletType},
+    models::{ContractState, MultisigPendingTransaction},
+    ton_wallet::{ExistingWalletInfo, MultisigType, TonWalletDetails, WalletType},
 };
 use nekoton_utils::{serde_address, serde_public_key};
 use serde::{Deserialize, Serialize};
@@ -38,3 +38,78 @@ pub struct ExistingWalletInfoDef {
     pub wallet_type: WalletType,
     pub contract_state: ContractState,
 }
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnconfirmedTransaction<'a> {
+    #[serde(flatten)]
+    pub transaction: &'a MultisigPendingTransaction,
+    pub expire_at: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TonWalletDetailsOutput {
+    pub requires_separate_deploy: bool,
+    pub min_amount: String,
+    pub supports_payload: bool,
+    pub supports_multiple_owners: bool,
+    pub expiration_time: u32,
+}
+
+impl From<TonWalletDetails> for TonWalletDetailsOutput {
+    fn from(details: TonWalletDetails) -> Self {
+        Self {
+            requires_separate_deploy: details.requires_separate_deploy,
+            min_amount: details.min_amount.to_string(),
+            supports_payload: details.supports_payload,
+            supports_multiple_owners: details.supports_multiple_owners,
+            expiration_time: details.expiration_time,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum TransferUriIssue {
+    InvalidUri {
+        reason: String,
+    },
+    InvalidAddress {
+        address: String,
+    },
+    InvalidAmount {
+        amount: String,
+    },
+    MissingAmount,
+    UnsupportedParameter {
+        name: String,
+    },
+    InvalidBody {
+        reason: String,
+    },
+    /// Wallet has to be deployed before it can send the transfer
+    DeployFirst,
+    #[serde(rename_all = "camelCase")]
+    InsufficientBalance {
+        balance: String,
+        amount: String,
+        fees: String,
+    },
+}
+
+/// Either a message handle ready to be signed or the reasons why it was not created
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferFromUriOutput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unsigned_message: Option<usize>,
+    pub issues: Vec<TransferUriIssue>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PreflightOptions {
+    #[serde(default)]
+    pub skip_preflight_on_fetch_failure: bool,
+}
@@ -0,0 +1,173 @@
+use ton_block::MsgAddressInt;
+
+use crate::{core::ton_wallet::models::TransferUriIssue, parse_address};
+
+const TRANSFER_URI_PREFIX: &str = "ton://transfer/";
+
+pub struct TransferUri {
+    pub destination: MsgAddressInt,
+    pub amount: u64,
+    pub body: Option<ton_types::SliceData>,
+}
+
+/// Parses `ton://transfer/<address>?amount=<nano>&text=<comment>` or `&bin=<base64 boc>`
+pub fn parse_transfer_uri(uri: &str) -> Result<TransferUri, Vec<TransferUriIssue>> {
+    let uri = match uri.trim().strip_prefix(TRANSFER_URI_PREFIX) {
+        Some(uri) => uri,
+        None => {
+            return Err(vec![TransferUriIssue::InvalidUri {
+                reason: format!("Expected {} prefix", TRANSFER_URI_PREFIX),
+            }])
+        },
+    };
+
+    let (address, query) = uri.split_once('?').unwrap_or((uri, ""));
+
+    let mut issues = Vec::new();
+
+    let destination = parse_address(address)
+        .or_else(|_| nekoton_utils::unpack_std_smc_addr(address, true).map_err(|e| e.to_string()))
+        .or_else(|_| nekoton_utils::unpack_std_smc_addr(address, false).map_err(|e| e.to_string()))
+        .map_err(|_| {
+            issues.push(TransferUriIssue::InvalidAddress {
+                address: address.to_owned(),
+            })
+        })
+        .ok();
+
+    let mut amount = None;
+    let mut body = None;
+
+    for param in query.split('&').filter(|e| !e.is_empty()) {
+        let (name, value) = param.split_once('=').unwrap_or((param, ""));
+
+        let value = match percent_decode(value) {
+            Some(value) => value,
+            None => {
+                issues.push(TransferUriIssue::InvalidUri {
+                    reason: format!("Malformed {} parameter", name),
+                });
+                continue;
+            },
+        };
+
+        match name {
+            "amount" => match value.parse::<u64>() {
+                Ok(value) => amount = Some(value),
+                Err(_) => issues.push(TransferUriIssue::InvalidAmount { amount: value }),
+            },
+            "text" if body.is_none() => match nekoton_abi::create_comment_payload(&value) {
+                Ok(payload) => body = Some(payload),
+                Err(err) => issues.push(TransferUriIssue::InvalidBody {
+                    reason: err.to_string(),
+                }),
+            },
+            "bin" if body.is_none() => match parse_bin_payload(&value) {
+                Ok(payload) => body = Some(payload),
+                Err(reason) => issues.push(TransferUriIssue::InvalidBody { reason }),
+            },
+            _ => issues.push(TransferUriIssue::UnsupportedParameter {
+                name: name.to_owned(),
+            }),
+        }
+    }
+
+    if amount.is_none()
+        && !issues
+            .iter()
+            .any(|e| matches!(e, TransferUriIssue::InvalidAmount { .. }))
+    {
+        issues.push(TransferUriIssue::MissingAmount);
+    }
+
+    match (destination, amount) {
+        (Some(destination), Some(amount)) if issues.is_empty() => Ok(TransferUri {
+            destination,
+            amount,
+            body,
+        }),
+        _ => Err(issues),
+    }
+}
+
+/// Strict counterpart of a BOC payload, unlike `create_boc_or_comment_payload` it never falls
+/// back to a comment
+fn parse_bin_payload(value: &str) -> Result<ton_types::SliceData, String> {
+    // URIs usually carry URL-safe base64, but the standard alphabet is seen as well
+    let bytes = base64::decode_config(value, base64::URL_SAFE)
+        .or_else(|_| base64::decode(value))
+        .map_err(|_| "Invalid base64".to_owned())?;
+
+    ton_types::deserialize_tree_of_cells(&mut bytes.as_slice())
+        .map(From::from)
+        .map_err(|e| e.to_string())
+}
+
+fn percent_decode(value: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut iter = value.bytes();
+
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'%' => {
+                let hex = [iter.next()?, iter.next()?];
+                let hex = std::str::from_utf8(&hex).ok()?;
+                bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            },
+            b'+' => bytes.push(b' '),
+            byte => bytes.push(byte),
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "0:a921453472366b7feeec15323a96b5dcf17197c88dc0d4578dfa52900b8a33cb";
+
+    fn issues(uri: &str) -> Vec<TransferUriIssue> {
+        match parse_transfer_uri(uri) {
+            Ok(_) => panic!("Expected issues"),
+            Err(issues) => issues,
+        }
+    }
+
+    #[test]
+    fn text_becomes_comment_payload() {
+        let uri = format!("ton://transfer/{}?amount=1000&text=AQID", ADDRESS);
+
+        let body = parse_transfer_uri(&uri).ok().unwrap().body.unwrap();
+        let expected = nekoton_abi::create_comment_payload("AQID").unwrap();
+
+        assert_eq!(body.into_cell(), expected.into_cell());
+    }
+
+    #[test]
+    fn bin_is_parsed_as_boc() {
+        let cell = nekoton_abi::create_comment_payload("hello")
+            .unwrap()
+            .into_cell();
+        let boc = base64::encode_config(ton_types::serialize_toc(&cell).unwrap(), base64::URL_SAFE);
+
+        let uri = format!("ton://transfer/{}?amount=1000&bin={}", ADDRESS, boc);
+
+        let body = parse_transfer_uri(&uri).ok().unwrap().body.unwrap();
+
+        assert_eq!(body.into_cell(), cell);
+    }
+
+    #[test]
+    fn invalid_bin_is_an_issue() {
+        for bin in ["hello", "AQID"] {
+            let uri = format!("ton://transfer/{}?amount=1000&bin={}", ADDRESS, bin);
+
+            assert!(matches!(
+                issues(&uri).as_slice(),
+                [TransferUriIssue::InvalidBody { .. }]
+            ));
+        }
+    }
+}
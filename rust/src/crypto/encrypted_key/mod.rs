@@ -1,11 +1,33 @@
+use std::ops::RangeInclusive;
+
+use chacha20poly1305::{aead::Aead, ChaCha20Poly1305, Key, KeyInit, Nonce};
+use hmac::Hmac;
 use nekoton::crypto::{EncryptedKeyCreateInput, EncryptedKeyExportOutput, MnemonicType, Password};
+use rand::RngCore;
 use secstr::SecUtf8;
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroizing;
 
 use super::mnemonic::models::MnemonicTypeDef;
+use crate::HandleError;
 
 pub(crate) const ENCRYPTED_KEY_SIGNER_NAME: &str = "EncryptedKeySigner";
 
+const EXPORTED_KEY_VERSION: u32 = 1;
+
+/// Scrypt cost of new exports, 32 MiB of memory with `r = 8`
+const EXPORTED_KEY_SCRYPT_LOG_N: u8 = 15;
+const EXPORTED_KEY_SCRYPT_R: u32 = 8;
+const EXPORTED_KEY_SCRYPT_P: u32 = 1;
+
+/// Bounds of KDF params accepted on import, files outside them are either weak or a way to
+/// stall the app
+const SCRYPT_LOG_N_RANGE: RangeInclusive<u8> = 14..=20;
+const SCRYPT_R_RANGE: RangeInclusive<u32> = 8..=8;
+const SCRYPT_P_RANGE: RangeInclusive<u32> = 1..=4;
+const PBKDF2_ITERATIONS_RANGE: RangeInclusive<u32> = 100_000..=10_000_000;
+
 #[derive(Deserialize)]
 pub struct EncryptedKeyCreateInputHelper(
     #[serde(with = "EncryptedKeyCreateInputDef")] pub EncryptedKeyCreateInput,
@@ -21,15 +43,264 @@ pub struct EncryptedKeyCreateInputDef {
     pub password: Password,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 pub struct EncryptedKeyExportOutputHelper(
     #[serde(with = "EncryptedKeyExportOutputDef")] pub EncryptedKeyExportOutput,
 );
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(remote = "EncryptedKeyExportOutput", rename_all = "camelCase")]
 pub struct EncryptedKeyExportOutputDef {
     pub phrase: SecUtf8,
     #[serde(with = "MnemonicTypeDef")]
     pub mnemonic_type: MnemonicType,
 }
+
+/// Standalone password protected file with a single key
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedKey {
+    pub version: u32,
+    pub signer: String,
+    pub public_key: String,
+    pub kdf: ExportedKeyKdf,
+    pub cipher: ExportedKeyCipher,
+    pub ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "algorithm")]
+pub enum ExportedKeyKdf {
+    #[serde(rename_all = "camelCase")]
+    Scrypt {
+        log_n: u8,
+        r: u32,
+        p: u32,
+        salt: String,
+    },
+    /// Only read, files written before the switch to scrypt use it
+    #[serde(rename = "pbkdf2HmacSha256")]
+    Pbkdf2HmacSha256 { iterations: u32, salt: String },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "algorithm")]
+pub enum ExportedKeyCipher {
+    #[serde(rename = "chaCha20Poly1305")]
+    ChaCha20Poly1305 { nonce: String },
+}
+
+pub fn encrypt_exported_key(
+    public_key: &ed25519_dalek::PublicKey,
+    output: EncryptedKeyExportOutput,
+    export_password: &str,
+) -> Result<ExportedKey, String> {
+    let mut salt = [0u8; 32];
+    let mut nonce = [0u8; 12];
+    rand::thread_rng().fill_bytes(&mut salt);
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let kdf = ExportedKeyKdf::Scrypt {
+        log_n: EXPORTED_KEY_SCRYPT_LOG_N,
+        r: EXPORTED_KEY_SCRYPT_R,
+        p: EXPORTED_KEY_SCRYPT_P,
+        salt: base64::encode(salt),
+    };
+
+    let cipher = make_cipher(export_password, &kdf)?;
+
+    let plaintext = SecUtf8::from(
+        serde_json::to_string(&EncryptedKeyExportOutputHelper(output)).handle_error()?,
+    );
+
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext.unsecure().as_bytes())
+        .map_err(|_| "Failed to encrypt key")
+        .handle_error()?;
+
+    Ok(ExportedKey {
+        version: EXPORTED_KEY_VERSION,
+        signer: ENCRYPTED_KEY_SIGNER_NAME.to_owned(),
+        public_key: hex::encode(public_key.as_bytes()),
+        kdf,
+        cipher: ExportedKeyCipher::ChaCha20Poly1305 {
+            nonce: base64::encode(nonce),
+        },
+        ciphertext: base64::encode(ciphertext),
+    })
+}
+
+pub fn decrypt_exported_key(
+    exported_key: &ExportedKey,
+    export_password: &str,
+) -> Result<EncryptedKeyExportOutput, String> {
+    if exported_key.version != EXPORTED_KEY_VERSION {
+        return Err(format!(
+            "Unsupported exported key version: {}",
+            exported_key.version
+        ));
+    }
+
+    if exported_key.signer != ENCRYPTED_KEY_SIGNER_NAME {
+        return Err(format!("Unsupported signer: {}", exported_key.signer));
+    }
+
+    let cipher = make_cipher(export_password, &exported_key.kdf)?;
+
+    let nonce = match &exported_key.cipher {
+        ExportedKeyCipher::ChaCha20Poly1305 { nonce } => base64::decode(nonce).handle_error()?,
+    };
+
+    if nonce.len() != 12 {
+        return Err("Invalid nonce length").handle_error();
+    }
+
+    let ciphertext = base64::decode(&exported_key.ciphertext).handle_error()?;
+
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+        .map(Zeroizing::new)
+        .map_err(|_| "Invalid export password")
+        .handle_error()?;
+
+    let plaintext = std::str::from_utf8(&plaintext).handle_error()?;
+
+    serde_json::from_str::<EncryptedKeyExportOutputHelper>(plaintext)
+        .map(|EncryptedKeyExportOutputHelper(output)| output)
+        .handle_error()
+}
+
+fn make_cipher(password: &str, kdf: &ExportedKeyKdf) -> Result<ChaCha20Poly1305, String> {
+    let mut key = Zeroizing::new([0u8; 32]);
+
+    match kdf {
+        ExportedKeyKdf::Scrypt { log_n, r, p, salt } => {
+            if !SCRYPT_LOG_N_RANGE.contains(log_n)
+                || !SCRYPT_R_RANGE.contains(r)
+                || !SCRYPT_P_RANGE.contains(p)
+            {
+                return Err("Unsupported scrypt params").handle_error();
+            }
+
+            let salt = base64::decode(salt).handle_error()?;
+            let params = scrypt::Params::new(*log_n, *r, *p).handle_error()?;
+
+            scrypt::scrypt(password.as_bytes(), &salt, &params, &mut key[..]).handle_error()?;
+        },
+        ExportedKeyKdf::Pbkdf2HmacSha256 { iterations, salt } => {
+            if !PBKDF2_ITERATIONS_RANGE.contains(iterations) {
+                return Err("Unsupported PBKDF2 iterations").handle_error();
+            }
+
+            let salt = base64::decode(salt).handle_error()?;
+
+            pbkdf2::pbkdf2::<Hmac<Sha256>>(password.as_bytes(), &salt, *iterations, &mut key[..]);
+        },
+    }
+
+    Ok(ChaCha20Poly1305::new(Key::from_slice(&key[..])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "penalty vacant bracket dust adapt assist crash soup cancel rent clinic \
+        lock";
+
+    fn export_output() -> EncryptedKeyExportOutput {
+        EncryptedKeyExportOutput {
+            phrase: SecUtf8::from(PHRASE),
+            mnemonic_type: MnemonicType::Labs(0),
+        }
+    }
+
+    fn public_key() -> ed25519_dalek::PublicKey {
+        let secret = ed25519_dalek::SecretKey::from_bytes(&[7; 32]).unwrap();
+
+        ed25519_dalek::PublicKey::from(&secret)
+    }
+
+    #[test]
+    fn exported_key_round_trip() {
+        let exported_key =
+            encrypt_exported_key(&public_key(), export_output(), "password").unwrap();
+
+        assert!(matches!(
+            exported_key.kdf,
+            ExportedKeyKdf::Scrypt {
+                log_n: EXPORTED_KEY_SCRYPT_LOG_N,
+                ..
+            }
+        ));
+
+        let output = decrypt_exported_key(&exported_key, "password").unwrap();
+
+        assert_eq!(output.phrase.unsecure(), PHRASE);
+        assert!(matches!(output.mnemonic_type, MnemonicType::Labs(0)));
+    }
+
+    #[test]
+    fn exported_key_rejects_wrong_password() {
+        let exported_key =
+            encrypt_exported_key(&public_key(), export_output(), "password").unwrap();
+
+        assert!(decrypt_exported_key(&exported_key, "other").is_err());
+    }
+
+    #[test]
+    fn exported_key_rejects_weak_or_costly_kdf() {
+        let salt = base64::encode([0u8; 32]);
+
+        for iterations in [1, 10_000_001] {
+            let kdf = ExportedKeyKdf::Pbkdf2HmacSha256 {
+                iterations,
+                salt: salt.clone(),
+            };
+
+            assert!(make_cipher("password", &kdf).is_err());
+        }
+
+        for (log_n, r, p) in [(10, 8, 1), (21, 8, 1), (15, 1, 1), (15, 8, 64)] {
+            let kdf = ExportedKeyKdf::Scrypt {
+                log_n,
+                r,
+                p,
+                salt: salt.clone(),
+            };
+
+            assert!(make_cipher("password", &kdf).is_err());
+        }
+    }
+
+    #[test]
+    fn legacy_pbkdf2_export_is_readable() {
+        let mut exported_key =
+            encrypt_exported_key(&public_key(), export_output(), "password").unwrap();
+
+        // Re-encrypt the same payload the way files written before scrypt were
+        let salt = base64::encode([1u8; 32]);
+        let kdf = ExportedKeyKdf::Pbkdf2HmacSha256 {
+            iterations: 100_000,
+            salt,
+        };
+
+        let nonce = match &exported_key.cipher {
+            ExportedKeyCipher::ChaCha20Poly1305 { nonce } => base64::decode(nonce).unwrap(),
+        };
+
+        let plaintext =
+            serde_json::to_string(&EncryptedKeyExportOutputHelper(export_output())).unwrap();
+        let ciphertext = make_cipher("password", &kdf)
+            .unwrap()
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_bytes())
+            .unwrap();
+
+        exported_key.kdf = kdf;
+        exported_key.ciphertext = base64::encode(ciphertext);
+
+        let output = decrypt_exported_key(&exported_key, "password").unwrap();
+
+        assert_eq!(output.phrase.unsecure(), PHRASE);
+    }
+}
@@ -4,14 +4,17 @@ use std::os::raw::c_char;
 
 use anyhow::Result;
 use nekoton::crypto::{derive_from_phrase, dict, generate_key};
+use rand::{rngs::OsRng, RngCore};
 
 use crate::{
     crypto::mnemonic::models::{GeneratedKeyHelper, KeypairHelper, MnemonicTypeHelper},
-    HandleError, MatchResult, ToStringFromPtr,
+    ffi_timer, HandleError, MatchResult, ToStringFromPtr,
 };
 
 #[no_mangle]
 pub unsafe extern "C" fn nt_generate_key(mnemonic_type: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_generate_key");
+
     let mnemonic_type = mnemonic_type.to_string_from_ptr();
 
     fn internal_fn(mnemonic_type: String) -> Result<serde_json::Value, String> {
@@ -27,8 +30,29 @@ pub unsafe extern "C" fn nt_generate_key(mnemonic_type: *mut c_char) -> *mut c_c
     internal_fn(mnemonic_type).match_result()
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_generate_key_pair() -> *mut c_char {
+    let _timer = ffi_timer!("nt_generate_key_pair");
+
+    fn internal_fn() -> Result<serde_json::Value, String> {
+        let mut secret = [0u8; ed25519_dalek::SECRET_KEY_LENGTH];
+        OsRng.try_fill_bytes(&mut secret).handle_error()?;
+
+        let secret = ed25519_dalek::SecretKey::from_bytes(&secret).handle_error()?;
+        let public = ed25519_dalek::PublicKey::from(&secret);
+
+        let keypair = ed25519_dalek::Keypair { secret, public };
+
+        serde_json::to_value(&KeypairHelper(keypair)).handle_error()
+    }
+
+    internal_fn().match_result()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_get_hints(input: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_hints");
+
     let input = input.to_string_from_ptr();
 
     fn internal_fn(input: String) -> Result<serde_json::Value, String> {
@@ -45,6 +69,8 @@ pub unsafe extern "C" fn nt_derive_from_phrase(
     phrase: *mut c_char,
     mnemonic_type: *mut c_char,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_derive_from_phrase");
+
     let phrase = phrase.to_string_from_ptr();
     let mnemonic_type = mnemonic_type.to_string_from_ptr();
 
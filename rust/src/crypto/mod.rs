@@ -12,8 +12,8 @@ use nekoton::crypto::UnsignedMessage;
 use tokio::sync::RwLock;
 
 use crate::{
-    clock, parse_public_key, runtime, HandleError, MatchResult, PostWithResult, ToStringFromPtr,
-    CLOCK, RUNTIME,
+    clock, crypto::models::SignatureEncoding, ffi_timer, parse_public_key, spawn_ffi_task,
+    HandleError, MatchResult, PostWithResult, ToStringFromPtr, CLOCK,
 };
 
 #[no_mangle]
@@ -23,7 +23,9 @@ pub unsafe extern "C" fn nt_unsigned_message_refresh_timeout(
 ) {
     let unsigned_message = &*(unsigned_message as *mut RwLock<Box<dyn UnsignedMessage>>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_unsigned_message_refresh_timeout");
+
         fn internal_fn(
             unsigned_message: &mut Box<dyn UnsignedMessage>,
         ) -> Result<serde_json::Value, String> {
@@ -47,7 +49,9 @@ pub unsafe extern "C" fn nt_unsigned_message_expire_at(
 ) {
     let unsigned_message = &*(unsigned_message as *mut RwLock<Box<dyn UnsignedMessage>>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_unsigned_message_expire_at");
+
         fn internal_fn(
             unsigned_message: &Box<dyn UnsignedMessage>,
         ) -> Result<serde_json::Value, String> {
@@ -71,7 +75,9 @@ pub unsafe extern "C" fn nt_unsigned_message_hash(
 ) {
     let unsigned_message = &*(unsigned_message as *mut RwLock<Box<dyn UnsignedMessage>>);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_unsigned_message_hash");
+
         fn internal_fn(
             unsigned_message: &Box<dyn UnsignedMessage>,
         ) -> Result<serde_json::Value, String> {
@@ -95,21 +101,33 @@ pub unsafe extern "C" fn nt_unsigned_message_sign(
     result_port: c_longlong,
     unsigned_message: *mut c_void,
     signature: *mut c_char,
+    signature_encoding: *mut c_char,
 ) {
     let unsigned_message = &*(unsigned_message as *mut RwLock<Box<dyn UnsignedMessage>>);
 
     let signature = signature.to_string_from_ptr();
+    let signature_encoding = signature_encoding.to_string_from_ptr();
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_unsigned_message_sign");
 
-    runtime!().spawn(async move {
         fn internal_fn(
             unsigned_message: &Box<dyn UnsignedMessage>,
             signature: String,
+            signature_encoding: String,
         ) -> Result<serde_json::Value, String> {
-            let signature: [u8; ed25519_dalek::SIGNATURE_LENGTH] = base64::decode(&signature)
-                .handle_error()?
-                .as_slice()
-                .try_into()
-                .handle_error()?;
+            let signature_encoding =
+                serde_json::from_str::<SignatureEncoding>(&signature_encoding).handle_error()?;
+
+            let signature = match signature_encoding {
+                SignatureEncoding::Hex => hex::decode(&signature).handle_error()?,
+                SignatureEncoding::Base64 => base64::decode(&signature).handle_error()?,
+            };
+
+            let signature: [u8; ed25519_dalek::SIGNATURE_LENGTH] = match signature.try_into() {
+                Ok(signature) => signature,
+                Err(_) => return Err("Invalid signature. Expected 64 bytes").handle_error(),
+            };
 
             let signed_message = unsigned_message.sign(&signature).handle_error()?;
 
@@ -118,7 +136,7 @@ pub unsafe extern "C" fn nt_unsigned_message_sign(
 
         let unsigned_message = unsigned_message.read().await;
 
-        let result = internal_fn(&unsigned_message, signature).match_result();
+        let result = internal_fn(&unsigned_message, signature, signature_encoding).match_result();
 
         Isolate::new(result_port).post_with_result(result).unwrap();
     });
@@ -136,6 +154,8 @@ pub unsafe extern "C" fn nt_verify_signature(
     data_hash: *mut c_char,
     signature: *mut c_char,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_verify_signature");
+
     let public_key = public_key.to_string_from_ptr();
     let data_hash = data_hash.to_string_from_ptr();
     let signature = signature.to_string_from_ptr();
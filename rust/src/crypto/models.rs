@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -22,3 +22,10 @@ pub struct SignatureParts {
     pub high: String,
     pub low: String,
 }
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SignatureEncoding {
+    Hex,
+    Base64,
+}
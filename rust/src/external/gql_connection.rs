@@ -1,7 +1,7 @@
 use std::{
     ffi::c_void,
     os::raw::{c_char, c_longlong, c_uint},
-    sync::Arc,
+    sync::{Arc, RwLock},
 };
 
 use allo_isolate::Isolate;
@@ -11,11 +11,15 @@ use nekoton::external::GqlConnection;
 use serde::Serialize;
 use tokio::sync::oneshot::{channel, Sender};
 
-use crate::{HandleError, MatchResult};
+use crate::{
+    external::reconnect_backoff::{post_with_backoff, ReconnectBackoff},
+    ffi_timer, HandleError, MatchResult,
+};
 
 pub struct GqlConnectionImpl {
     is_local: bool,
     port: Isolate,
+    pub backoff: Arc<RwLock<ReconnectBackoff>>,
 }
 
 impl GqlConnectionImpl {
@@ -23,17 +27,11 @@ impl GqlConnectionImpl {
         Self {
             is_local,
             port: Isolate::new(port),
+            backoff: Default::default(),
         }
     }
-}
-
-#[async_trait]
-impl GqlConnection for GqlConnectionImpl {
-    fn is_local(&self) -> bool {
-        self.is_local
-    }
 
-    async fn post(&self, data: &str) -> Result<String> {
+    async fn post_once(&self, data: &str) -> Result<String> {
         let (tx, rx) = channel::<Result<String>>();
 
         let tx = Box::into_raw(Box::new(tx)) as usize;
@@ -54,6 +52,17 @@ impl GqlConnection for GqlConnectionImpl {
     }
 }
 
+#[async_trait]
+impl GqlConnection for GqlConnectionImpl {
+    fn is_local(&self) -> bool {
+        self.is_local
+    }
+
+    async fn post(&self, data: &str) -> Result<String> {
+        post_with_backoff(&self.backoff, || self.post_once(data)).await
+    }
+}
+
 #[derive(Serialize)]
 pub struct GqlConnectionPostRequest {
     pub tx: usize,
@@ -65,6 +74,8 @@ pub unsafe extern "C" fn nt_gql_connection_create(
     is_local: c_uint,
     port: c_longlong,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_gql_connection_create");
+
     let is_local = is_local != 0;
 
     fn internal_fn(is_local: bool, port: i64) -> Result<serde_json::Value, String> {
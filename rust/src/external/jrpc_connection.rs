@@ -1,7 +1,7 @@
 use std::{
     ffi::c_void,
     os::raw::{c_char, c_longlong},
-    sync::Arc,
+    sync::{Arc, RwLock},
 };
 
 use allo_isolate::Isolate;
@@ -11,23 +11,25 @@ use nekoton::external::JrpcConnection;
 use serde::Serialize;
 use tokio::sync::oneshot::{channel, Sender};
 
-use crate::{HandleError, MatchResult};
+use crate::{
+    external::reconnect_backoff::{post_with_backoff, ReconnectBackoff},
+    ffi_timer, HandleError, MatchResult,
+};
 
 pub struct JrpcConnectionImpl {
     port: Isolate,
+    pub backoff: Arc<RwLock<ReconnectBackoff>>,
 }
 
 impl JrpcConnectionImpl {
     pub fn new(port: i64) -> Self {
         Self {
             port: Isolate::new(port),
+            backoff: Default::default(),
         }
     }
-}
 
-#[async_trait]
-impl JrpcConnection for JrpcConnectionImpl {
-    async fn post(&self, data: &str) -> Result<String> {
+    async fn post_once(&self, data: &str) -> Result<String> {
         let (tx, rx) = channel::<Result<String>>();
 
         let tx = Box::into_raw(Box::new(tx)) as usize;
@@ -48,6 +50,13 @@ impl JrpcConnection for JrpcConnectionImpl {
     }
 }
 
+#[async_trait]
+impl JrpcConnection for JrpcConnectionImpl {
+    async fn post(&self, data: &str) -> Result<String> {
+        post_with_backoff(&self.backoff, || self.post_once(data)).await
+    }
+}
+
 #[derive(Serialize)]
 pub struct JrpcConnectionPostRequest {
     pub tx: usize,
@@ -56,6 +65,8 @@ pub struct JrpcConnectionPostRequest {
 
 #[no_mangle]
 pub unsafe extern "C" fn nt_jrpc_connection_create(port: c_longlong) -> *mut c_char {
+    let _timer = ffi_timer!("nt_jrpc_connection_create");
+
     fn internal_fn(port: i64) -> Result<serde_json::Value, String> {
         let jrpc_connection = JrpcConnectionImpl::new(port);
 
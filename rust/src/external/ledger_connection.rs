@@ -10,7 +10,7 @@ use nekoton::external::{LedgerConnection, LedgerSignatureContext};
 use serde::Serialize;
 use tokio::sync::oneshot::{channel, Sender};
 
-use crate::{HandleError, MatchResult};
+use crate::{ffi_timer, HandleError, MatchResult};
 
 pub struct LedgerConnectionImpl {
     get_public_key_port: Isolate,
@@ -113,6 +113,8 @@ pub unsafe extern "C" fn nt_ledger_connection_create(
     get_public_key_port: c_longlong,
     sign_port: c_longlong,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_ledger_connection_create");
+
     fn internal_fn(get_public_key_port: i64, sign_port: i64) -> Result<serde_json::Value, String> {
         let ledger_connection = LedgerConnectionImpl::new(get_public_key_port, sign_port);
 
@@ -1,6 +1,7 @@
 pub(crate) mod gql_connection;
 pub(crate) mod jrpc_connection;
 pub(crate) mod ledger_connection;
+pub(crate) mod reconnect_backoff;
 pub(crate) mod storage;
 
 use std::os::raw::{c_char, c_void};
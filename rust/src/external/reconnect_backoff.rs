@@ -0,0 +1,241 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    os::raw::{c_char, c_float, c_uint, c_void},
+    sync::{Arc, Mutex, RwLock},
+    time::Duration,
+};
+
+use anyhow::Result;
+use lazy_static::lazy_static;
+use rand::Rng;
+use serde::Serialize;
+
+use crate::{ffi_timer, HandleError, MatchResult};
+
+const MAX_RECONNECT_ATTEMPTS: u32 = 5;
+
+/// Dart exceptions which mean that the request didn't reach the endpoint or got no answer in time.
+/// Errors reported by the endpoint itself are not repeated
+const RETRYABLE_ERRORS: [&str; 5] = [
+    "SocketException",
+    "TimeoutException",
+    "HttpException",
+    "HandshakeException",
+    "ClientException",
+];
+
+lazy_static! {
+    static ref TRANSPORT_BACKOFFS: Mutex<HashMap<usize, Arc<RwLock<ReconnectBackoff>>>> =
+        Mutex::new(HashMap::new());
+}
+
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconnectBackoff {
+    pub initial_delay_ms: u32,
+    pub max_delay_ms: u32,
+    pub multiplier: f32,
+    pub jitter_fraction: f32,
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay_ms: 500,
+            max_delay_ms: 30_000,
+            multiplier: 2.0,
+            jitter_fraction: 0.1,
+        }
+    }
+}
+
+impl ReconnectBackoff {
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let delay = (self.initial_delay_ms as f64 * (self.multiplier as f64).powi(attempt as i32))
+            .min(self.max_delay_ms as f64);
+
+        let jitter = rand::thread_rng().gen_range(-1.0..=1.0);
+
+        let delay = delay * (1.0 + jitter * self.jitter_fraction as f64);
+
+        Duration::from_millis(delay.max(0.0) as u64)
+    }
+}
+
+/// Repeats failed connection requests, waiting longer after each failure
+pub async fn post_with_backoff<F, Fut>(
+    backoff: &RwLock<ReconnectBackoff>,
+    request: F,
+) -> Result<String>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<String>>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match request().await {
+            Ok(response) => return Ok(response),
+            Err(err) if !is_retryable(&err) || attempt + 1 >= MAX_RECONNECT_ATTEMPTS => {
+                return Err(err)
+            },
+            Err(_) => {
+                let delay = backoff.read().unwrap().delay(attempt);
+
+                tokio::time::sleep(delay).await;
+
+                attempt += 1;
+            },
+        }
+    }
+}
+
+fn is_retryable(err: &anyhow::Error) -> bool {
+    let err = err.to_string();
+
+    RETRYABLE_ERRORS.iter().any(|e| err.contains(e))
+}
+
+pub fn register_transport_backoff(transport: usize, backoff: Arc<RwLock<ReconnectBackoff>>) {
+    TRANSPORT_BACKOFFS
+        .lock()
+        .unwrap()
+        .insert(transport, backoff);
+}
+
+pub fn remove_transport_backoff(transport: usize) {
+    TRANSPORT_BACKOFFS.lock().unwrap().remove(&transport);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_transport_reconnect_backoff(transport: *mut c_void) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_transport_reconnect_backoff");
+
+    let transport = transport as usize;
+
+    fn internal_fn(transport: usize) -> Result<serde_json::Value, String> {
+        let backoff = *TRANSPORT_BACKOFFS
+            .lock()
+            .unwrap()
+            .get(&transport)
+            .ok_or("Transport not found")
+            .handle_error()?
+            .read()
+            .unwrap();
+
+        serde_json::to_value(&backoff).handle_error()
+    }
+
+    internal_fn(transport).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_set_transport_reconnect_backoff(
+    transport: *mut c_void,
+    initial_delay_ms: c_uint,
+    max_delay_ms: c_uint,
+    multiplier: c_float,
+    jitter_fraction: c_float,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_set_transport_reconnect_backoff");
+
+    let transport = transport as usize;
+
+    fn internal_fn(
+        transport: usize,
+        backoff: ReconnectBackoff,
+    ) -> Result<serde_json::Value, String> {
+        if backoff.initial_delay_ms > backoff.max_delay_ms {
+            return Err("Initial delay exceeds max delay").handle_error();
+        }
+
+        if backoff.multiplier.is_nan() || backoff.multiplier < 1.0 {
+            return Err("Multiplier must be at least 1").handle_error();
+        }
+
+        if !(0.0..=1.0).contains(&backoff.jitter_fraction) {
+            return Err("Jitter fraction must be between 0 and 1").handle_error();
+        }
+
+        *TRANSPORT_BACKOFFS
+            .lock()
+            .unwrap()
+            .get(&transport)
+            .ok_or("Transport not found")
+            .handle_error()?
+            .write()
+            .unwrap() = backoff;
+
+        Ok(serde_json::Value::Null)
+    }
+
+    internal_fn(
+        transport,
+        ReconnectBackoff {
+            initial_delay_ms,
+            max_delay_ms,
+            multiplier,
+            jitter_fraction,
+        },
+    )
+    .match_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use anyhow::anyhow;
+
+    use super::*;
+
+    fn instant_backoff() -> RwLock<ReconnectBackoff> {
+        RwLock::new(ReconnectBackoff {
+            initial_delay_ms: 0,
+            max_delay_ms: 0,
+            ..Default::default()
+        })
+    }
+
+    async fn count_attempts(error: &str) -> u32 {
+        let attempts = AtomicU32::new(0);
+
+        let result = post_with_backoff(&instant_backoff(), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(anyhow!(error.to_owned()))
+        })
+        .await;
+
+        assert!(result.is_err());
+
+        attempts.load(Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn retries_transport_errors() {
+        let attempts = count_attempts("SocketException: Connection refused").await;
+        assert_eq!(attempts, MAX_RECONNECT_ATTEMPTS);
+
+        let attempts = count_attempts("TimeoutException after 0:00:30.000000").await;
+        assert_eq!(attempts, MAX_RECONNECT_ATTEMPTS);
+    }
+
+    #[tokio::test]
+    async fn returns_other_errors_immediately() {
+        let attempts = count_attempts("Exception: Invalid request").await;
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn delay_stays_within_jitter_and_limit() {
+        let backoff = ReconnectBackoff::default();
+
+        for attempt in 0..10 {
+            let base = (500.0 * 2f64.powi(attempt as i32)).min(30_000.0);
+            let delay = backoff.delay(attempt).as_millis() as f64;
+
+            assert!(delay >= base * 0.9 - 1.0 && delay <= base * 1.1 + 1.0);
+        }
+    }
+}
@@ -10,7 +10,7 @@ use nekoton::external::Storage;
 use serde::Serialize;
 use tokio::sync::oneshot::{channel, Sender};
 
-use crate::{HandleError, MatchResult};
+use crate::{ffi_timer, HandleError, MatchResult};
 
 pub struct StorageImpl {
     get_port: Isolate,
@@ -157,6 +157,8 @@ pub unsafe extern "C" fn nt_storage_create(
     remove_port: c_longlong,
     remove_unchecked_port: c_longlong,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_storage_create");
+
     fn internal_fn(
         get_port: i64,
         set_port: i64,
@@ -0,0 +1,100 @@
+use std::{
+    collections::HashMap,
+    os::raw::c_char,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use lazy_static::lazy_static;
+use sha2::{Digest, Sha256};
+
+use crate::{ffi_timer, helpers::abi::models::CacheStats, HandleError, MatchResult};
+
+const ABI_CACHE_CAPACITY: usize = 64;
+
+static ABI_CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+static ABI_CACHE_MISSES: AtomicU64 = AtomicU64::new(0);
+
+lazy_static! {
+    static ref ABI_CACHE: Mutex<AbiCache> = Mutex::new(AbiCache::default());
+}
+
+/// Parsed ABIs keyed by SHA-256 of their JSON, least recently used entry is evicted when full
+#[derive(Default)]
+struct AbiCache {
+    entries: HashMap<[u8; 32], (Arc<ton_abi::Contract>, u64)>,
+    counter: u64,
+}
+
+impl AbiCache {
+    fn get(&mut self, key: &[u8; 32]) -> Option<Arc<ton_abi::Contract>> {
+        self.counter += 1;
+
+        let (contract, last_used) = self.entries.get_mut(key)?;
+        *last_used = self.counter;
+
+        Some(contract.clone())
+    }
+
+    fn insert(&mut self, key: [u8; 32], contract: Arc<ton_abi::Contract>) {
+        if self.entries.len() >= ABI_CACHE_CAPACITY && !self.entries.contains_key(&key) {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| *key);
+
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        self.counter += 1;
+        self.entries.insert(key, (contract, self.counter));
+    }
+}
+
+pub fn load_contract_abi(contract_abi: &str) -> Result<Arc<ton_abi::Contract>, String> {
+    let mut key = [0; 32];
+    key.copy_from_slice(&Sha256::digest(contract_abi.as_bytes()));
+
+    if let Some(contract) = ABI_CACHE.lock().unwrap().get(&key) {
+        ABI_CACHE_HITS.fetch_add(1, Ordering::Relaxed);
+        return Ok(contract);
+    }
+
+    ABI_CACHE_MISSES.fetch_add(1, Ordering::Relaxed);
+
+    // Parsing happens outside of the lock so that concurrent calls with other ABIs are not blocked
+    let contract = Arc::new(ton_abi::Contract::load(contract_abi).handle_error()?);
+
+    ABI_CACHE.lock().unwrap().insert(key, contract.clone());
+
+    Ok(contract)
+}
+
+pub fn stats() -> CacheStats {
+    CacheStats {
+        entries: ABI_CACHE.lock().unwrap().entries.len(),
+        hits: ABI_CACHE_HITS.load(Ordering::Relaxed),
+        misses: ABI_CACHE_MISSES.load(Ordering::Relaxed),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_clear_abi_cache() -> *mut c_char {
+    let _timer = ffi_timer!("nt_clear_abi_cache");
+
+    fn internal_fn() -> Result<serde_json::Value, String> {
+        let mut cache = ABI_CACHE.lock().unwrap();
+
+        cache.entries.clear();
+        cache.entries.shrink_to_fit();
+
+        Ok(serde_json::Value::Null)
+    }
+
+    internal_fn().match_result()
+}
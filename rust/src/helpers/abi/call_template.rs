@@ -0,0 +1,152 @@
+use std::os::raw::{c_char, c_void};
+
+use ton_abi::{Function, Param, Token};
+
+use crate::{
+    ffi_timer, helpers::abi::parse_contract_abi, HandleError, MatchResult, ToStringFromPtr,
+};
+
+pub struct CallTemplate {
+    function: Function,
+    fixed_tokens: Vec<Token>,
+    variable_params: Vec<Param>,
+}
+
+impl CallTemplate {
+    fn new(function: Function, fixed_input: serde_json::Value) -> Result<Self, String> {
+        let fixed_names = input_names(&fixed_input)?;
+
+        if let Some(name) = fixed_names
+            .iter()
+            .find(|name| !function.inputs.iter().any(|e| &e.name == *name))
+        {
+            return Err(format!("Unknown input field: {}", name));
+        }
+
+        let (fixed_params, variable_params) = function
+            .inputs
+            .iter()
+            .cloned()
+            .partition::<Vec<_>, _>(|e| fixed_names.contains(&e.name));
+
+        let fixed_tokens =
+            nekoton_abi::parse_abi_tokens(&fixed_params, fixed_input).handle_error()?;
+
+        Ok(Self {
+            function,
+            fixed_tokens,
+            variable_params,
+        })
+    }
+
+    fn encode(&self, variable_input: serde_json::Value) -> Result<ton_types::Cell, String> {
+        let variable_names = input_names(&variable_input)?;
+
+        for name in &variable_names {
+            if self.fixed_tokens.iter().any(|e| &e.name == name) {
+                return Err(format!("Input field is fixed by the template: {}", name));
+            }
+
+            if !self.variable_params.iter().any(|e| &e.name == name) {
+                return Err(format!("Unknown input field: {}", name));
+            }
+        }
+
+        let variable_tokens =
+            nekoton_abi::parse_abi_tokens(&self.variable_params, variable_input).handle_error()?;
+
+        // Tokens must follow the order of the function inputs
+        let tokens = self
+            .function
+            .inputs
+            .iter()
+            .filter_map(|param| {
+                self.fixed_tokens
+                    .iter()
+                    .chain(variable_tokens.iter())
+                    .find(|e| e.name == param.name)
+                    .cloned()
+            })
+            .collect::<Vec<_>>();
+
+        self.function
+            .encode_input(&Default::default(), &tokens, true, None)
+            .and_then(|e| e.into_cell())
+            .handle_error()
+    }
+}
+
+fn input_names(input: &serde_json::Value) -> Result<Vec<String>, String> {
+    match input {
+        serde_json::Value::Object(fields) => Ok(fields.keys().cloned().collect()),
+        _ => Err("Input must be an object").handle_error(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_prepare_call_template(
+    contract_abi: *mut c_char,
+    method: *mut c_char,
+    fixed_input: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_prepare_call_template");
+
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let method = method.to_string_from_ptr();
+    let fixed_input = fixed_input.to_string_from_ptr();
+
+    fn internal_fn(
+        contract_abi: String,
+        method: String,
+        fixed_input: String,
+    ) -> Result<serde_json::Value, String> {
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let function = contract_abi.function(&method).handle_error()?.to_owned();
+
+        let fixed_input = serde_json::from_str::<serde_json::Value>(&fixed_input).handle_error()?;
+
+        let call_template = CallTemplate::new(function, fixed_input)?;
+
+        let ptr = Box::into_raw(Box::new(call_template));
+
+        serde_json::to_value(ptr as usize).handle_error()
+    }
+
+    internal_fn(contract_abi, method, fixed_input).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_encode_from_template(
+    call_template: *mut c_void,
+    variable_input: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_encode_from_template");
+
+    let call_template = &*(call_template as *mut CallTemplate);
+
+    let variable_input = variable_input.to_string_from_ptr();
+
+    fn internal_fn(
+        call_template: &CallTemplate,
+        variable_input: String,
+    ) -> Result<serde_json::Value, String> {
+        let variable_input =
+            serde_json::from_str::<serde_json::Value>(&variable_input).handle_error()?;
+
+        let body = call_template.encode(variable_input)?;
+
+        let body = ton_types::serialize_toc(&body).handle_error()?;
+
+        let body = base64::encode(&body);
+
+        serde_json::to_value(body).handle_error()
+    }
+
+    internal_fn(call_template, variable_input).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_call_template_free_ptr(ptr: *mut c_void) {
+    println!("nt_call_template_free_ptr");
+    Box::from_raw(ptr as *mut CallTemplate);
+}
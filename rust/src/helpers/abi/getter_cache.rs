@@ -0,0 +1,243 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
+use sha2::{Digest, Sha256};
+use ton_block::{AccountStuff, MsgAddressInt, Serializable};
+
+use crate::helpers::abi::models::CacheStats;
+
+const GETTER_CACHE_CAPACITY: usize = 256;
+const GETTER_CACHE_TTL: Duration = Duration::from_secs(60);
+
+static GETTER_CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+static GETTER_CACHE_MISSES: AtomicU64 = AtomicU64::new(0);
+
+lazy_static! {
+    static ref GETTER_CACHE: Mutex<HashMap<[u8; 32], CachedGetterOutput>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Getter result without execution context, which is rebuilt for every call
+#[derive(Clone)]
+pub struct GetterOutput {
+    pub tokens: Option<serde_json::Value>,
+    pub code: i32,
+}
+
+struct CachedGetterOutput {
+    address: MsgAddressInt,
+    output: GetterOutput,
+    created_at: Instant,
+}
+
+/// Builds a key from everything a getter can observe except the current time, so only getters
+/// not depending on it may be cached. `None` for inactive accounts
+pub fn make_key(
+    account_stuff: &AccountStuff,
+    function_id: u32,
+    responsible: bool,
+    input: &str,
+) -> Option<[u8; 32]> {
+    let state_init = match &account_stuff.storage.state {
+        ton_block::AccountState::AccountActive { state_init } => state_init,
+        _ => return None,
+    };
+
+    let balance = account_stuff.storage.balance.serialize().ok()?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(state_init.code.as_ref()?.repr_hash().as_slice());
+    hasher.update(state_init.data.as_ref()?.repr_hash().as_slice());
+    hasher.update(balance.repr_hash().as_slice());
+    hasher.update(account_stuff.storage.last_trans_lt.to_be_bytes());
+    hasher.update(function_id.to_be_bytes());
+    hasher.update([responsible as u8]);
+    hasher.update(input.as_bytes());
+
+    let mut key = [0; 32];
+    key.copy_from_slice(&hasher.finalize());
+
+    Some(key)
+}
+
+pub fn get(key: &[u8; 32]) -> Option<GetterOutput> {
+    let mut cache = GETTER_CACHE.lock().unwrap();
+
+    let output = match cache.get(key) {
+        Some(entry) if is_fresh(entry.created_at) => Some(entry.output.clone()),
+        Some(_) => {
+            cache.remove(key);
+            None
+        },
+        None => None,
+    };
+
+    match output.is_some() {
+        true => GETTER_CACHE_HITS.fetch_add(1, Ordering::Relaxed),
+        false => GETTER_CACHE_MISSES.fetch_add(1, Ordering::Relaxed),
+    };
+
+    output
+}
+
+fn is_fresh(created_at: Instant) -> bool {
+    created_at.elapsed() < GETTER_CACHE_TTL
+}
+
+pub fn insert(key: [u8; 32], address: MsgAddressInt, output: GetterOutput) {
+    insert_created_at(key, address, output, Instant::now())
+}
+
+fn insert_created_at(
+    key: [u8; 32],
+    address: MsgAddressInt,
+    output: GetterOutput,
+    created_at: Instant,
+) {
+    let mut cache = GETTER_CACHE.lock().unwrap();
+
+    if cache.len() >= GETTER_CACHE_CAPACITY {
+        cache.retain(|_, entry| is_fresh(entry.created_at));
+    }
+
+    if cache.len() >= GETTER_CACHE_CAPACITY {
+        let oldest = cache
+            .iter()
+            .min_by_key(|(_, entry)| entry.created_at)
+            .map(|(key, _)| *key);
+
+        if let Some(oldest) = oldest {
+            cache.remove(&oldest);
+        }
+    }
+
+    cache.insert(
+        key,
+        CachedGetterOutput {
+            address,
+            output,
+            created_at,
+        },
+    );
+}
+
+/// Drops all results of the account, its balance or state may have changed
+pub fn invalidate_address(address: &MsgAddressInt) {
+    GETTER_CACHE
+        .lock()
+        .unwrap()
+        .retain(|_, entry| &entry.address != address);
+}
+
+pub fn stats() -> CacheStats {
+    CacheStats {
+        entries: GETTER_CACHE.lock().unwrap().len(),
+        hits: GETTER_CACHE_HITS.load(Ordering::Relaxed),
+        misses: GETTER_CACHE_MISSES.load(Ordering::Relaxed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn active_account() -> AccountStuff {
+        let mut account_stuff = AccountStuff::default();
+
+        account_stuff.storage.state = ton_block::AccountState::AccountActive {
+            state_init: ton_block::StateInit {
+                code: Some(ton_types::Cell::default()),
+                data: Some(ton_types::Cell::default()),
+                ..Default::default()
+            },
+        };
+
+        account_stuff
+    }
+
+    #[test]
+    fn key_depends_on_balance_and_last_transaction() {
+        let account_stuff = active_account();
+        let key = make_key(&account_stuff, 1, false, "{}").unwrap();
+
+        assert_eq!(make_key(&account_stuff, 1, false, "{}"), Some(key));
+
+        let mut with_balance = active_account();
+        with_balance.storage.balance = ton_block::CurrencyCollection::with_grams(1);
+        assert_ne!(make_key(&with_balance, 1, false, "{}"), Some(key));
+
+        let mut with_transaction = active_account();
+        with_transaction.storage.last_trans_lt = 1;
+        assert_ne!(make_key(&with_transaction, 1, false, "{}"), Some(key));
+
+        assert_ne!(make_key(&account_stuff, 2, false, "{}"), Some(key));
+        assert_ne!(make_key(&account_stuff, 1, true, "{}"), Some(key));
+    }
+
+    #[test]
+    fn inactive_accounts_are_not_cached() {
+        assert!(make_key(&AccountStuff::default(), 1, false, "{}").is_none());
+    }
+
+    // The cache is shared by all tests, each one uses its own keys and addresses
+
+    fn address(byte: u8) -> MsgAddressInt {
+        MsgAddressInt::from_str(&format!("0:{}", hex::encode([byte; 32]))).unwrap()
+    }
+
+    fn output(code: i32) -> GetterOutput {
+        GetterOutput {
+            tokens: Some(serde_json::json!({ "value": code.to_string() })),
+            code,
+        }
+    }
+
+    #[test]
+    fn inserted_output_is_a_hit() {
+        let key = [1; 32];
+        insert(key, address(1), output(7));
+
+        let hits = stats().hits;
+        let cached = get(&key).unwrap();
+
+        assert_eq!(cached.code, 7);
+        assert_eq!(cached.tokens, output(7).tokens);
+        assert!(stats().hits > hits);
+    }
+
+    #[test]
+    fn invalidated_address_is_a_miss() {
+        let (key, other_key) = ([2; 32], [3; 32]);
+        insert(key, address(2), output(0));
+        insert(other_key, address(3), output(0));
+
+        invalidate_address(&address(2));
+
+        let misses = stats().misses;
+        assert!(get(&key).is_none());
+        assert!(stats().misses > misses);
+
+        assert!(get(&other_key).is_some());
+    }
+
+    #[test]
+    fn expired_output_is_a_miss() {
+        let key = [4; 32];
+        let created_at = Instant::now()
+            .checked_sub(GETTER_CACHE_TTL + Duration::from_secs(1))
+            .unwrap();
+        insert_created_at(key, address(4), output(0), created_at);
+
+        assert!(get(&key).is_none());
+        assert!(!GETTER_CACHE.lock().unwrap().contains_key(&key));
+    }
+}
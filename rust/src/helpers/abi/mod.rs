@@ -1,41 +1,66 @@
-mod models;
+mod abi_cache;
+mod call_template;
+pub(crate) mod getter_cache;
+pub(crate) mod models;
+pub(crate) mod tvm;
+mod validation;
+mod value_types;
 
 use std::{
     borrow::Cow,
     collections::HashMap,
-    os::raw::{c_char, c_schar, c_uint},
+    os::raw::{c_char, c_longlong, c_schar, c_uint, c_ulonglong},
     str::FromStr,
     sync::Arc,
     time::{SystemTime, UNIX_EPOCH},
     u64,
 };
 
+use allo_isolate::Isolate;
 use nekoton::{
     core::{
-        models::{Expiration, ExpireAt, Transaction},
+        models::{AccountStatus, Expiration, ExpireAt, Transaction},
         parsing::parse_payload,
         utils::make_labs_unsigned_message,
     },
-    crypto::SignedMessage,
+    crypto::{SignedMessage, UnsignedMessage},
 };
-use nekoton_abi::{get_state_init_hash, guess_method_by_input, FunctionExt, MethodName};
-use ton_block::{Deserializable, MsgAddressInt};
+use nekoton_abi::{
+    guess_method_by_input, insert_state_init_data, num_bigint::BigUint, FunctionExt, MethodName,
+};
+use nekoton_utils::{Clock, ConstClock};
+use ton_block::{Deserializable, MsgAddressInt, Serializable};
+use ton_executor::BlockchainConfig;
 
 use crate::{
-    clock,
+    clock, ffi_timer,
     helpers::{
-        abi::models::{
-            AbiParam, DecodedEvent, DecodedInput, DecodedOutput, DecodedTransaction,
-            DecodedTransactionEvent, ExecutionOutput,
+        abi::{
+            getter_cache::GetterOutput,
+            models::{
+                AbiParam, BridgeBurnPayload, CacheStatsOutput, DecodedCall, DecodedEvent,
+                DecodedInput, DecodedMessageBody, DecodedOutMessage, DecodedOutMessageBody,
+                DecodedOutput, DecodedTransaction, DecodedTransactionEvent,
+                DecodedTransactionEventError, DecodedTransactionEventResult,
+                DecodedTransactionWithState, EncodedExternalInput, ExecutionContext,
+                ExecutionOutput, ExpectedAddress, ExtraKnownPayload, FunctionIds, LocalCall,
+                LocalCallResult, MatchedBody, MultisigConfirmation, MultisigSubmission,
+                PublicKeyRejection, PublicKeyValidation, RawDecodedInput,
+                ResponsibleExecutionOutput, SafeMultisigTransaction, SignPayload, SignatureLayout,
+                TransactionDirection, UnmatchedBody, UnpackedCell, UnsignedExternalMessage,
+            },
         },
+        camel_case::to_camel_case_value,
         parse_account_stuff,
     },
-    parse_address, parse_public_key, HandleError, MatchResult, ToOptionalStringFromPtr,
-    ToStringFromPtr, CLOCK,
+    parse_address, parse_public_key, spawn_ffi_task, HandleError, MatchResult, PostWithResult,
+    ToOptionalStringFromPtr, ToStringFromPtr, CLOCK,
 };
 
 #[no_mangle]
 pub unsafe extern "C" fn nt_check_public_key(public_key: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_check_public_key");
+
     let public_key = public_key.to_string_from_ptr();
 
     fn internal_fn(public_key: String) -> Result<serde_json::Value, String> {
@@ -47,6 +72,35 @@ pub unsafe extern "C" fn nt_check_public_key(public_key: *mut c_char) -> *mut c_
     internal_fn(public_key).match_result()
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_validate_public_key(public_key: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_validate_public_key");
+
+    let public_key = public_key.to_string_from_ptr();
+
+    fn internal_fn(public_key: String) -> Result<serde_json::Value, String> {
+        let reason = match hex::decode(public_key.trim()) {
+            Err(_) => Some(PublicKeyRejection::NotHex),
+            Ok(bytes) if bytes.len() != ed25519_dalek::PUBLIC_KEY_LENGTH => {
+                Some(PublicKeyRejection::WrongLength)
+            },
+            // With the length checked, decompression of the curve point is the only failure left
+            Ok(bytes) => ed25519_dalek::PublicKey::from_bytes(&bytes)
+                .err()
+                .map(|_| PublicKeyRejection::InvalidCurvePoint),
+        };
+
+        let validation = PublicKeyValidation {
+            valid: reason.is_none(),
+            reason,
+        };
+
+        serde_json::to_value(&validation).handle_error()
+    }
+
+    internal_fn(public_key).match_result()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_run_local(
     account_stuff_boc: *mut c_char,
@@ -54,12 +108,24 @@ pub unsafe extern "C" fn nt_run_local(
     method: *mut c_char,
     input: *mut c_char,
     responsible: c_uint,
+    libraries_json: *mut c_char,
+    blockchain_config_boc: *mut c_char,
+    timestamp: c_uint,
+    lt: c_ulonglong,
+    no_cache: c_uint,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_run_local");
+
     let account_stuff_boc = account_stuff_boc.to_string_from_ptr();
     let contract_abi = contract_abi.to_string_from_ptr();
     let method = method.to_string_from_ptr();
     let input = input.to_string_from_ptr();
     let responsible = responsible != 0;
+    let libraries_json = libraries_json.to_optional_string_from_ptr();
+    let blockchain_config_boc = blockchain_config_boc.to_optional_string_from_ptr();
+    let timestamp = (timestamp != 0).then_some(timestamp);
+    let lt = (lt != 0).then_some(lt);
+    let no_cache = no_cache != 0;
 
     fn internal_fn(
         account_stuff_boc: String,
@@ -67,38 +133,484 @@ pub unsafe extern "C" fn nt_run_local(
         method: String,
         input: String,
         responsible: bool,
+        libraries_json: Option<String>,
+        blockchain_config_boc: Option<String>,
+        timestamp: Option<u32>,
+        lt: Option<u64>,
+        no_cache: bool,
     ) -> Result<serde_json::Value, String> {
         let account_stuff = parse_account_stuff(&account_stuff_boc)?;
         let contract_abi = parse_contract_abi(&contract_abi)?;
         let method = contract_abi.function(&method).handle_error()?;
 
+        let libraries = libraries_json
+            .as_deref()
+            .map(parse_libraries_map)
+            .transpose()?
+            .unwrap_or_default();
+
+        let config = blockchain_config_boc
+            .as_deref()
+            .map(parse_blockchain_config)
+            .transpose()?;
+
+        // The key doesn't cover the current time, getters depending on it have to pass `no_cache`.
+        // Overridden environment is never cached
+        let cacheable = !no_cache && timestamp.is_none() && lt.is_none() && libraries.is_empty();
+
+        let cache_key = match cacheable {
+            true => getter_cache::make_key(&account_stuff, method.input_id, responsible, &input),
+            false => None,
+        };
+
         let input = serde_json::from_str::<serde_json::Value>(&input).handle_error()?;
-        let input = nekoton_abi::parse_abi_tokens(&method.inputs, input).handle_error()?;
+        let input = parse_abi_tokens(&method.inputs, input)?;
+
+        let clock = match timestamp {
+            Some(timestamp) => ConstClock::from_millis(timestamp as u64 * 1000),
+            None => ConstClock::from_millis(clock!().now_ms_u64()),
+        };
+
+        let block_lt = lt.unwrap_or_else(|| tvm::local_execution_lt(&account_stuff));
+
+        let context = ExecutionContext {
+            now: clock.now_sec_u64() as u32,
+            block_lt: block_lt.to_string(),
+        };
+
+        // Library cells and lt overrides are only supported by the local VM, which charges gas by
+        // the network config
+        if config.is_some() || !libraries.is_empty() || lt.is_some() {
+            let config = config
+                .ok_or("Blockchain config is required to run with libraries or an lt override")
+                .handle_error()?;
+
+            let output = tvm::run_local(
+                method,
+                &account_stuff,
+                &input,
+                responsible,
+                context.now,
+                block_lt,
+                &config,
+                &libraries,
+            )?;
+
+            let tokens = make_output_tokens(method, output.exit_code, output.tokens)?;
+
+            let execution_output = ExecutionOutput {
+                output: tokens,
+                code: output.exit_code,
+                gas_used: Some(output.gas_used),
+                context,
+            };
+
+            return serde_json::to_value(&execution_output).handle_error();
+        }
+
+        if let Some(output) = cache_key.as_ref().and_then(getter_cache::get) {
+            let execution_output = ExecutionOutput {
+                output: output.tokens,
+                code: output.code,
+                gas_used: None,
+                context,
+            };
+
+            return serde_json::to_value(&execution_output).handle_error();
+        }
+
+        let address = account_stuff.addr.clone();
 
         let output = if responsible {
             method
-                .run_local_responsible(clock!().as_ref(), account_stuff, &input)
+                .run_local_responsible(&clock, account_stuff, &input)
                 .handle_error()?
         } else {
             method
-                .run_local(clock!().as_ref(), account_stuff, &input)
+                .run_local(&clock, account_stuff, &input)
                 .handle_error()?
         };
 
-        let tokens = output
-            .tokens
-            .map(|e| nekoton_abi::make_abi_tokens(&e).handle_error())
-            .transpose()?;
+        let tokens = make_output_tokens(method, output.result_code, output.tokens)?;
+
+        if let Some(cache_key) = cache_key {
+            let output = GetterOutput {
+                tokens: tokens.clone(),
+                code: output.result_code,
+            };
+
+            getter_cache::insert(cache_key, address, output);
+        }
 
         let execution_output = ExecutionOutput {
             output: tokens,
             code: output.result_code,
+            gas_used: None,
+            context,
+        };
+
+        serde_json::to_value(&execution_output).handle_error()
+    }
+
+    internal_fn(
+        account_stuff_boc,
+        contract_abi,
+        method,
+        input,
+        responsible,
+        libraries_json,
+        blockchain_config_boc,
+        timestamp,
+        lt,
+        no_cache,
+    )
+    .match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_cache_stats() -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_cache_stats");
+
+    fn internal_fn() -> Result<serde_json::Value, String> {
+        let cache_stats = CacheStatsOutput {
+            abi: abi_cache::stats(),
+            getters: getter_cache::stats(),
+        };
+
+        serde_json::to_value(&cache_stats).handle_error()
+    }
+
+    internal_fn().match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_run_local_responsible_value(
+    account_stuff_boc: *mut c_char,
+    contract_abi: *mut c_char,
+    method: *mut c_char,
+    input: *mut c_char,
+    blockchain_config_boc: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_run_local_responsible_value");
+
+    let account_stuff_boc = account_stuff_boc.to_string_from_ptr();
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let method = method.to_string_from_ptr();
+    let input = input.to_string_from_ptr();
+    let blockchain_config_boc = blockchain_config_boc.to_optional_string_from_ptr();
+
+    fn internal_fn(
+        account_stuff_boc: String,
+        contract_abi: String,
+        method: String,
+        input: String,
+        blockchain_config_boc: Option<String>,
+    ) -> Result<serde_json::Value, String> {
+        let account_stuff = parse_account_stuff(&account_stuff_boc)?;
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let method = contract_abi.function(&method).handle_error()?;
+
+        // Without the network config gas is charged by the default one
+        let config = blockchain_config_boc
+            .as_deref()
+            .map(parse_blockchain_config)
+            .transpose()?
+            .unwrap_or_default();
+
+        let input = serde_json::from_str::<serde_json::Value>(&input).handle_error()?;
+        let input = parse_abi_tokens(&method.inputs, input)?;
+
+        let utime = clock!().now_sec_u64() as u32;
+        let block_lt = tvm::local_execution_lt(&account_stuff);
+
+        let context = ExecutionContext {
+            now: utime,
+            block_lt: block_lt.to_string(),
+        };
+
+        // Answer message is only available from the local VM, nekoton returns just its tokens
+        let output = tvm::run_local(
+            method,
+            &account_stuff,
+            &input,
+            true,
+            utime,
+            block_lt,
+            &config,
+            &[],
+        )?;
+
+        let tokens = make_output_tokens(method, output.exit_code, output.tokens)?;
+
+        let responsible_output = ResponsibleExecutionOutput {
+            execution_output: ExecutionOutput {
+                output: tokens,
+                code: output.exit_code,
+                gas_used: Some(output.gas_used),
+                context,
+            },
+            responsible_value: output.responsible_value,
+        };
+
+        serde_json::to_value(&responsible_output).handle_error()
+    }
+
+    internal_fn(
+        account_stuff_boc,
+        contract_abi,
+        method,
+        input,
+        blockchain_config_boc,
+    )
+    .match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_execute_transaction_locally(
+    account_stuff_boc: *mut c_char,
+    message_boc: *mut c_char,
+    config_boc: *mut c_char,
+    utime: c_uint,
+    lt: c_ulonglong,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_execute_transaction_locally");
+
+    let account_stuff_boc = account_stuff_boc.to_optional_string_from_ptr();
+    let message_boc = message_boc.to_string_from_ptr();
+    let config_boc = config_boc.to_string_from_ptr();
+
+    fn internal_fn(
+        account_stuff_boc: Option<String>,
+        message_boc: String,
+        config_boc: String,
+        utime: u32,
+        lt: u64,
+    ) -> Result<serde_json::Value, String> {
+        let account = match account_stuff_boc {
+            Some(account_stuff_boc) => {
+                ton_block::Account::Account(parse_account_stuff(&account_stuff_boc)?)
+            },
+            None => ton_block::Account::AccountNone,
+        };
+
+        let message = parse_boc::<ton_block::Message>(&message_boc)?;
+        let config = parse_blockchain_config(&config_boc)?;
+
+        let utime = match utime {
+            0 => clock!().now_sec_u64() as u32,
+            utime => utime,
+        };
+
+        let lt = match (lt, &account) {
+            (0, ton_block::Account::Account(account_stuff)) => {
+                tvm::local_execution_lt(account_stuff)
+            },
+            (lt, _) => lt,
+        };
+
+        let execution = tvm::execute_transaction(account, &message, config, utime, lt)?;
+
+        serde_json::to_value(&execution).handle_error()
+    }
+
+    internal_fn(account_stuff_boc, message_boc, config_boc, utime, lt).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_simulate_transaction(
+    account_stuff_boc: *mut c_char,
+    message_boc: *mut c_char,
+    config_boc: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_simulate_transaction");
+
+    let account_stuff_boc = account_stuff_boc.to_string_from_ptr();
+    let message_boc = message_boc.to_string_from_ptr();
+    let config_boc = config_boc.to_optional_string_from_ptr();
+
+    fn internal_fn(
+        account_stuff_boc: String,
+        message_boc: String,
+        config_boc: Option<String>,
+    ) -> Result<serde_json::Value, String> {
+        let account_stuff = parse_account_stuff(&account_stuff_boc)?;
+        let message = parse_boc::<ton_block::Message>(&message_boc)?;
+
+        let config = match config_boc {
+            Some(config_boc) => parse_blockchain_config(&config_boc)?,
+            None => BlockchainConfig::default(),
+        };
+
+        let utime = clock!().now_sec_u64() as u32;
+
+        let simulated_transaction =
+            tvm::simulate_transaction(account_stuff, &message, config, utime)?;
+
+        serde_json::to_value(&simulated_transaction).handle_error()
+    }
+
+    internal_fn(account_stuff_boc, message_boc, config_boc).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_batch_run_local(
+    result_port: c_longlong,
+    account_stuff_boc: *mut c_char,
+    contract_abi: *mut c_char,
+    calls: *mut c_char,
+) {
+    let account_stuff_boc = account_stuff_boc.to_string_from_ptr();
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let calls = calls.to_string_from_ptr();
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_batch_run_local");
+
+        fn internal_fn(
+            account_stuff_boc: String,
+            contract_abi: String,
+            calls: String,
+        ) -> Result<serde_json::Value, String> {
+            let account_stuff = parse_account_stuff(&account_stuff_boc)?;
+            let contract_abi = parse_contract_abi(&contract_abi)?;
+            let calls = serde_json::from_str::<Vec<LocalCall>>(&calls).handle_error()?;
+
+            // Malformed calls fail the whole batch before anything gets executed
+            let calls = calls
+                .into_iter()
+                .map(|call| {
+                    let method = contract_abi.function(&call.method).handle_error()?;
+                    let input = parse_abi_tokens(&method.inputs, call.input)?;
+                    Ok((method, input, call.responsible))
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+
+            let clock = ConstClock::from_millis(clock!().now_ms_u64());
+
+            let context = || ExecutionContext {
+                now: clock.now_sec_u64() as u32,
+                block_lt: tvm::local_execution_lt(&account_stuff).to_string(),
+            };
+
+            let results = calls
+                .into_iter()
+                .map(|(method, input, responsible)| {
+                    let output = if responsible {
+                        method.run_local_responsible(&clock, account_stuff.clone(), &input)
+                    } else {
+                        method.run_local(&clock, account_stuff.clone(), &input)
+                    };
+
+                    let output = output.handle_error().and_then(|output| {
+                        let tokens = make_output_tokens(method, output.result_code, output.tokens)?;
+
+                        Ok(ExecutionOutput {
+                            output: tokens,
+                            code: output.result_code,
+                            gas_used: None,
+                            context: context(),
+                        })
+                    });
+
+                    match output {
+                        Ok(output) => LocalCallResult::Output(output),
+                        Err(error) => LocalCallResult::Error { error },
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            serde_json::to_value(&results).handle_error()
+        }
+
+        let result = internal_fn(account_stuff_boc, contract_abi, calls).match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_run_local_with_config(
+    account_stuff_boc: *mut c_char,
+    contract_abi: *mut c_char,
+    method: *mut c_char,
+    input: *mut c_char,
+    responsible: c_uint,
+    blockchain_config_boc: *mut c_char,
+    libraries: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_run_local_with_config");
+
+    let account_stuff_boc = account_stuff_boc.to_string_from_ptr();
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let method = method.to_string_from_ptr();
+    let input = input.to_string_from_ptr();
+    let responsible = responsible != 0;
+    let blockchain_config_boc = blockchain_config_boc.to_string_from_ptr();
+    let libraries = libraries.to_optional_string_from_ptr();
+
+    fn internal_fn(
+        account_stuff_boc: String,
+        contract_abi: String,
+        method: String,
+        input: String,
+        responsible: bool,
+        blockchain_config_boc: String,
+        libraries: Option<String>,
+    ) -> Result<serde_json::Value, String> {
+        let account_stuff = parse_account_stuff(&account_stuff_boc)?;
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let method = contract_abi.function(&method).handle_error()?;
+        let config = parse_blockchain_config(&blockchain_config_boc)?;
+
+        let libraries = libraries
+            .as_deref()
+            .map(parse_libraries_map)
+            .transpose()?
+            .unwrap_or_default();
+
+        let input = serde_json::from_str::<serde_json::Value>(&input).handle_error()?;
+        let input = parse_abi_tokens(&method.inputs, input)?;
+
+        let utime = clock!().now_sec_u64() as u32;
+
+        let context = ExecutionContext {
+            now: utime,
+            block_lt: tvm::local_execution_lt(&account_stuff).to_string(),
+        };
+
+        let output = tvm::run_local(
+            method,
+            &account_stuff,
+            &input,
+            responsible,
+            utime,
+            tvm::local_execution_lt(&account_stuff),
+            &config,
+            &libraries,
+        )?;
+
+        let tokens = make_output_tokens(method, output.exit_code, output.tokens)?;
+
+        let execution_output = ExecutionOutput {
+            output: tokens,
+            code: output.exit_code,
+            gas_used: Some(output.gas_used),
+            context,
         };
 
         serde_json::to_value(&execution_output).handle_error()
     }
 
-    internal_fn(account_stuff_boc, contract_abi, method, input, responsible).match_result()
+    internal_fn(
+        account_stuff_boc,
+        contract_abi,
+        method,
+        input,
+        responsible,
+        blockchain_config_boc,
+        libraries,
+    )
+    .match_result()
 }
 
 #[no_mangle]
@@ -109,19 +621,21 @@ pub unsafe extern "C" fn nt_get_expected_address(
     public_key: *mut c_char,
     init_data: *mut c_char,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_expected_address");
+
     let tvc = tvc.to_string_from_ptr();
     let contract_abi = contract_abi.to_string_from_ptr();
     let public_key = public_key.to_optional_string_from_ptr();
-    let init_data = init_data.to_string_from_ptr();
+    let init_data = init_data.to_optional_string_from_ptr();
 
     fn internal_fn(
         tvc: String,
         contract_abi: String,
         workchain_id: i8,
         public_key: Option<String>,
-        init_data: String,
+        init_data: Option<String>,
     ) -> Result<serde_json::Value, String> {
-        let state_init = ton_block::StateInit::construct_from_base64(&tvc).handle_error()?;
+        let mut state_init = parse_boc::<ton_block::StateInit>(&tvc)?;
         let contract_abi = parse_contract_abi(&contract_abi)?;
         let public_key = public_key.as_deref().map(parse_public_key).transpose()?;
 
@@ -131,20 +645,47 @@ pub unsafe extern "C" fn nt_get_expected_address(
             .map(|(_, v)| v.value.to_owned())
             .collect::<Vec<_>>();
 
-        let init_data = serde_json::from_str::<serde_json::Value>(&init_data).handle_error()?;
-        let init_data = nekoton_abi::parse_abi_tokens(&params, init_data).handle_error()?;
+        let init_data = match init_data.as_deref().map(str::trim) {
+            Some(init_data) if !init_data.is_empty() => {
+                serde_json::from_str::<serde_json::Value>(init_data).handle_error()?
+            },
+            _ => serde_json::Value::Null,
+        };
+
+        let init_data = match init_data {
+            serde_json::Value::Null if params.is_empty() => Vec::new(),
+            init_data => parse_abi_tokens(&params, init_data)?,
+        };
 
-        let hash = get_state_init_hash(state_init, &contract_abi, &public_key, init_data)
+        state_init.data = state_init
+            .data
+            .take()
+            .map(|data| {
+                insert_state_init_data(&contract_abi, data.into(), &public_key, init_data)
+                    .map(|e| e.into_cell())
+            })
+            .transpose()
             .handle_error()?;
 
+        let state_init = state_init.serialize().handle_error()?;
+        let hash = state_init.repr_hash();
+
         let address = MsgAddressInt::AddrStd(ton_block::MsgAddrStd {
             anycast: None,
             workchain_id,
-            address: hash.into(),
+            address: hash.clone().into(),
         })
         .to_string();
 
-        serde_json::to_value(address).handle_error()
+        let state_init = ton_types::serialize_toc(&state_init).handle_error()?;
+
+        let expected_address = ExpectedAddress {
+            address,
+            state_init: base64::encode(&state_init),
+            hash: hash.to_hex_string(),
+        };
+
+        serde_json::to_value(&expected_address).handle_error()
     }
 
     internal_fn(tvc, contract_abi, workchain_id, public_key, init_data).match_result()
@@ -156,6 +697,8 @@ pub unsafe extern "C" fn nt_encode_internal_input(
     method: *mut c_char,
     input: *mut c_char,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_encode_internal_input");
+
     let contract_abi = contract_abi.to_string_from_ptr();
     let method = method.to_string_from_ptr();
     let input = input.to_string_from_ptr();
@@ -170,7 +713,7 @@ pub unsafe extern "C" fn nt_encode_internal_input(
         let method = contract_abi.function(&method).handle_error()?;
 
         let input = serde_json::from_str::<serde_json::Value>(&input).handle_error()?;
-        let input = nekoton_abi::parse_abi_tokens(&method.inputs, input).handle_error()?;
+        let input = parse_abi_tokens(&method.inputs, input)?;
 
         let body = method
             .encode_input(&Default::default(), &input, true, None)
@@ -187,87 +730,166 @@ pub unsafe extern "C" fn nt_encode_internal_input(
     internal_fn(contract_abi, method, input).match_result()
 }
 
+/// `remaining_gas_to` is not in the request signature, the transfer can't be encoded without it as
+/// TIP-3 wallets send the change there. Params other than the TIP-3 transfer ones get their
+/// default values
 #[no_mangle]
-pub unsafe extern "C" fn nt_create_external_message_without_signature(
-    dst: *mut c_char,
+pub unsafe extern "C" fn nt_encode_transfer_with_comment(
     contract_abi: *mut c_char,
-    method: *mut c_char,
-    state_init: *mut c_char,
-    input: *mut c_char,
-    timeout: c_uint,
+    recipient: *mut c_char,
+    amount: *mut c_char,
+    deploy_wallet_value: *mut c_char,
+    remaining_gas_to: *mut c_char,
+    notify: c_uint,
+    comment: *mut c_char,
 ) -> *mut c_char {
-    let dst = dst.to_string_from_ptr();
+    let _timer = ffi_timer!("nt_encode_transfer_with_comment");
+
     let contract_abi = contract_abi.to_string_from_ptr();
-    let method = method.to_string_from_ptr();
-    let state_init = state_init.to_optional_string_from_ptr();
-    let input = input.to_string_from_ptr();
+    let recipient = recipient.to_string_from_ptr();
+    let amount = amount.to_string_from_ptr();
+    let deploy_wallet_value = deploy_wallet_value.to_string_from_ptr();
+    let remaining_gas_to = remaining_gas_to.to_string_from_ptr();
+    let notify = notify != 0;
+    let comment = comment.to_string_from_ptr();
 
     fn internal_fn(
-        dst: String,
         contract_abi: String,
-        method: String,
-        state_init: Option<String>,
-        input: String,
-        timeout: u32,
+        recipient: String,
+        amount: String,
+        deploy_wallet_value: String,
+        remaining_gas_to: String,
+        notify: bool,
+        comment: String,
     ) -> Result<serde_json::Value, String> {
-        let dst = parse_address(&dst)?;
         let contract_abi = parse_contract_abi(&contract_abi)?;
-        let method = contract_abi.function(&method).handle_error()?;
+        let recipient = parse_address(&recipient)?;
+        let remaining_gas_to = parse_address(&remaining_gas_to)?;
 
-        let state_init = state_init
-            .as_deref()
-            .map(ton_block::StateInit::construct_from_base64)
-            .transpose()
+        let method = contract_abi.function("transfer").handle_error()?;
+
+        let payload = nekoton_abi::create_comment_payload(&comment)
+            .handle_error()?
+            .into_cell();
+
+        let payload = ton_types::serialize_toc(&payload)
+            .map(base64::encode)
             .handle_error()?;
 
-        let input = serde_json::from_str::<serde_json::Value>(&input).handle_error()?;
-        let input = nekoton_abi::parse_abi_tokens(&method.inputs, input).handle_error()?;
+        let mut known_params = Vec::with_capacity(method.inputs.len());
+        let mut known_input = serde_json::Map::with_capacity(method.inputs.len());
 
-        let time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|e| format!("{}", e))?
-            .as_millis() as u64;
+        for param in &method.inputs {
+            let value = match param.name.as_str() {
+                "amount" | "tokens" => serde_json::Value::String(amount.to_owned()),
+                "recipient" | "to" => serde_json::Value::String(recipient.to_string()),
+                "deployWalletValue" => serde_json::Value::String(deploy_wallet_value.to_owned()),
+                "remainingGasTo" | "send_gas_to" => {
+                    serde_json::Value::String(remaining_gas_to.to_string())
+                },
+                "notify" | "notify_receiver" => serde_json::Value::Bool(notify),
+                "payload" => serde_json::Value::String(payload.to_owned()),
+                _ => continue,
+            };
 
-        let expire_at = ExpireAt::new_from_millis(Expiration::Timeout(timeout), time);
+            known_params.push(param.to_owned());
+            known_input.insert(param.name.to_owned(), value);
+        }
 
-        let mut header = HashMap::with_capacity(3);
+        let mut known_input =
+            parse_abi_tokens(&known_params, serde_json::Value::Object(known_input))?.into_iter();
 
-        header.insert("time".to_string(), ton_abi::TokenValue::Time(time));
-        header.insert(
-            "expire".to_string(),
-            ton_abi::TokenValue::Expire(expire_at.timestamp),
-        );
-        header.insert("pubkey".to_string(), ton_abi::TokenValue::PublicKey(None));
+        let input = method
+            .inputs
+            .iter()
+            .map(
+                |param| match known_params.iter().any(|e| e.name == param.name) {
+                    true => known_input.next().ok_or("Missing transfer param"),
+                    false => Ok(ton_abi::Token::new(
+                        &param.name,
+                        ton_abi::TokenValue::default_value(&param.kind),
+                    )),
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()
+            .handle_error()?;
 
         let body = method
-            .encode_input(&header, &input, false, None)
+            .encode_input(&Default::default(), &input, true, None)
+            .and_then(|e| e.into_cell())
             .handle_error()?;
 
-        let mut message =
-            ton_block::Message::with_ext_in_header(ton_block::ExternalInboundMessageHeader {
-                dst,
-                ..Default::default()
-            });
+        let body = ton_types::serialize_toc(&body).handle_error()?;
 
-        if let Some(state_init) = state_init {
-            message.set_state_init(state_init);
-        }
+        let body = base64::encode(&body);
 
-        message.set_body(body.into());
+        serde_json::to_value(body).handle_error()
+    }
+
+    internal_fn(
+        contract_abi,
+        recipient,
+        amount,
+        deploy_wallet_value,
+        remaining_gas_to,
+        notify,
+        comment,
+    )
+    .match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_encode_external_input(
+    contract_abi: *mut c_char,
+    method: *mut c_char,
+    input: *mut c_char,
+    public_key: *mut c_char,
+    timeout: c_uint,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_encode_external_input");
+
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let method = method.to_string_from_ptr();
+    let input = input.to_string_from_ptr();
+    let public_key = public_key.to_optional_string_from_ptr();
+
+    fn internal_fn(
+        contract_abi: String,
+        method: String,
+        input: String,
+        public_key: Option<String>,
+        timeout: u32,
+    ) -> Result<serde_json::Value, String> {
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let method = contract_abi.function(&method).handle_error()?;
+
+        let input = serde_json::from_str::<serde_json::Value>(&input).handle_error()?;
+        let input = parse_abi_tokens(&method.inputs, input)?;
+
+        let public_key = public_key.as_deref().map(parse_public_key).transpose()?;
+
+        let (header, _, expire_at) = make_external_header(public_key, timeout)?;
+
+        let body = method
+            .encode_input(&header, &input, false, None)
+            .and_then(|e| e.into_cell())
+            .handle_error()?;
+
+        let body = ton_types::serialize_toc(&body).handle_error()?;
 
-        let signed_message = SignedMessage {
-            message,
-            expire_at: expire_at.timestamp,
+        let encoded_external_input = EncodedExternalInput {
+            body: base64::encode(&body),
+            expire_at,
         };
 
-        serde_json::to_value(&signed_message).handle_error()
+        serde_json::to_value(&encoded_external_input).handle_error()
     }
 
-    internal_fn(dst, contract_abi, method, state_init, input, timeout).match_result()
+    internal_fn(contract_abi, method, input, public_key, timeout).match_result()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn nt_create_external_message(
+pub unsafe extern "C" fn nt_create_external_message_without_signature(
     dst: *mut c_char,
     contract_abi: *mut c_char,
     method: *mut c_char,
@@ -276,12 +898,14 @@ pub unsafe extern "C" fn nt_create_external_message(
     public_key: *mut c_char,
     timeout: c_uint,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_create_external_message_without_signature");
+
     let dst = dst.to_string_from_ptr();
     let contract_abi = contract_abi.to_string_from_ptr();
     let method = method.to_string_from_ptr();
     let state_init = state_init.to_optional_string_from_ptr();
     let input = input.to_string_from_ptr();
-    let public_key = public_key.to_string_from_ptr();
+    let public_key = public_key.to_optional_string_from_ptr();
 
     fn internal_fn(
         dst: String,
@@ -289,7 +913,7 @@ pub unsafe extern "C" fn nt_create_external_message(
         method: String,
         state_init: Option<String>,
         input: String,
-        public_key: String,
+        public_key: Option<String>,
         timeout: u32,
     ) -> Result<serde_json::Value, String> {
         let dst = parse_address(&dst)?;
@@ -298,14 +922,35 @@ pub unsafe extern "C" fn nt_create_external_message(
 
         let state_init = state_init
             .as_deref()
-            .map(ton_block::StateInit::construct_from_base64)
-            .transpose()
-            .handle_error()?;
+            .map(parse_boc::<ton_block::StateInit>)
+            .transpose()?;
 
         let input = serde_json::from_str::<serde_json::Value>(&input).handle_error()?;
-        let input = nekoton_abi::parse_abi_tokens(&method.inputs, input).handle_error()?;
+        let input = parse_abi_tokens(&method.inputs, input)?;
 
-        let public_key = parse_public_key(&public_key)?;
+        let public_key = public_key.as_deref().map(parse_public_key).transpose()?;
+
+        let (body, expire_at, data_hash, layout) = match has_signature_layout(method) {
+            true => {
+                let unsigned_body = make_unsigned_body(method, &input, public_key, timeout)?;
+
+                (
+                    unsigned_body.body,
+                    unsigned_body.expire_at,
+                    Some(base64::encode(&unsigned_body.hash)),
+                    Some(unsigned_body.layout),
+                )
+            },
+            false => {
+                let (header, _, expire_at) = make_external_header(public_key, timeout)?;
+
+                let body = method
+                    .encode_input(&header, &input, false, None)
+                    .handle_error()?;
+
+                (body, expire_at, None, None)
+            },
+        };
 
         let mut message =
             ton_block::Message::with_ext_in_header(ton_block::ExternalInboundMessageHeader {
@@ -317,19 +962,17 @@ pub unsafe extern "C" fn nt_create_external_message(
             message.set_state_init(state_init);
         }
 
-        let unsigned_message = make_labs_unsigned_message(
-            clock!().as_ref(),
-            message,
-            Expiration::Timeout(timeout),
-            &public_key,
-            Cow::Owned(method.to_owned()),
-            input,
-        )
-        .handle_error()?;
+        message.set_body(body.into());
 
-        let ptr = Box::into_raw(Box::new(Arc::new(unsigned_message)));
+        let signed_message = SignedMessage { message, expire_at };
 
-        serde_json::to_value(ptr as usize).handle_error()
+        let unsigned_external_message = UnsignedExternalMessage {
+            signed_message,
+            data_hash,
+            layout,
+        };
+
+        serde_json::to_value(&unsigned_external_message).handle_error()
     }
 
     internal_fn(
@@ -345,476 +988,2989 @@ pub unsafe extern "C" fn nt_create_external_message(
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn nt_parse_known_payload(payload: *mut c_char) -> *mut c_char {
-    let payload = payload.to_string_from_ptr();
-
-    fn internal_fn(payload: String) -> Result<serde_json::Value, String> {
-        let payload = parse_slice(&payload)?;
-
-        let known_payload = parse_payload(payload);
-
-        serde_json::to_value(known_payload).handle_error()
-    }
-
-    internal_fn(payload).match_result()
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn nt_decode_input(
-    message_body: *mut c_char,
+pub unsafe extern "C" fn nt_create_external_message(
+    dst: *mut c_char,
     contract_abi: *mut c_char,
     method: *mut c_char,
-    internal: c_uint,
+    state_init: *mut c_char,
+    input: *mut c_char,
+    public_key: *mut c_char,
+    timeout: c_uint,
 ) -> *mut c_char {
-    let message_body = message_body.to_string_from_ptr();
+    let _timer = ffi_timer!("nt_create_external_message");
+
+    let dst = dst.to_string_from_ptr();
     let contract_abi = contract_abi.to_string_from_ptr();
     let method = method.to_string_from_ptr();
-    let internal = internal != 0;
+    let state_init = state_init.to_optional_string_from_ptr();
+    let input = input.to_string_from_ptr();
+    let public_key = public_key.to_string_from_ptr();
 
     fn internal_fn(
-        message_body: String,
+        dst: String,
         contract_abi: String,
         method: String,
-        internal: bool,
+        state_init: Option<String>,
+        input: String,
+        public_key: String,
+        timeout: u32,
     ) -> Result<serde_json::Value, String> {
-        let message_body = parse_slice(&message_body)?;
+        let dst = parse_address(&dst)?;
         let contract_abi = parse_contract_abi(&contract_abi)?;
-        let method = parse_method_name(&method)?;
-
-        let input = nekoton_abi::decode_input(&contract_abi, message_body, &method, internal)
-            .handle_error()?;
-
-        match input {
-            Some((method, input)) => {
-                let input = nekoton_abi::make_abi_tokens(&input).handle_error()?;
-
-                let input = DecodedInput {
-                    method: method.name.to_owned(),
-                    input,
-                };
-
-                serde_json::to_value(&input).handle_error()
-            },
-            None => Ok(serde_json::Value::Null),
-        }
-    }
-
-    internal_fn(message_body, contract_abi, method, internal).match_result()
-}
+        let method = contract_abi.function(&method).handle_error()?;
 
-#[no_mangle]
-pub unsafe extern "C" fn nt_decode_event(
-    message_body: *mut c_char,
-    contract_abi: *mut c_char,
-    event: *mut c_char,
-) -> *mut c_char {
-    let message_body = message_body.to_string_from_ptr();
-    let contract_abi = contract_abi.to_string_from_ptr();
-    let event = event.to_string_from_ptr();
+        let state_init = state_init
+            .as_deref()
+            .map(parse_boc::<ton_block::StateInit>)
+            .transpose()?;
 
-    fn internal_fn(
-        message_body: String,
-        contract_abi: String,
-        event: String,
-    ) -> Result<serde_json::Value, String> {
-        let message_body = parse_slice(&message_body)?;
-        let contract_abi = parse_contract_abi(&contract_abi)?;
-        let event = parse_method_name(&event)?;
+        let input = serde_json::from_str::<serde_json::Value>(&input).handle_error()?;
+        let input = parse_abi_tokens(&method.inputs, input)?;
 
-        let event =
-            nekoton_abi::decode_event(&contract_abi, message_body, &event).handle_error()?;
+        let public_key = parse_public_key(&public_key)?;
 
-        match event {
-            Some((event, data)) => {
-                let data = nekoton_abi::make_abi_tokens(&data).handle_error()?;
+        let unsigned_message =
+            make_unsigned_message(dst, state_init, method, input, &public_key, timeout)?;
 
-                let event = DecodedEvent {
-                    event: event.name.to_owned(),
-                    data,
-                };
+        let ptr = Box::into_raw(Box::new(Arc::new(unsigned_message)));
 
-                serde_json::to_value(&event).handle_error()
-            },
-            None => Ok(serde_json::Value::Null),
-        }
+        serde_json::to_value(ptr as usize).handle_error()
     }
 
-    internal_fn(message_body, contract_abi, event).match_result()
+    internal_fn(
+        dst,
+        contract_abi,
+        method,
+        state_init,
+        input,
+        public_key,
+        timeout,
+    )
+    .match_result()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn nt_decode_output(
-    message_body: *mut c_char,
+pub unsafe extern "C" fn nt_prepare_sign_payload(
     contract_abi: *mut c_char,
     method: *mut c_char,
+    input: *mut c_char,
+    public_key: *mut c_char,
+    timeout: c_uint,
 ) -> *mut c_char {
-    let message_body = message_body.to_string_from_ptr();
+    let _timer = ffi_timer!("nt_prepare_sign_payload");
+
     let contract_abi = contract_abi.to_string_from_ptr();
     let method = method.to_string_from_ptr();
+    let input = input.to_string_from_ptr();
+    let public_key = public_key.to_string_from_ptr();
 
     fn internal_fn(
-        message_body: String,
         contract_abi: String,
         method: String,
+        input: String,
+        public_key: String,
+        timeout: u32,
     ) -> Result<serde_json::Value, String> {
-        let message_body = parse_slice(&message_body)?;
         let contract_abi = parse_contract_abi(&contract_abi)?;
-        let method = parse_method_name(&method)?;
+        let method = contract_abi.function(&method).handle_error()?;
 
-        let output =
-            nekoton_abi::decode_output(&contract_abi, message_body, &method).handle_error()?;
+        let input = serde_json::from_str::<serde_json::Value>(&input).handle_error()?;
+        let input = parse_abi_tokens(&method.inputs, input)?;
 
-        match output {
-            Some((method, output)) => {
-                let output = nekoton_abi::make_abi_tokens(&output).handle_error()?;
+        let public_key = parse_public_key(&public_key)?;
 
-                let output = DecodedOutput {
-                    method: method.name.to_owned(),
-                    output,
-                };
+        let unsigned_body = make_unsigned_body(method, &input, Some(public_key), timeout)?;
 
-                serde_json::to_value(&output).handle_error()
-            },
-            None => Ok(serde_json::Value::Null),
-        }
+        let body = unsigned_body.body.into_cell().handle_error()?;
+        let body = ton_types::serialize_toc(&body).handle_error()?;
+
+        let sign_payload = SignPayload {
+            hash: base64::encode(&unsigned_body.hash),
+            body: base64::encode(&body),
+            time: unsigned_body.time,
+            expire_at: unsigned_body.expire_at,
+        };
+
+        serde_json::to_value(&sign_payload).handle_error()
     }
 
-    internal_fn(message_body, contract_abi, method).match_result()
+    internal_fn(contract_abi, method, input, public_key, timeout).match_result()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn nt_decode_transaction(
-    transaction: *mut c_char,
-    contract_abi: *mut c_char,
-    method: *mut c_char,
+pub unsafe extern "C" fn nt_create_signed_external_message(
+    dst: *mut c_char,
+    state_init: *mut c_char,
+    body: *mut c_char,
+    signature: *mut c_char,
+    expire_at: c_uint,
 ) -> *mut c_char {
-    let transaction = transaction.to_string_from_ptr();
-    let contract_abi = contract_abi.to_string_from_ptr();
-    let method = method.to_string_from_ptr();
+    let _timer = ffi_timer!("nt_create_signed_external_message");
+
+    let dst = dst.to_string_from_ptr();
+    let state_init = state_init.to_optional_string_from_ptr();
+    let body = body.to_string_from_ptr();
+    let signature = signature.to_string_from_ptr();
 
     fn internal_fn(
-        transaction: String,
-        contract_abi: String,
-        method: String,
+        dst: String,
+        state_init: Option<String>,
+        body: String,
+        signature: String,
+        expire_at: u32,
     ) -> Result<serde_json::Value, String> {
-        let transaction = serde_json::from_str::<Transaction>(&transaction).handle_error()?;
-        let contract_abi = parse_contract_abi(&contract_abi)?;
-        let method = parse_method_name(&method)?;
+        let dst = parse_address(&dst)?;
 
-        let internal = transaction.in_msg.src.is_some();
+        let state_init = state_init
+            .as_deref()
+            .map(parse_boc::<ton_block::StateInit>)
+            .transpose()?;
 
-        let in_msg_body = match transaction.in_msg.body {
-            Some(body) => body.data.into(),
-            None => return Ok(serde_json::Value::Null),
-        };
+        let body = parse_slice(&body)?;
 
-        let method = match guess_method_by_input(&contract_abi, &in_msg_body, &method, internal)
-            .handle_error()?
-        {
-            Some(method) => method,
-            None => return Ok(serde_json::Value::Null),
-        };
+        let signature = base64::decode(&signature).handle_error()?;
+        let signature = ed25519_dalek::Signature::try_from(signature.as_slice()).handle_error()?;
 
-        let input = method.decode_input(in_msg_body, internal).handle_error()?;
-        let input = nekoton_abi::make_abi_tokens(&input).handle_error()?;
+        let body = insert_signature(body, &signature)?;
 
-        let ext_out_msgs = transaction
-            .out_msgs
-            .iter()
-            .filter_map(|e| {
-                if e.dst.is_some() {
-                    return None;
-                };
+        let mut message =
+            ton_block::Message::with_ext_in_header(ton_block::ExternalInboundMessageHeader {
+                dst,
+                ..Default::default()
+            });
 
-                Some(match e.body.to_owned() {
-                    Some(body) => Ok(body.data.into()),
-                    None => Err("Expected message body").handle_error(),
-                })
-            })
-            .collect::<Result<Vec<_>, String>>()?;
+        if let Some(state_init) = state_init {
+            message.set_state_init(state_init);
+        }
 
-        let output = nekoton_abi::process_raw_outputs(&ext_out_msgs, method).handle_error()?;
-        let output = nekoton_abi::make_abi_tokens(&output).handle_error()?;
+        message.set_body(body);
 
-        let decoded_transaction = DecodedTransaction {
-            method: method.name.to_owned(),
-            input,
-            output,
-        };
+        let signed_message = SignedMessage { message, expire_at };
 
-        serde_json::to_value(&decoded_transaction).handle_error()
+        serde_json::to_value(&signed_message).handle_error()
     }
 
-    internal_fn(transaction, contract_abi, method).match_result()
+    internal_fn(dst, state_init, body, signature, expire_at).match_result()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn nt_decode_transaction_events(
-    transaction: *mut c_char,
-    contract_abi: *mut c_char,
-) -> *mut c_char {
-    let transaction = transaction.to_string_from_ptr();
-    let contract_abi = contract_abi.to_string_from_ptr();
-
-    fn internal_fn(transaction: String, contract_abi: String) -> Result<serde_json::Value, String> {
-        let transaction = serde_json::from_str::<Transaction>(&transaction).handle_error()?;
-        let contract_abi = parse_contract_abi(&contract_abi)?;
-
-        let ext_out_msgs = transaction
-            .out_msgs
-            .iter()
-            .filter_map(|e| {
-                if e.dst.is_some() {
-                    return None;
-                };
+pub unsafe extern "C" fn nt_parse_known_payload(payload: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_parse_known_payload");
 
-                Some(match e.body.to_owned() {
-                    Some(body) => Ok(body.data.into()),
-                    None => Err("Expected message body").handle_error(),
-                })
-            })
-            .collect::<Result<Vec<_>, String>>()?;
+    let payload = payload.to_string_from_ptr();
 
-        let events = ext_out_msgs
-            .into_iter()
-            .filter_map(|e| {
-                let id = nekoton_abi::read_function_id(&e).ok()?;
-                let event = contract_abi.event_by_id(id).ok()?;
-                let tokens = event.decode_input(e).ok()?;
+    fn internal_fn(payload: String) -> Result<serde_json::Value, String> {
+        let payload = parse_slice(&payload)?;
 
-                let data = match nekoton_abi::make_abi_tokens(&tokens) {
-                    Ok(data) => Ok(DecodedTransactionEvent {
-                        event: event.name.to_owned(),
-                        data,
-                    }),
-                    Err(err) => Err(err).handle_error(),
-                };
+        if let Some(known_payload) = parse_payload(payload.clone()) {
+            return to_camel_case_value(&known_payload).handle_error();
+        }
 
-                Some(data)
-            })
-            .collect::<Result<Vec<_>, String>>()?;
+        let known_payload = parse_bridge_burn_payload(payload).map(ExtraKnownPayload::BridgeBurn);
 
-        serde_json::to_value(&events).handle_error()
+        serde_json::to_value(known_payload).handle_error()
     }
 
-    internal_fn(transaction, contract_abi).match_result()
+    internal_fn(payload).match_result()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn nt_get_boc_hash(boc: *mut c_char) -> *mut c_char {
-    let boc = boc.to_string_from_ptr();
+pub unsafe extern "C" fn nt_build_bridge_burn_payload(
+    evm_address: *mut c_char,
+    chain_id: c_uint,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_build_bridge_burn_payload");
 
-    fn internal_fn(boc: String) -> Result<serde_json::Value, String> {
-        let body = base64::decode(boc).handle_error()?;
+    let evm_address = evm_address.to_string_from_ptr();
 
-        let hash = ton_types::deserialize_tree_of_cells(&mut body.as_slice())
-            .handle_error()?
-            .repr_hash()
-            .to_hex_string();
+    fn internal_fn(evm_address: String, chain_id: u32) -> Result<serde_json::Value, String> {
+        let evm_address = evm_address.trim_start_matches("0x");
+        let evm_address = hex::decode(evm_address).handle_error()?;
 
-        serde_json::to_value(hash).handle_error()
+        if evm_address.len() != EVM_ADDRESS_LEN {
+            return Err("Invalid EVM address length").handle_error();
+        }
+
+        if evm_address.iter().all(|e| *e == 0) {
+            return Err("Empty EVM address").handle_error();
+        }
+
+        let tokens = [
+            ton_abi::Token::new(
+                "ethereumAddress",
+                ton_abi::TokenValue::Uint(ton_abi::Uint {
+                    number: BigUint::from_bytes_be(&evm_address),
+                    size: EVM_ADDRESS_LEN * 8,
+                }),
+            ),
+            ton_abi::Token::new(
+                "chainId",
+                ton_abi::TokenValue::Uint(ton_abi::Uint {
+                    number: BigUint::from(chain_id),
+                    size: 32,
+                }),
+            ),
+        ];
+
+        let cell = nekoton_abi::pack_into_cell(&tokens).handle_error()?;
+
+        let payload = ton_types::serialize_toc(&cell)
+            .handle_error()
+            .map(base64::encode)?;
+
+        serde_json::to_value(payload).handle_error()
     }
 
-    internal_fn(boc).match_result()
+    internal_fn(evm_address, chain_id).match_result()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn nt_pack_into_cell(
-    params: *mut c_char,
-    tokens: *mut c_char,
+pub unsafe extern "C" fn nt_get_contract_function_selector_from_body(
+    body: *mut c_char,
 ) -> *mut c_char {
-    let params = params.to_string_from_ptr();
-    let tokens = tokens.to_string_from_ptr();
+    let _timer = ffi_timer!("nt_get_contract_function_selector_from_body");
 
-    fn internal_fn(params: String, tokens: String) -> Result<serde_json::Value, String> {
-        let params = parse_params_list(&params)?;
-        let tokens = serde_json::from_str::<serde_json::Value>(&tokens).handle_error()?;
-        let tokens = nekoton_abi::parse_abi_tokens(&params, tokens).handle_error()?;
+    let body = body.to_string_from_ptr();
 
-        let cell = nekoton_abi::pack_into_cell(&tokens).handle_error()?;
-        let bytes = ton_types::serialize_toc(&cell).handle_error()?;
+    fn internal_fn(body: String) -> Result<serde_json::Value, String> {
+        let body = parse_slice(&body)?;
 
-        let bytes = base64::encode(&bytes);
+        if body.remaining_bits() < 32 {
+            return Ok(serde_json::Value::Null);
+        }
 
-        serde_json::to_value(bytes).handle_error()
+        let function_id = nekoton_abi::read_function_id(&body).handle_error()?;
+
+        Ok(serde_json::Value::String(format!("0x{:08x}", function_id)))
     }
 
-    internal_fn(params, tokens).match_result()
+    internal_fn(body).match_result()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn nt_unpack_from_cell(
-    params: *mut c_char,
-    boc: *mut c_char,
-    allow_partial: c_uint,
+pub unsafe extern "C" fn nt_get_function_signature(
+    contract_abi: *mut c_char,
+    method: *mut c_char,
 ) -> *mut c_char {
-    let params = params.to_string_from_ptr();
-    let boc = boc.to_string_from_ptr();
-    let allow_partial = allow_partial != 0;
+    let _timer = ffi_timer!("nt_get_function_signature");
 
-    fn internal_fn(
-        params: String,
-        boc: String,
-        allow_partial: bool,
-    ) -> Result<serde_json::Value, String> {
-        let params = parse_params_list(&params)?;
-        let body = base64::decode(boc).handle_error()?;
-        let cell = ton_types::deserialize_tree_of_cells(&mut body.as_slice()).handle_error()?;
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let method = method.to_string_from_ptr();
 
-        let tokens = nekoton_abi::unpack_from_cell(&params, cell.into(), allow_partial)
-            .handle_error()
-            .and_then(|e| nekoton_abi::make_abi_tokens(&e).handle_error())?;
+    fn internal_fn(contract_abi: String, method: String) -> Result<serde_json::Value, String> {
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let function = contract_abi.function(&method).handle_error()?;
 
-        serde_json::to_value(&tokens).handle_error()
+        Ok(serde_json::Value::String(format!(
+            "0x{:08x}",
+            function.input_id
+        )))
     }
 
-    internal_fn(params, boc, allow_partial).match_result()
+    internal_fn(contract_abi, method).match_result()
 }
 
-fn parse_contract_abi(contract_abi: &str) -> Result<ton_abi::Contract, String> {
-    ton_abi::Contract::load(contract_abi).handle_error()
-}
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_function_id(
+    contract_abi: *mut c_char,
+    method: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_function_id");
 
-fn parse_method_name(value: &str) -> Result<MethodName, String> {
-    if let Ok(value) = serde_json::from_str::<String>(value) {
-        Ok(MethodName::Known(value))
-    } else if let Ok(value) = serde_json::from_str::<Vec<String>>(value) {
-        Ok(MethodName::GuessInRange(value))
-    } else {
-        Err(AbiError::ExpectedStringOrArray).handle_error()
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let method = method.to_string_from_ptr();
+
+    fn internal_fn(contract_abi: String, method: String) -> Result<serde_json::Value, String> {
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let function = contract_abi.function(&method).handle_error()?;
+
+        let function_ids = FunctionIds {
+            input_id: function.input_id,
+            output_id: function.output_id,
+        };
+
+        serde_json::to_value(&function_ids).handle_error()
     }
-}
 
-fn parse_slice(boc: &str) -> Result<ton_types::SliceData, String> {
-    let body = base64::decode(boc).handle_error()?;
-    let cell = ton_types::deserialize_tree_of_cells(&mut body.as_slice()).handle_error()?;
-    Ok(cell.into())
+    internal_fn(contract_abi, method).match_result()
 }
 
-fn parse_params_list(params: &str) -> Result<Vec<ton_abi::Param>, String> {
-    let params = serde_json::from_str::<Vec<AbiParam>>(params).handle_error()?;
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_event_signature(
+    contract_abi: *mut c_char,
+    event: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_event_signature");
 
-    params
-        .iter()
-        .map(parse_param)
-        .collect::<Result<_, AbiError>>()
-        .handle_error()
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let event = event.to_string_from_ptr();
+
+    fn internal_fn(contract_abi: String, event: String) -> Result<serde_json::Value, String> {
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let event = contract_abi.event(&event).handle_error()?;
+
+        Ok(serde_json::Value::String(format!("0x{:08x}", event.id)))
+    }
+
+    internal_fn(contract_abi, event).match_result()
 }
 
-fn parse_param(param: &AbiParam) -> Result<ton_abi::Param, AbiError> {
-    let name = param.name.to_owned();
+#[no_mangle]
+pub unsafe extern "C" fn nt_decode_input(
+    message_body: *mut c_char,
+    contract_abi: *mut c_char,
+    method: *mut c_char,
+    internal: c_uint,
+    with_raw_fallback: c_uint,
+    strict: c_uint,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_decode_input");
 
-    let mut kind: ton_abi::ParamType = parse_param_type(&param.param_type)?;
+    let message_body = message_body.to_string_from_ptr();
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let method = method.to_string_from_ptr();
+    let internal = internal != 0;
+    let with_raw_fallback = with_raw_fallback != 0;
+    let strict = strict != 0;
 
-    let components: Vec<ton_abi::Param> = match &param.components {
-        Some(components) => components
-            .iter()
-            .map(parse_param)
-            .collect::<Result<_, AbiError>>()?,
-        None => Vec::new(),
-    };
+    fn internal_fn(
+        message_body: String,
+        contract_abi: String,
+        method: String,
+        internal: bool,
+        with_raw_fallback: bool,
+        strict: bool,
+    ) -> Result<serde_json::Value, String> {
+        let message_body = parse_slice(&message_body)?;
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let method = parse_method_name(&method)?;
 
-    kind.set_components(components)
-        .map_err(|_| AbiError::InvalidComponents)?;
+        let input =
+            nekoton_abi::decode_input(&contract_abi, message_body.clone(), &method, internal)
+                .handle_error()?;
 
-    Ok(ton_abi::Param { name, kind })
-}
+        match input {
+            Some((method, input)) => {
+                let value_types = value_types::collect_value_types(&input);
 
-fn parse_param_type(kind: &str) -> Result<ton_abi::ParamType, AbiError> {
-    if let Some(']') = kind.chars().last() {
-        let num: String = kind
-            .chars()
-            .rev()
-            .skip(1)
-            .take_while(|c| *c != '[')
-            .collect::<String>()
-            .chars()
-            .rev()
-            .collect();
+                let input = nekoton_abi::make_abi_tokens(&input).handle_error()?;
 
-        let count = kind.len();
-        return if num.is_empty() {
-            let subtype = parse_param_type(&kind[..count - 2])?;
-            Ok(ton_abi::ParamType::Array(Box::new(subtype)))
-        } else {
-            let len = num
-                .parse::<usize>()
-                .map_err(|_| AbiError::ExpectedParamType)?;
+                let (trailing_bits, trailing_refs) = match strict {
+                    true => {
+                        let trailing_data = measure_trailing_data(method, message_body, internal)?;
+                        ensure_no_trailing_data(trailing_data)?
+                    },
+                    false => measure_trailing_data(method, message_body, internal)?,
+                };
 
-            let subtype = parse_param_type(&kind[..count - num.len() - 2])?;
-            Ok(ton_abi::ParamType::FixedArray(Box::new(subtype), len))
-        };
-    }
+                let input = DecodedInput {
+                    method: method.name.to_owned(),
+                    input,
+                    trailing_bits,
+                    trailing_refs,
+                    value_types,
+                };
 
-    let result = match kind {
-        "bool" => ton_abi::ParamType::Bool,
-        "tuple" => ton_abi::ParamType::Tuple(Vec::new()),
-        s if s.starts_with("int") => {
-            let len = usize::from_str(&s[3..]).map_err(|_| AbiError::ExpectedParamType)?;
-            ton_abi::ParamType::Int(len)
-        },
-        s if s.starts_with("uint") => {
-            let len = usize::from_str(&s[4..]).map_err(|_| AbiError::ExpectedParamType)?;
-            ton_abi::ParamType::Uint(len)
-        },
-        s if s.starts_with("varint") => {
-            let len = usize::from_str(&s[6..]).map_err(|_| AbiError::ExpectedParamType)?;
-            ton_abi::ParamType::Int(len)
-        },
-        s if s.starts_with("varuint") => {
-            let len = usize::from_str(&s[7..]).map_err(|_| AbiError::ExpectedParamType)?;
-            ton_abi::ParamType::Uint(len)
-        },
-        s if s.starts_with("map(") && s.ends_with(')') => {
-            let types: Vec<&str> = kind[4..kind.len() - 1].splitn(2, ',').collect();
-            if types.len() != 2 {
-                return Err(AbiError::ExpectedParamType);
-            }
+                serde_json::to_value(&MatchedBody::new(input)).handle_error()
+            },
+            None if with_raw_fallback => {
+                // External bodies start with the signature and the header, the id follows them
+                let function_id = nekoton_abi::read_input_function_id(
+                    &contract_abi,
+                    message_body.clone(),
+                    internal,
+                )
+                .ok();
+
+                let raw_hex = hex::encode(message_body.get_bytestring(0));
+
+                let original_body_boc = match function_id {
+                    Some(BOUNCE_MARKER) if internal => Some(split_bounced_body(message_body)?),
+                    _ => None,
+                };
 
-            let key_type = parse_param_type(types[0])?;
-            let value_type = parse_param_type(types[1])?;
+                let input = RawDecodedInput {
+                    matched: false,
+                    function_id: function_id.map(|e| format!("0x{:08x}", e)),
+                    raw_hex,
+                    bounced: original_body_boc.is_some(),
+                    original_body_boc,
+                };
 
-            match key_type {
-                ton_abi::ParamType::Int(_)
-                | ton_abi::ParamType::Uint(_)
-                | ton_abi::ParamType::Address => {
-                    ton_abi::ParamType::Map(Box::new(key_type), Box::new(value_type))
-                },
-                _ => return Err(AbiError::ExpectedParamType),
-            }
-        },
-        "cell" => ton_abi::ParamType::Cell,
-        "address" => ton_abi::ParamType::Address,
-        "token" | "gram" => ton_abi::ParamType::Token,
-        "bytes" => ton_abi::ParamType::Bytes,
-        s if s.starts_with("fixedbytes") => {
-            let len = usize::from_str(&s[10..]).map_err(|_| AbiError::ExpectedParamType)?;
-            ton_abi::ParamType::FixedBytes(len)
-        },
-        "time" => ton_abi::ParamType::Time,
-        "expire" => ton_abi::ParamType::Expire,
-        "pubkey" => ton_abi::ParamType::PublicKey,
-        "string" => ton_abi::ParamType::String,
-        s if s.starts_with("optional(") && s.ends_with(')') => {
-            let inner_type = parse_param_type(&s[9..s.len() - 1])?;
-            ton_abi::ParamType::Optional(Box::new(inner_type))
-        },
-        s if s.starts_with("ref(") && s.ends_with(')') => {
-            let inner_type = parse_param_type(&s[4..s.len() - 1])?;
-            ton_abi::ParamType::Ref(Box::new(inner_type))
-        },
-        _ => return Err(AbiError::ExpectedParamType),
-    };
+                serde_json::to_value(&input).handle_error()
+            },
+            None => serde_json::to_value(&UnmatchedBody { matched: false }).handle_error(),
+        }
+    }
 
-    Ok(result)
+    internal_fn(
+        message_body,
+        contract_abi,
+        method,
+        internal,
+        with_raw_fallback,
+        strict,
+    )
+    .match_result()
 }
 
-#[derive(thiserror::Error, Debug)]
-enum AbiError {
-    #[error("Expected param type")]
-    ExpectedParamType,
-    #[error("Expected string or array")]
-    ExpectedStringOrArray,
-    #[error("Invalid components")]
-    InvalidComponents,
+#[no_mangle]
+pub unsafe extern "C" fn nt_decode_event(
+    message_body: *mut c_char,
+    contract_abi: *mut c_char,
+    event: *mut c_char,
+    strict: c_uint,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_decode_event");
+
+    let message_body = message_body.to_string_from_ptr();
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let event = event.to_string_from_ptr();
+    let strict = strict != 0;
+
+    fn internal_fn(
+        message_body: String,
+        contract_abi: String,
+        event: String,
+        strict: bool,
+    ) -> Result<serde_json::Value, String> {
+        let message_body = parse_slice(&message_body)?;
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let event = parse_method_name(&event)?;
+
+        let event = nekoton_abi::decode_event(&contract_abi, message_body.clone(), &event)
+            .handle_error()?;
+
+        match event {
+            Some((event, data)) => {
+                if strict {
+                    let trailing_data = measure_body_trailing_data(
+                        &event.inputs,
+                        &event.abi_version,
+                        message_body,
+                    )?;
+                    ensure_no_trailing_data(trailing_data)?;
+                }
+
+                let data = nekoton_abi::make_abi_tokens(&data).handle_error()?;
+
+                let event = DecodedEvent {
+                    event: event.name.to_owned(),
+                    data,
+                };
+
+                serde_json::to_value(&MatchedBody::new(event)).handle_error()
+            },
+            None => serde_json::to_value(&UnmatchedBody { matched: false }).handle_error(),
+        }
+    }
+
+    internal_fn(message_body, contract_abi, event, strict).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_decode_message_body(
+    message_body: *mut c_char,
+    contract_abi: *mut c_char,
+    internal: c_uint,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_decode_message_body");
+
+    let message_body = message_body.to_string_from_ptr();
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let internal = internal != 0;
+
+    fn internal_fn(
+        message_body: String,
+        contract_abi: String,
+        internal: bool,
+    ) -> Result<serde_json::Value, String> {
+        let message_body = parse_slice(&message_body)?;
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+
+        // Outputs and events carry the id right at the start of the body
+        let function_id = nekoton_abi::read_function_id(&message_body).ok();
+
+        // External inbound bodies start with the signature and the header, the id follows them
+        let input_id = match internal {
+            true => function_id,
+            false => {
+                nekoton_abi::read_input_function_id(&contract_abi, message_body.clone(), internal)
+                    .ok()
+            },
+        };
+
+        let find_function = |id: Option<u32>, is_input: bool| {
+            let id = id?;
+            contract_abi.functions().values().find(|e| match is_input {
+                true => e.input_id == id,
+                false => e.output_id == id,
+            })
+        };
+
+        let decoded_body = if let Some(function) = find_function(input_id, true) {
+            let input = function
+                .decode_input(message_body, internal)
+                .handle_error()?;
+
+            DecodedMessageBody::Input(DecodedCall {
+                method: function.name.to_owned(),
+                input: nekoton_abi::make_abi_tokens(&input).handle_error()?,
+            })
+        } else if let Some(function) = find_function(function_id, false) {
+            let output = function
+                .decode_output(message_body, internal)
+                .handle_error()?;
+
+            DecodedMessageBody::Output(DecodedOutput {
+                method: function.name.to_owned(),
+                output: nekoton_abi::make_abi_tokens(&output).handle_error()?,
+            })
+        } else if let Some(event) = function_id.and_then(|id| contract_abi.event_by_id(id).ok()) {
+            let data = event.decode_input(message_body).handle_error()?;
+
+            DecodedMessageBody::Event(DecodedEvent {
+                event: event.name.to_owned(),
+                data: nekoton_abi::make_abi_tokens(&data).handle_error()?,
+            })
+        } else {
+            // Unknown ids are expected on arbitrary traffic
+            return Ok(serde_json::Value::Null);
+        };
+
+        serde_json::to_value(&decoded_body).handle_error()
+    }
+
+    internal_fn(message_body, contract_abi, internal).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_decode_output(
+    message_body: *mut c_char,
+    contract_abi: *mut c_char,
+    method: *mut c_char,
+    strict: c_uint,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_decode_output");
+
+    let message_body = message_body.to_string_from_ptr();
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let method = method.to_string_from_ptr();
+    let strict = strict != 0;
+
+    fn internal_fn(
+        message_body: String,
+        contract_abi: String,
+        method: String,
+        strict: bool,
+    ) -> Result<serde_json::Value, String> {
+        let message_body = parse_slice(&message_body)?;
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let method = parse_method_name(&method)?;
+
+        let output = nekoton_abi::decode_output(&contract_abi, message_body.clone(), &method)
+            .handle_error()?;
+
+        match output {
+            Some((method, output)) => {
+                if strict {
+                    let trailing_data = measure_body_trailing_data(
+                        &method.outputs,
+                        &method.abi_version,
+                        message_body,
+                    )?;
+                    ensure_no_trailing_data(trailing_data)?;
+                }
+
+                let output = nekoton_abi::make_abi_tokens(&output).handle_error()?;
+
+                let output = DecodedOutput {
+                    method: method.name.to_owned(),
+                    output,
+                };
+
+                serde_json::to_value(&MatchedBody::new(output)).handle_error()
+            },
+            None => serde_json::to_value(&UnmatchedBody { matched: false }).handle_error(),
+        }
+    }
+
+    internal_fn(message_body, contract_abi, method, strict).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_decode_transaction(
+    transaction: *mut c_char,
+    contract_abi: *mut c_char,
+    method: *mut c_char,
+    strict: c_uint,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_decode_transaction");
+
+    let transaction = transaction.to_string_from_ptr();
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let method = method.to_string_from_ptr();
+    let strict = strict != 0;
+
+    fn internal_fn(
+        transaction: String,
+        contract_abi: String,
+        method: String,
+        strict: bool,
+    ) -> Result<serde_json::Value, String> {
+        let transaction = serde_json::from_str::<Transaction>(&transaction).handle_error()?;
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let method = parse_method_name(&method)?;
+
+        let decoded_transaction = decode_transaction(&transaction, &contract_abi, &method, strict)?;
+
+        serde_json::to_value(&decoded_transaction).handle_error()
+    }
+
+    internal_fn(transaction, contract_abi, method, strict).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_decode_transaction_with_state(
+    transaction: *mut c_char,
+    contract_abi: *mut c_char,
+    method: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_decode_transaction_with_state");
+
+    let transaction = transaction.to_string_from_ptr();
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let method = method.to_string_from_ptr();
+
+    fn internal_fn(
+        transaction: String,
+        contract_abi: String,
+        method: String,
+    ) -> Result<serde_json::Value, String> {
+        let transaction = serde_json::from_str::<Transaction>(&transaction).handle_error()?;
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let method = parse_method_name(&method)?;
+
+        let decoded_transaction =
+            match decode_transaction(&transaction, &contract_abi, &method, false)? {
+                Some(decoded_transaction) => decoded_transaction,
+                None => return Ok(serde_json::Value::Null),
+            };
+
+        // Account is destroyed when the transaction leaves it nonexistent
+        let destroyed = !matches!(transaction.orig_status, AccountStatus::Nonexist)
+            && matches!(transaction.end_status, AccountStatus::Nonexist);
+
+        let decoded_transaction_with_state = DecodedTransactionWithState {
+            decoded_transaction,
+            total_fees: transaction.total_fees.to_string(),
+            aborted: transaction.aborted,
+            destroyed,
+            action_result_code: transaction.result_code,
+        };
+
+        serde_json::to_value(&decoded_transaction_with_state).handle_error()
+    }
+
+    internal_fn(transaction, contract_abi, method).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_decode_safe_multisig_transaction(
+    submit_transaction: *mut c_char,
+    confirm_transactions: *mut c_char,
+    contract_abi: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_decode_safe_multisig_transaction");
+
+    let submit_transaction = submit_transaction.to_string_from_ptr();
+    let confirm_transactions = confirm_transactions.to_string_from_ptr();
+    let contract_abi = contract_abi.to_string_from_ptr();
+
+    fn internal_fn(
+        submit_transaction: String,
+        confirm_transactions: String,
+        contract_abi: String,
+    ) -> Result<serde_json::Value, String> {
+        let submit_transaction =
+            serde_json::from_str::<Transaction>(&submit_transaction).handle_error()?;
+        let confirm_transactions =
+            serde_json::from_str::<Vec<Transaction>>(&confirm_transactions).handle_error()?;
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+
+        let submit_function = contract_abi.function("submitTransaction").handle_error()?;
+        let confirm_function = contract_abi.function("confirmTransaction").handle_error()?;
+
+        let submit_call = decode_multisig_call(&submit_transaction, submit_function)?;
+
+        let field = |value: &serde_json::Value, name: &str| {
+            value.get(name).cloned().unwrap_or(serde_json::Value::Null)
+        };
+
+        let submission = MultisigSubmission {
+            transaction_id: field(&submit_call.output, "transId"),
+            dest: field(&submit_call.input, "dest"),
+            value: field(&submit_call.input, "value"),
+            bounce: field(&submit_call.input, "bounce"),
+            all_balance: field(&submit_call.input, "allBalance"),
+            payload: field(&submit_call.input, "payload"),
+        };
+
+        // Transaction is sent right away and gets zero id if no more confirmations are required
+        let mut executed = submit_call.sent_internal_messages
+            || submission.transaction_id == serde_json::Value::String("0".to_owned());
+
+        let mut confirmations = Vec::with_capacity(confirm_transactions.len());
+
+        for confirm_transaction in &confirm_transactions {
+            let confirm_call = decode_multisig_call(confirm_transaction, confirm_function)?;
+
+            executed |= confirm_call.sent_internal_messages;
+
+            confirmations.push(MultisigConfirmation {
+                transaction_id: field(&confirm_call.input, "transactionId"),
+                signer_public_key: confirm_call.signer_public_key,
+            });
+        }
+
+        let transaction = SafeMultisigTransaction {
+            submission,
+            confirmations,
+            executed,
+        };
+
+        serde_json::to_value(&transaction).handle_error()
+    }
+
+    internal_fn(submit_transaction, confirm_transactions, contract_abi).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_decode_transaction_events(
+    transaction: *mut c_char,
+    contract_abi: *mut c_char,
+    abi_version: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_decode_transaction_events");
+
+    let transaction = transaction.to_string_from_ptr();
+    let contract_abi = contract_abi.to_string_from_ptr();
+    let abi_version = abi_version.to_optional_string_from_ptr();
+
+    fn internal_fn(
+        transaction: String,
+        contract_abi: String,
+        abi_version: Option<String>,
+    ) -> Result<serde_json::Value, String> {
+        let transaction = serde_json::from_str::<Transaction>(&transaction).handle_error()?;
+        let contract_abi = parse_contract_abi(&contract_abi)?;
+        let abi_version = abi_version.as_deref().map(parse_abi_version).transpose()?;
+
+        // Messages without body can't carry an event
+        let ext_out_msgs = transaction
+            .out_msgs
+            .iter()
+            .filter(|e| e.dst.is_none())
+            .filter_map(|e| e.body.as_ref().map(|body| body.data.clone().into()))
+            .collect::<Vec<ton_types::SliceData>>();
+
+        // Unknown events are skipped, failures of known ones are reported in place
+        let events = ext_out_msgs
+            .into_iter()
+            .filter_map(|e| {
+                let id = nekoton_abi::read_function_id(&e).ok()?;
+                let mut event = contract_abi.event_by_id(id).ok()?.to_owned();
+
+                if let Some(abi_version) = abi_version {
+                    event.abi_version = abi_version;
+                }
+
+                let data = event
+                    .decode_input(e)
+                    .handle_error()
+                    .and_then(|tokens| nekoton_abi::make_abi_tokens(&tokens).handle_error());
+
+                let result = match data {
+                    Ok(data) => DecodedTransactionEventResult::Decoded(DecodedTransactionEvent {
+                        event: event.name.to_owned(),
+                        data,
+                    }),
+                    Err(error) => {
+                        DecodedTransactionEventResult::Error(DecodedTransactionEventError {
+                            event_name: event.name.to_owned(),
+                            error,
+                        })
+                    },
+                };
+
+                Some(result)
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::to_value(&events).handle_error()
+    }
+
+    internal_fn(transaction, contract_abi, abi_version).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_boc_hash(boc: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_boc_hash");
+
+    let boc = boc.to_string_from_ptr();
+
+    fn internal_fn(boc: String) -> Result<serde_json::Value, String> {
+        let hash = parse_cell(&boc)?.repr_hash().to_hex_string();
+
+        serde_json::to_value(hash).handle_error()
+    }
+
+    internal_fn(boc).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_code_salt(code: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_code_salt");
+
+    let code = code.to_string_from_ptr();
+
+    fn internal_fn(code: String) -> Result<serde_json::Value, String> {
+        let code = parse_cell(&code)?;
+
+        let salt = match nekoton_abi::get_code_salt(code).handle_error()? {
+            Some(salt) => salt,
+            None => return Ok(serde_json::Value::Null),
+        };
+
+        let salt = ton_types::serialize_toc(&salt)
+            .handle_error()
+            .map(base64::encode)?;
+
+        serde_json::to_value(salt).handle_error()
+    }
+
+    internal_fn(code).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_set_code_salt(code: *mut c_char, salt: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_set_code_salt");
+
+    let code = code.to_string_from_ptr();
+    let salt = salt.to_string_from_ptr();
+
+    fn internal_fn(code: String, salt: String) -> Result<serde_json::Value, String> {
+        let code = parse_cell(&code)?;
+        let salt = parse_cell(&salt)?;
+
+        let code = nekoton_abi::set_code_salt(code, salt).handle_error()?;
+
+        let code = ton_types::serialize_toc(&code)
+            .handle_error()
+            .map(base64::encode)?;
+
+        serde_json::to_value(code).handle_error()
+    }
+
+    internal_fn(code, salt).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_pack_into_cell(
+    params: *mut c_char,
+    tokens: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_pack_into_cell");
+
+    let params = params.to_string_from_ptr();
+    let tokens = tokens.to_string_from_ptr();
+
+    fn internal_fn(params: String, tokens: String) -> Result<serde_json::Value, String> {
+        let params = parse_params_list(&params)?;
+        let tokens = serde_json::from_str::<serde_json::Value>(&tokens).handle_error()?;
+        let tokens = parse_abi_tokens(&params, tokens)?;
+
+        let cell = nekoton_abi::pack_into_cell(&tokens).handle_error()?;
+        let bytes = ton_types::serialize_toc(&cell).handle_error()?;
+
+        let bytes = base64::encode(&bytes);
+
+        serde_json::to_value(bytes).handle_error()
+    }
+
+    internal_fn(params, tokens).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_unpack_from_cell(
+    params: *mut c_char,
+    boc: *mut c_char,
+    allow_partial: c_uint,
+    as_slice: c_uint,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_unpack_from_cell");
+
+    let params = params.to_string_from_ptr();
+    let boc = boc.to_string_from_ptr();
+    let allow_partial = allow_partial != 0;
+    let as_slice = as_slice != 0;
+
+    fn internal_fn(
+        params: String,
+        boc: String,
+        allow_partial: bool,
+        as_slice: bool,
+    ) -> Result<serde_json::Value, String> {
+        let params = parse_params_list(&params)?;
+        let mut slice = ton_types::SliceData::from(parse_cell(&boc)?);
+
+        // Message bodies keep their params after the function id
+        if as_slice {
+            slice.get_next_u32().handle_error()?;
+        }
+
+        let tokens = nekoton_abi::unpack_from_cell(&params, slice, allow_partial)
+            .handle_error()
+            .and_then(|e| nekoton_abi::make_abi_tokens(&e).handle_error())?;
+
+        serde_json::to_value(&tokens).handle_error()
+    }
+
+    internal_fn(params, boc, allow_partial, as_slice).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_unpack_from_cell_ex(
+    params: *mut c_char,
+    boc: *mut c_char,
+    allow_partial: c_uint,
+    as_slice: c_uint,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_unpack_from_cell_ex");
+
+    let params = params.to_string_from_ptr();
+    let boc = boc.to_string_from_ptr();
+    let allow_partial = allow_partial != 0;
+    let as_slice = as_slice != 0;
+
+    fn internal_fn(
+        params: String,
+        boc: String,
+        allow_partial: bool,
+        as_slice: bool,
+    ) -> Result<serde_json::Value, String> {
+        let params = parse_params_list(&params)?;
+        let root = parse_cell(&boc)?;
+        let mut slice = ton_types::SliceData::from(root.clone());
+
+        // Message bodies keep their params after the function id
+        if as_slice {
+            slice.get_next_u32().handle_error()?;
+        }
+
+        // Trailing data is rejected here unless partial decoding is allowed
+        let (tokens, cursor) = ton_abi::TokenValue::decode_params_with_cursor(
+            &params,
+            slice.into(),
+            &ton_abi::contract::ABI_VERSION_2_2,
+            allow_partial,
+        )
+        .handle_error()?;
+
+        let tokens = nekoton_abi::make_abi_tokens(&tokens).handle_error()?;
+
+        let (bits_consumed, refs_consumed, cell_depth) =
+            measure_consumed_data(&root, &cursor.slice)?;
+
+        let unpacked_cell = UnpackedCell {
+            tokens,
+            bits_consumed,
+            refs_consumed,
+            cell_depth,
+        };
+
+        serde_json::to_value(&unpacked_cell).handle_error()
+    }
+
+    internal_fn(params, boc, allow_partial, as_slice).match_result()
+}
+
+/// Bits and references read from the root up to the slice, along with the number of continuation
+/// cells in between. Decoding moves into the last reference once a cell has nothing else left, so
+/// every cell before the one of the slice is read completely
+fn measure_consumed_data(
+    root: &ton_types::Cell,
+    slice: &ton_types::SliceData,
+) -> Result<(usize, usize, usize), String> {
+    let target = slice.cell().repr_hash();
+
+    let mut cell = root.clone();
+    let (mut bits, mut refs, mut depth) = (0, 0, 0);
+
+    while cell.repr_hash() != target {
+        let cell_refs = cell.references_count();
+
+        bits += cell.bit_length();
+        refs += cell_refs;
+
+        cell = match cell_refs {
+            0 => return Err("Slice doesn't belong to the cell").handle_error(),
+            _ => cell.reference(cell_refs - 1).handle_error()?,
+        };
+        depth += 1;
+    }
+
+    bits += cell.bit_length() - slice.remaining_bits();
+    refs += cell.references_count() - slice.remaining_references();
+
+    Ok((bits, refs, depth))
+}
+
+/// Getters without declared outputs give an empty object on success, `None` stays for calls
+/// which produced no answer, so the two cases serialize differently
+fn make_output_tokens(
+    function: &ton_abi::Function,
+    code: i32,
+    tokens: Option<Vec<ton_abi::Token>>,
+) -> Result<Option<serde_json::Value>, String> {
+    let tokens = match tokens {
+        None if function.outputs.is_empty() && (code == 0 || code == 1) => Some(Vec::new()),
+        tokens => tokens,
+    };
+
+    tokens
+        .map(|e| nekoton_abi::make_abi_tokens(&e).handle_error())
+        .transpose()
+}
+
+/// Counts bits and references left in the body after its header and inputs were read
+fn measure_trailing_data(
+    function: &ton_abi::Function,
+    body: ton_types::SliceData,
+    internal: bool,
+) -> Result<(usize, usize), String> {
+    let (_, _, cursor) =
+        ton_abi::Function::decode_header(&function.abi_version, body, &function.header, internal)
+            .handle_error()?;
+
+    measure_params_trailing_data(&function.inputs, &function.abi_version, cursor)
+}
+
+/// Same as [`measure_trailing_data`] for event and output bodies, which only start with an id
+fn measure_body_trailing_data(
+    params: &[ton_abi::Param],
+    abi_version: &ton_abi::contract::AbiVersion,
+    mut body: ton_types::SliceData,
+) -> Result<(usize, usize), String> {
+    body.get_next_u32().handle_error()?;
+
+    measure_params_trailing_data(params, abi_version, body)
+}
+
+fn measure_params_trailing_data(
+    params: &[ton_abi::Param],
+    abi_version: &ton_abi::contract::AbiVersion,
+    body: ton_types::SliceData,
+) -> Result<(usize, usize), String> {
+    let (_, cursor) =
+        ton_abi::TokenValue::decode_params_with_cursor(params, body.into(), abi_version, true)
+            .handle_error()?;
+
+    Ok((
+        cursor.slice.remaining_bits(),
+        cursor.slice.remaining_references(),
+    ))
+}
+
+/// Strict decoding rejects bodies with data after the params, otherwise two different
+/// bodies could decode to the same values
+fn ensure_no_trailing_data((bits, refs): (usize, usize)) -> Result<(usize, usize), String> {
+    match bits == 0 && refs == 0 {
+        true => Ok((bits, refs)),
+        false => Err(format!("TrailingData {{ bits: {}, refs: {} }}", bits, refs)),
+    }
+}
+
+fn parse_abi_tokens(
+    params: &[ton_abi::Param],
+    value: serde_json::Value,
+) -> Result<Vec<ton_abi::Token>, String> {
+    let mismatches = validation::validate_abi_tokens(params, &value);
+
+    nekoton_abi::parse_abi_tokens(params, value).map_err(|err| {
+        if mismatches.is_empty() {
+            return err.to_string();
+        }
+
+        let details = mismatches
+            .iter()
+            .map(|e| format!("{}: expected {}, got {}", e.path, e.expected, e.got))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        format!("Invalid ABI tokens: {}", details)
+    })
+}
+
+fn decode_transaction(
+    transaction: &Transaction,
+    contract_abi: &ton_abi::Contract,
+    method: &MethodName,
+    strict: bool,
+) -> Result<Option<DecodedTransaction>, String> {
+    let internal = transaction.in_msg.src.is_some();
+
+    let direction = transaction_direction(transaction);
+
+    let (in_msg_value, in_msg_sender) = match &transaction.in_msg.src {
+        Some(src) => (
+            Some(transaction.in_msg.value.to_string()),
+            Some(src.to_string()),
+        ),
+        None => (None, None),
+    };
+
+    let in_msg_body = match &transaction.in_msg.body {
+        Some(body) => body.data.clone().into(),
+        None => return Ok(None),
+    };
+
+    let method =
+        match guess_method_by_input(contract_abi, &in_msg_body, method, internal).handle_error()? {
+            Some(method) => method,
+            None => return Ok(None),
+        };
+
+    if strict {
+        let trailing_data = measure_trailing_data(method, in_msg_body.clone(), internal)?;
+        ensure_no_trailing_data(trailing_data)?;
+    }
+
+    let input = method.decode_input(in_msg_body, internal).handle_error()?;
+    let input = nekoton_abi::make_abi_tokens(&input).handle_error()?;
+
+    let ext_out_msgs = transaction
+        .out_msgs
+        .iter()
+        .filter_map(|e| {
+            if e.dst.is_some() {
+                return None;
+            };
+
+            Some(match e.body.to_owned() {
+                Some(body) => Ok(body.data.into()),
+                None => Err("Expected message body").handle_error(),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let output = nekoton_abi::process_raw_outputs(&ext_out_msgs, method).handle_error()?;
+    let output = nekoton_abi::make_abi_tokens(&output).handle_error()?;
+
+    let out_messages = transaction
+        .out_msgs
+        .iter()
+        .map(|e| decode_out_message(contract_abi, e))
+        .collect::<Vec<_>>();
+
+    let decoded_transaction = DecodedTransaction {
+        method: method.name.to_owned(),
+        input,
+        output,
+        in_msg_value,
+        in_msg_sender,
+        direction,
+        out_messages,
+    };
+
+    Ok(Some(decoded_transaction))
+}
+
+/// Internal bodies are matched against function inputs and external ones against events,
+/// bodies which don't match or fail to decode are left as raw info
+fn decode_out_message(
+    contract_abi: &ton_abi::Contract,
+    message: &nekoton::core::models::Message,
+) -> DecodedOutMessage {
+    let body: Option<ton_types::SliceData> = message.body.as_ref().map(|e| e.data.clone().into());
+
+    let function_id = body
+        .as_ref()
+        .and_then(|e| nekoton_abi::read_function_id(e).ok());
+
+    let decoded = match (body, function_id) {
+        (Some(body), Some(function_id)) if message.dst.is_some() => contract_abi
+            .functions()
+            .values()
+            .find(|e| e.input_id == function_id)
+            .and_then(|function| {
+                let input = function.decode_input(body, true).ok()?;
+                let input = nekoton_abi::make_abi_tokens(&input).ok()?;
+
+                Some(DecodedOutMessageBody::Call(DecodedCall {
+                    method: function.name.to_owned(),
+                    input,
+                }))
+            }),
+        (Some(body), Some(function_id)) => {
+            contract_abi
+                .event_by_id(function_id)
+                .ok()
+                .and_then(|event| {
+                    let data = event.decode_input(body).ok()?;
+                    let data = nekoton_abi::make_abi_tokens(&data).ok()?;
+
+                    Some(DecodedOutMessageBody::Event(DecodedEvent {
+                        event: event.name.to_owned(),
+                        data,
+                    }))
+                })
+        },
+        _ => None,
+    };
+
+    DecodedOutMessage {
+        dst: message.dst.as_ref().map(|e| e.to_string()),
+        value: message.value.to_string(),
+        bounce: message.bounce,
+        body_function_id: function_id.map(|e| format!("0x{:08x}", e)),
+        decoded,
+    }
+}
+
+struct MultisigCall {
+    input: serde_json::Value,
+    output: serde_json::Value,
+    signer_public_key: Option<String>,
+    sent_internal_messages: bool,
+}
+
+/// Reads `expire` from the header of an external message body, `None` if the ABI has no such header
+pub(crate) fn read_expire_at(
+    contract_abi: &str,
+    body: ton_types::SliceData,
+) -> Result<Option<u32>, String> {
+    let contract_abi = parse_contract_abi(contract_abi)?;
+
+    let function = contract_abi
+        .functions()
+        .values()
+        .next()
+        .ok_or("ABI has no functions")
+        .handle_error()?;
+
+    let (header, _, _) =
+        ton_abi::Function::decode_header(&function.abi_version, body, &function.header, false)
+            .handle_error()?;
+
+    let expire_at = header.into_iter().find_map(|e| match e.value {
+        ton_abi::TokenValue::Expire(expire_at) => Some(expire_at),
+        _ => None,
+    });
+
+    Ok(expire_at)
+}
+
+fn decode_multisig_call(
+    transaction: &Transaction,
+    function: &ton_abi::Function,
+) -> Result<MultisigCall, String> {
+    let internal = transaction.in_msg.src.is_some();
+
+    let body: ton_types::SliceData = match &transaction.in_msg.body {
+        Some(body) => body.data.clone().into(),
+        None => return Err("Expected message body").handle_error(),
+    };
+
+    let (header, _, _) = ton_abi::Function::decode_header(
+        &function.abi_version,
+        body.clone(),
+        &function.header,
+        internal,
+    )
+    .handle_error()?;
+
+    let signer_public_key = header.into_iter().find_map(|e| match e.value {
+        ton_abi::TokenValue::PublicKey(Some(public_key)) => {
+            Some(hex::encode(public_key.as_bytes()))
+        },
+        _ => None,
+    });
+
+    let input = function.decode_input(body, internal).handle_error()?;
+    let input = nekoton_abi::make_abi_tokens(&input).handle_error()?;
+
+    let ext_out_msgs = transaction
+        .out_msgs
+        .iter()
+        .filter(|e| e.dst.is_none())
+        .filter_map(|e| e.body.as_ref().map(|body| body.data.clone().into()))
+        .collect::<Vec<ton_types::SliceData>>();
+
+    let output = nekoton_abi::process_raw_outputs(&ext_out_msgs, function).handle_error()?;
+    let output = nekoton_abi::make_abi_tokens(&output).handle_error()?;
+
+    Ok(MultisigCall {
+        input,
+        output,
+        signer_public_key,
+        sent_internal_messages: transaction.out_msgs.iter().any(|e| e.dst.is_some()),
+    })
+}
+
+/// External inbound messages are initiated by the account owner, internal ones are received
+/// unless more value left the account than came in
+fn transaction_direction(transaction: &Transaction) -> TransactionDirection {
+    let account = transaction.in_msg.dst.as_ref();
+
+    let value_left = transaction
+        .out_msgs
+        .iter()
+        .filter(|e| e.dst.is_some() && e.dst.as_ref() != account)
+        .map(|e| e.value as u128)
+        .sum::<u128>();
+
+    match &transaction.in_msg.src {
+        None if value_left > 0 => TransactionDirection::Out,
+        None => TransactionDirection::SelfTransfer,
+        Some(src) if Some(src) == account => TransactionDirection::SelfTransfer,
+        Some(_) if value_left > transaction.in_msg.value as u128 => TransactionDirection::Out,
+        Some(_) => TransactionDirection::In,
+    }
+}
+
+/// Header of an external call along with its expiration timestamp
+fn make_external_header(
+    public_key: Option<ed25519_dalek::PublicKey>,
+    timeout: u32,
+) -> Result<(HashMap<String, ton_abi::TokenValue>, u64, u32), String> {
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("{}", e))?
+        .as_millis() as u64;
+
+    let expire_at = ExpireAt::new_from_millis(Expiration::Timeout(timeout), time);
+
+    let mut header = HashMap::with_capacity(3);
+
+    header.insert("time".to_string(), ton_abi::TokenValue::Time(time));
+    header.insert(
+        "expire".to_string(),
+        ton_abi::TokenValue::Expire(expire_at.timestamp),
+    );
+    header.insert(
+        "pubkey".to_string(),
+        ton_abi::TokenValue::PublicKey(public_key),
+    );
+
+    Ok((header, time, expire_at.timestamp))
+}
+
+/// External call body with a zeroed signature slot in front of the signed payload
+struct UnsignedBody {
+    body: ton_types::BuilderData,
+    hash: Vec<u8>,
+    layout: SignatureLayout,
+    time: u64,
+    expire_at: u32,
+}
+
+/// ABI 1.x keeps the signature in a reference and 2.3 signs the address too
+fn has_signature_layout(method: &ton_abi::Function) -> bool {
+    method.abi_version.major == 2 && method.abi_version.minor <= 2
+}
+
+fn make_unsigned_body(
+    method: &ton_abi::Function,
+    input: &[ton_abi::Token],
+    public_key: Option<ed25519_dalek::PublicKey>,
+    timeout: u32,
+) -> Result<UnsignedBody, String> {
+    if !has_signature_layout(method) {
+        return Err(format!(
+            "Signature layout is not supported for ABI {}.{}",
+            method.abi_version.major, method.abi_version.minor
+        ));
+    }
+
+    let (header, time, expire_at) = make_external_header(public_key, timeout)?;
+
+    // Payload excludes the signature bits, its hash is exactly what gets signed
+    let (payload, hash) = method
+        .create_unsigned_call(&header, input, false, true)
+        .handle_error()?;
+
+    let signature_offset = 1;
+    let signature_length = ed25519_dalek::SIGNATURE_LENGTH * 8;
+
+    let mut body = payload;
+    body.prepend_raw(&[0; ed25519_dalek::SIGNATURE_LENGTH], signature_length)
+        .and_then(|e| e.prepend_raw(&[0x80], signature_offset))
+        .handle_error()?;
+
+    // Header params are written in the declared order right after the signature
+    let mut header_offset = signature_offset + signature_length;
+    let mut public_key_offset = None;
+
+    for param in &method.header {
+        match param.kind {
+            ton_abi::ParamType::Time => header_offset += 64,
+            ton_abi::ParamType::Expire => header_offset += 32,
+            ton_abi::ParamType::PublicKey => {
+                // Maybe bit goes before the key itself
+                header_offset += 1;
+
+                if public_key.is_some() {
+                    public_key_offset = Some(header_offset);
+                    header_offset += ed25519_dalek::PUBLIC_KEY_LENGTH * 8;
+                }
+            },
+            ton_abi::ParamType::Uint(size) | ton_abi::ParamType::Int(size) => header_offset += size,
+            _ => return Err(format!("Unsupported header param: {}", param.name)),
+        }
+    }
+
+    let layout = SignatureLayout {
+        signature_offset,
+        signature_length,
+        public_key_offset,
+        public_key_length: public_key_offset.map(|_| ed25519_dalek::PUBLIC_KEY_LENGTH * 8),
+    };
+
+    Ok(UnsignedBody {
+        body,
+        hash,
+        layout,
+        time,
+        expire_at,
+    })
+}
+
+/// Fills the signature slot reserved by `make_unsigned_body`
+fn insert_signature(
+    mut body: ton_types::SliceData,
+    signature: &ed25519_dalek::Signature,
+) -> Result<ton_types::SliceData, String> {
+    if !body.get_next_bit().handle_error()? {
+        return Err("Body has no signature slot").handle_error();
+    }
+
+    body.move_by(ed25519_dalek::SIGNATURE_LENGTH * 8)
+        .handle_error()?;
+
+    let mut builder = ton_types::BuilderData::new();
+    builder
+        .append_bit_one()
+        .and_then(|e| e.append_raw(&signature.to_bytes(), ed25519_dalek::SIGNATURE_LENGTH * 8))
+        .and_then(|e| e.append_builder(&ton_types::BuilderData::from_slice(&body)))
+        .handle_error()?;
+
+    builder.into_cell().map(Into::into).handle_error()
+}
+
+fn make_unsigned_message(
+    dst: MsgAddressInt,
+    state_init: Option<ton_block::StateInit>,
+    method: &ton_abi::Function,
+    input: Vec<ton_abi::Token>,
+    public_key: &ed25519_dalek::PublicKey,
+    timeout: u32,
+) -> Result<Box<dyn UnsignedMessage>, String> {
+    let mut message =
+        ton_block::Message::with_ext_in_header(ton_block::ExternalInboundMessageHeader {
+            dst,
+            ..Default::default()
+        });
+
+    if let Some(state_init) = state_init {
+        message.set_state_init(state_init);
+    }
+
+    make_labs_unsigned_message(
+        clock!().as_ref(),
+        message,
+        Expiration::Timeout(timeout),
+        public_key,
+        Cow::Owned(method.to_owned()),
+        input,
+    )
+    .handle_error()
+}
+
+fn parse_contract_abi(contract_abi: &str) -> Result<Arc<ton_abi::Contract>, String> {
+    abi_cache::load_contract_abi(contract_abi)
+}
+
+/// Parses a `{ "<hex hash>": "<base64 boc>" }` map, checking that every cell matches its key
+fn parse_libraries_map(libraries: &str) -> Result<Vec<ton_types::Cell>, String> {
+    serde_json::from_str::<HashMap<String, String>>(libraries)
+        .handle_error()?
+        .into_iter()
+        .map(|(hash, boc)| {
+            let hash = ton_types::UInt256::from_str(&hash).handle_error()?;
+
+            let cell = parse_cell(&boc)?;
+
+            match cell.repr_hash() == hash {
+                true => Ok(cell),
+                false => Err(format!(
+                    "Library cell hash mismatch: {}",
+                    hash.to_hex_string()
+                )),
+            }
+        })
+        .collect()
+}
+
+fn parse_blockchain_config(config: &str) -> Result<BlockchainConfig, String> {
+    let config = parse_boc::<ton_block::ConfigParams>(config)?;
+    BlockchainConfig::with_config(config).handle_error()
+}
+
+fn parse_abi_version(abi_version: &str) -> Result<ton_abi::contract::AbiVersion, String> {
+    ton_abi::contract::AbiVersion::parse(abi_version).handle_error()
+}
+
+fn parse_method_name(value: &str) -> Result<MethodName, String> {
+    if let Ok(value) = serde_json::from_str::<String>(value) {
+        Ok(MethodName::Known(value))
+    } else if let Ok(value) = serde_json::from_str::<Vec<String>>(value) {
+        Ok(MethodName::GuessInRange(value))
+    } else {
+        Err(AbiError::ExpectedStringOrArray).handle_error()
+    }
+}
+
+const EVM_ADDRESS_LEN: usize = 20;
+
+/// Params which the bridge proxy (`ProxyTokenTransfer.onAcceptTokensBurn`) decodes from the burn
+/// payload, the cell has no tag of its own
+fn bridge_burn_payload_params() -> [ton_abi::Param; 2] {
+    [
+        ton_abi::Param::new(
+            "ethereumAddress",
+            ton_abi::ParamType::Uint(EVM_ADDRESS_LEN * 8),
+        ),
+        ton_abi::Param::new("chainId", ton_abi::ParamType::Uint(32)),
+    ]
+}
+
+/// Bounced message bodies start with 32 set bits followed by the original body
+const BOUNCE_MARKER: u32 = 0xffffffff;
+
+fn split_bounced_body(mut body: ton_types::SliceData) -> Result<String, String> {
+    body.move_by(32).handle_error()?;
+
+    ton_types::serialize_toc(&body.into_cell())
+        .handle_error()
+        .map(base64::encode)
+}
+
+/// As the payload is untagged, only a cell of exactly its size with a non-empty address is taken
+/// for one
+fn parse_bridge_burn_payload(payload: ton_types::SliceData) -> Option<BridgeBurnPayload> {
+    if payload.remaining_bits() != EVM_ADDRESS_LEN * 8 + 32 || payload.remaining_references() != 0 {
+        return None;
+    }
+
+    let tokens =
+        nekoton_abi::unpack_from_cell(&bridge_burn_payload_params(), payload, false).ok()?;
+
+    let (evm_address, chain_id) = match tokens.as_slice() {
+        [ton_abi::Token {
+            value: ton_abi::TokenValue::Uint(evm_address),
+            ..
+        }, ton_abi::Token {
+            value: ton_abi::TokenValue::Uint(chain_id),
+            ..
+        }] => (
+            evm_address.number.to_bytes_be(),
+            u32::try_from(&chain_id.number).ok()?,
+        ),
+        _ => return None,
+    };
+
+    if evm_address.iter().all(|e| *e == 0) {
+        return None;
+    }
+
+    // Leading zero bytes are dropped by the big integer
+    let mut padded_address = [0; EVM_ADDRESS_LEN];
+    padded_address[EVM_ADDRESS_LEN - evm_address.len()..].copy_from_slice(&evm_address);
+
+    Some(BridgeBurnPayload {
+        evm_address: format!("0x{}", hex::encode(padded_address)),
+        chain_id,
+    })
+}
+
+/// Reads a block structure from a base64 or hex encoded BOC
+pub(crate) fn parse_boc<T: Deserializable>(boc: &str) -> Result<T, String> {
+    parse_cell(boc).and_then(|cell| T::construct_from_cell(cell).handle_error())
+}
+
+fn parse_slice(boc: &str) -> Result<ton_types::SliceData, String> {
+    parse_cell(boc).map(From::from)
+}
+
+/// Accepts base64 and, as some tools hand them out, hex encoded BOCs
+pub(crate) fn parse_cell(boc: &str) -> Result<ton_types::Cell, String> {
+    let deserialize =
+        |body: Vec<u8>| ton_types::deserialize_tree_of_cells(&mut body.as_slice()).handle_error();
+
+    // Hex digits are valid base64 too, so hex is only tried once base64 doesn't give a cell
+    match base64::decode(boc).handle_error().and_then(deserialize) {
+        Ok(cell) => Ok(cell),
+        Err(err) => match hex::decode(boc) {
+            Ok(body) => deserialize(body),
+            Err(_) => Err(err),
+        },
+    }
+}
+
+/// Limits nesting of tuples, arrays, maps, optionals and refs in user supplied params
+const MAX_PARAM_DEPTH: usize = 32;
+
+fn parse_params_list(params: &str) -> Result<Vec<ton_abi::Param>, String> {
+    let params = serde_json::from_str::<Vec<AbiParam>>(params).handle_error()?;
+
+    params
+        .iter()
+        .map(|e| parse_param(e, 0))
+        .collect::<Result<_, AbiError>>()
+        .handle_error()
+}
+
+fn parse_param(param: &AbiParam, depth: usize) -> Result<ton_abi::Param, AbiError> {
+    if depth > MAX_PARAM_DEPTH {
+        return Err(AbiError::TooDeepNesting);
+    }
+
+    let name = param.name.to_owned();
+
+    let mut kind: ton_abi::ParamType = parse_param_type(&param.param_type, depth)?;
+
+    let components: Vec<ton_abi::Param> = match &param.components {
+        Some(components) => components
+            .iter()
+            .map(|e| parse_param(e, depth + 1))
+            .collect::<Result<_, AbiError>>()?,
+        None => Vec::new(),
+    };
+
+    kind.set_components(components)
+        .map_err(|_| AbiError::InvalidComponents)?;
+
+    Ok(ton_abi::Param { name, kind })
+}
+
+fn parse_param_type(kind: &str, depth: usize) -> Result<ton_abi::ParamType, AbiError> {
+    if depth > MAX_PARAM_DEPTH {
+        return Err(AbiError::TooDeepNesting);
+    }
+
+    // Pretty-printed ABIs put spaces after commas, e.g. `map(uint32, uint128)`
+    let kind = kind.trim();
+
+    if let Some(']') = kind.chars().last() {
+        let num: String = kind
+            .chars()
+            .rev()
+            .skip(1)
+            .take_while(|c| *c != '[')
+            .collect::<String>()
+            .chars()
+            .rev()
+            .collect();
+
+        let count = kind.len();
+        return if num.is_empty() {
+            let subtype = parse_param_type(&kind[..count - 2], depth + 1)?;
+            Ok(ton_abi::ParamType::Array(Box::new(subtype)))
+        } else {
+            let len = num
+                .parse::<usize>()
+                .map_err(|_| AbiError::ExpectedParamType)?;
+
+            let subtype = parse_param_type(&kind[..count - num.len() - 2], depth + 1)?;
+            Ok(ton_abi::ParamType::FixedArray(Box::new(subtype), len))
+        };
+    }
+
+    let result = match kind {
+        "bool" => ton_abi::ParamType::Bool,
+        "tuple" => ton_abi::ParamType::Tuple(Vec::new()),
+        s if s.starts_with("int") => {
+            let len = parse_type_size(&s[3..])?;
+            ton_abi::ParamType::Int(len)
+        },
+        s if s.starts_with("uint") => {
+            let len = parse_type_size(&s[4..])?;
+            ton_abi::ParamType::Uint(len)
+        },
+        s if s.starts_with("varint") => {
+            let len = parse_type_size(&s[6..])?;
+            ton_abi::ParamType::Int(len)
+        },
+        s if s.starts_with("varuint") => {
+            let len = parse_type_size(&s[7..])?;
+            ton_abi::ParamType::Uint(len)
+        },
+        s if s.starts_with("map(") && s.ends_with(')') => {
+            let types: Vec<&str> = kind[4..kind.len() - 1].splitn(2, ',').collect();
+            if types.len() != 2 {
+                return Err(AbiError::ExpectedParamType);
+            }
+
+            let key_type = parse_param_type(types[0], depth + 1)?;
+            let value_type = parse_param_type(types[1], depth + 1)?;
+
+            match key_type {
+                ton_abi::ParamType::Int(_)
+                | ton_abi::ParamType::Uint(_)
+                | ton_abi::ParamType::Address => {
+                    ton_abi::ParamType::Map(Box::new(key_type), Box::new(value_type))
+                },
+                _ => return Err(AbiError::ExpectedParamType),
+            }
+        },
+        "cell" => ton_abi::ParamType::Cell,
+        "address" => ton_abi::ParamType::Address,
+        "token" | "gram" => ton_abi::ParamType::Token,
+        "bytes" => ton_abi::ParamType::Bytes,
+        s if s.starts_with("fixedbytes") => {
+            let len = parse_type_size(&s[10..])?;
+            ton_abi::ParamType::FixedBytes(len)
+        },
+        "time" => ton_abi::ParamType::Time,
+        "expire" => ton_abi::ParamType::Expire,
+        "pubkey" => ton_abi::ParamType::PublicKey,
+        "string" => ton_abi::ParamType::String,
+        s if s.starts_with("optional(") && s.ends_with(')') => {
+            let inner_type = parse_param_type(&s[9..s.len() - 1], depth + 1)?;
+            ton_abi::ParamType::Optional(Box::new(inner_type))
+        },
+        s if s.starts_with("ref(") && s.ends_with(')') => {
+            let inner_type = parse_param_type(&s[4..s.len() - 1], depth + 1)?;
+            ton_abi::ParamType::Ref(Box::new(inner_type))
+        },
+        _ => return Err(AbiError::ExpectedParamType),
+    };
+
+    Ok(result)
+}
+
+/// Only plain digits are accepted, `usize::from_str` alone would also take `fixedbytes+32`
+fn parse_type_size(size: &str) -> Result<usize, AbiError> {
+    match !size.is_empty() && size.bytes().all(|e| e.is_ascii_digit()) {
+        true => usize::from_str(size).map_err(|_| AbiError::ExpectedParamType),
+        false => Err(AbiError::ExpectedParamType),
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+enum AbiError {
+    #[error("Expected param type")]
+    ExpectedParamType,
+    #[error("Expected string or array")]
+    ExpectedStringOrArray,
+    #[error("Invalid components")]
+    InvalidComponents,
+    #[error("Too deep param nesting")]
+    TooDeepNesting,
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{Signer, Verifier};
+
+    use super::*;
+
+    const TEST_ABI: &str = r#"{
+        "ABI version": 2,
+        "version": "2.2",
+        "header": ["time", "expire", "pubkey"],
+        "functions": [
+            {
+                "name": "setValue",
+                "inputs": [{ "name": "value", "type": "uint32" }],
+                "outputs": []
+            }
+        ],
+        "data": [],
+        "events": [],
+        "fields": []
+    }"#;
+
+    fn test_keypair() -> ed25519_dalek::Keypair {
+        let secret = ed25519_dalek::SecretKey::from_bytes(&[1; 32]).unwrap();
+        let public = ed25519_dalek::PublicKey::from(&secret);
+
+        ed25519_dalek::Keypair { secret, public }
+    }
+
+    fn test_input(method: &ton_abi::Function) -> Vec<ton_abi::Token> {
+        parse_abi_tokens(&method.inputs, serde_json::json!({ "value": 42 })).unwrap()
+    }
+
+    #[test]
+    fn unsigned_body_layout_points_at_public_key() {
+        let contract_abi = parse_contract_abi(TEST_ABI).unwrap();
+        let method = contract_abi.function("setValue").unwrap();
+        let keypair = test_keypair();
+
+        let unsigned_body =
+            make_unsigned_body(method, &test_input(method), Some(keypair.public), 60).unwrap();
+
+        let layout = unsigned_body.layout;
+        assert_eq!(layout.signature_offset, 1);
+        assert_eq!(layout.signature_length, 512);
+        assert_eq!(layout.public_key_offset, Some(1 + 512 + 64 + 32 + 1));
+
+        let mut body = ton_types::SliceData::from(unsigned_body.body.into_cell().unwrap());
+        body.move_by(layout.public_key_offset.unwrap()).unwrap();
+
+        let public_key = body.get_next_bytes(32).unwrap();
+        assert_eq!(public_key, keypair.public.to_bytes());
+    }
+
+    #[test]
+    fn expire_at_is_read_from_body_header() {
+        let contract_abi = parse_contract_abi(TEST_ABI).unwrap();
+        let method = contract_abi.function("setValue").unwrap();
+        let keypair = test_keypair();
+
+        let unsigned_body =
+            make_unsigned_body(method, &test_input(method), Some(keypair.public), 60).unwrap();
+        let expire_at = unsigned_body.expire_at;
+
+        let body = ton_types::SliceData::from(unsigned_body.body.into_cell().unwrap());
+
+        assert_eq!(read_expire_at(TEST_ABI, body).unwrap(), Some(expire_at));
+    }
+
+    #[test]
+    fn inserted_signature_matches_signed_hash() {
+        let contract_abi = parse_contract_abi(TEST_ABI).unwrap();
+        let method = contract_abi.function("setValue").unwrap();
+        let keypair = test_keypair();
+
+        let unsigned_body =
+            make_unsigned_body(method, &test_input(method), Some(keypair.public), 60).unwrap();
+        let unsigned_cell = unsigned_body.body.into_cell().unwrap();
+
+        let signature = keypair.sign(&unsigned_body.hash);
+
+        let signed_body = insert_signature(unsigned_cell.clone().into(), &signature).unwrap();
+
+        let mut signed = signed_body.clone();
+        assert!(signed.get_next_bit().unwrap());
+        let signature_bytes = signed.get_next_bytes(64).unwrap();
+        assert_eq!(signature_bytes, signature.to_bytes());
+
+        // Everything after the slot is untouched
+        let mut unsigned = ton_types::SliceData::from(unsigned_cell);
+        unsigned.move_by(1 + 512).unwrap();
+        assert_eq!(signed.remaining_bits(), unsigned.remaining_bits());
+        assert_eq!(signed.get_bytestring(0), unsigned.get_bytestring(0));
+
+        let signature = ed25519_dalek::Signature::try_from(signature_bytes.as_slice()).unwrap();
+        assert!(keypair
+            .public
+            .verify(&unsigned_body.hash, &signature)
+            .is_ok());
+    }
+
+    fn unsigned_external_message(contract_abi: &str) -> serde_json::Value {
+        let dst = c_string(&format!("0:{}", "44".repeat(32)));
+        let (contract_abi, method) = (c_string(contract_abi), c_string("setValue"));
+        let input = c_string(r#"{"value":42}"#);
+        let public_key = c_string(&hex::encode(test_keypair().public.as_bytes()));
+
+        let result = call_ffi(|| unsafe {
+            nt_create_external_message_without_signature(
+                dst.as_ptr() as *mut c_char,
+                contract_abi.as_ptr() as *mut c_char,
+                method.as_ptr() as *mut c_char,
+                std::ptr::null_mut(),
+                input.as_ptr() as *mut c_char,
+                public_key.as_ptr() as *mut c_char,
+                60,
+            )
+        });
+
+        assert_eq!(result["type"], "ok", "{}", result["data"]);
+
+        result["data"].clone()
+    }
+
+    #[test]
+    fn unsigned_external_message_has_layout_for_abi_2_2() {
+        let message = unsigned_external_message(TEST_ABI);
+
+        assert!(message["dataHash"].is_string());
+        assert_eq!(message["layout"]["signatureOffset"], 1);
+    }
+
+    #[test]
+    fn unsigned_external_message_for_abi_1_0() {
+        let contract_abi = r#"{
+            "ABI version": 1,
+            "functions": [
+                {
+                    "name": "setValue",
+                    "inputs": [{ "name": "value", "type": "uint32" }],
+                    "outputs": []
+                }
+            ],
+            "data": [],
+            "events": []
+        }"#;
+
+        let message = unsigned_external_message(contract_abi);
+
+        assert!(message["boc"].is_string());
+        assert!(message["dataHash"].is_null());
+        assert!(message["layout"].is_null());
+    }
+
+    #[test]
+    fn unsigned_external_message_for_abi_2_3() {
+        let contract_abi = r#"{
+            "ABI version": 2,
+            "version": "2.3",
+            "header": ["time", "expire", "pubkey"],
+            "functions": [
+                {
+                    "name": "setValue",
+                    "inputs": [{ "name": "value", "type": "uint32" }],
+                    "outputs": []
+                }
+            ],
+            "data": [],
+            "events": [],
+            "fields": []
+        }"#;
+
+        let message = unsigned_external_message(contract_abi);
+
+        assert!(message["boc"].is_string());
+        assert!(message["dataHash"].is_null());
+        assert!(message["layout"].is_null());
+    }
+
+    #[test]
+    fn insert_signature_requires_reserved_slot() {
+        let mut builder = ton_types::BuilderData::new();
+        builder.append_bit_zero().unwrap();
+
+        let body = ton_types::SliceData::from(builder.into_cell().unwrap());
+        let signature = test_keypair().sign(&[0; 32]);
+
+        assert!(insert_signature(body, &signature).is_err());
+    }
+
+    fn call_ffi(f: impl FnOnce() -> *mut c_char) -> serde_json::Value {
+        let result = unsafe { std::ffi::CString::from_raw(f()) };
+
+        serde_json::from_str(result.to_str().unwrap()).unwrap()
+    }
+
+    fn c_string(value: &str) -> std::ffi::CString {
+        std::ffi::CString::new(value).unwrap()
+    }
+
+    fn uint256_params(count: usize) -> String {
+        let params = (0..count)
+            .map(|i| serde_json::json!({ "name": format!("value{}", i), "type": "uint256" }))
+            .collect::<Vec<_>>();
+
+        serde_json::to_string(&params).unwrap()
+    }
+
+    /// Three values in the root and two more in its continuation, as ABI 2.2 lays them out
+    fn chained_boc() -> String {
+        let mut next = ton_types::BuilderData::new();
+        next.append_raw(&[0x11; 64], 512).unwrap();
+
+        let mut root = ton_types::BuilderData::new();
+        root.append_raw(&[0x22; 96], 768).unwrap();
+        root.checked_append_reference(next.into_cell().unwrap())
+            .unwrap();
+
+        base64::encode(ton_types::serialize_toc(&root.into_cell().unwrap()).unwrap())
+    }
+
+    fn unpack_ex(
+        params: &str,
+        boc: &str,
+        allow_partial: bool,
+        as_slice: bool,
+    ) -> serde_json::Value {
+        let (params, boc) = (c_string(params), c_string(boc));
+
+        call_ffi(|| unsafe {
+            nt_unpack_from_cell_ex(
+                params.as_ptr() as *mut c_char,
+                boc.as_ptr() as *mut c_char,
+                allow_partial as c_uint,
+                as_slice as c_uint,
+            )
+        })
+    }
+
+    #[test]
+    fn function_ids_match_multisig_selectors() {
+        let contract_abi = r#"{
+            "ABI version": 2,
+            "header": ["pubkey", "time", "expire"],
+            "functions": [
+                {
+                    "name": "sendTransaction",
+                    "inputs": [
+                        { "name": "dest", "type": "address" },
+                        { "name": "value", "type": "uint128" },
+                        { "name": "bounce", "type": "bool" },
+                        { "name": "flags", "type": "uint8" },
+                        { "name": "payload", "type": "cell" }
+                    ],
+                    "outputs": []
+                },
+                {
+                    "name": "submitTransaction",
+                    "inputs": [
+                        { "name": "dest", "type": "address" },
+                        { "name": "value", "type": "uint128" },
+                        { "name": "bounce", "type": "bool" },
+                        { "name": "allBalance", "type": "bool" },
+                        { "name": "payload", "type": "cell" }
+                    ],
+                    "outputs": [{ "name": "transId", "type": "uint64" }]
+                }
+            ],
+            "events": []
+        }"#;
+
+        let function_id = |method: &str| {
+            let (abi, method) = (c_string(contract_abi), c_string(method));
+
+            call_ffi(|| unsafe {
+                nt_get_function_id(abi.as_ptr() as *mut c_char, method.as_ptr() as *mut c_char)
+            })
+        };
+
+        // Selectors which explorers show for multisig wallet calls
+        let send_transaction = function_id("sendTransaction");
+        assert_eq!(send_transaction["data"]["inputId"], 0x4cee646c);
+        assert_eq!(send_transaction["data"]["outputId"], 0xccee646c_u32);
+
+        let submit_transaction = function_id("submitTransaction");
+        assert_eq!(submit_transaction["data"]["inputId"], 0x131d82cd);
+        assert_eq!(submit_transaction["data"]["outputId"], 0x931d82cd_u32);
+    }
+
+    #[test]
+    fn decode_input_reports_trailing_data() {
+        let contract_abi = parse_contract_abi(TEST_ABI).unwrap();
+        let method = contract_abi.function("setValue").unwrap();
+
+        let body = method.encode_internal_input(&test_input(method)).unwrap();
+
+        let mut extended_body = body.clone();
+        extended_body.append_u8(0xff).unwrap();
+
+        let extended_body = ton_types::SliceData::from(extended_body.into_cell().unwrap());
+        assert_eq!(
+            measure_trailing_data(method, extended_body, true).unwrap(),
+            (8, 0)
+        );
+
+        let body = base64::encode(ton_types::serialize_toc(&body.into_cell().unwrap()).unwrap());
+        let (body, abi, method) = (
+            c_string(&body),
+            c_string(TEST_ABI),
+            c_string("\"setValue\""),
+        );
+
+        let result = call_ffi(|| unsafe {
+            nt_decode_input(
+                body.as_ptr() as *mut c_char,
+                abi.as_ptr() as *mut c_char,
+                method.as_ptr() as *mut c_char,
+                1,
+                0,
+                0,
+            )
+        });
+
+        assert_eq!(result["type"], "ok");
+        assert_eq!(result["data"]["matched"], true);
+        assert_eq!(result["data"]["trailingBits"], 0);
+        assert_eq!(result["data"]["trailingRefs"], 0);
+    }
+
+    #[test]
+    fn raw_fallback_accepts_hex_boc() {
+        let mut body = ton_types::BuilderData::new();
+        body.append_u32(0x12345678).unwrap();
+        body.append_u8(0xab).unwrap();
+
+        let body = hex::encode(ton_types::serialize_toc(&body.into_cell().unwrap()).unwrap());
+        let (body, abi, method) = (
+            c_string(&body),
+            c_string(TEST_ABI),
+            c_string("\"setValue\""),
+        );
+
+        let result = call_ffi(|| unsafe {
+            nt_decode_input(
+                body.as_ptr() as *mut c_char,
+                abi.as_ptr() as *mut c_char,
+                method.as_ptr() as *mut c_char,
+                1,
+                1,
+                0,
+            )
+        });
+
+        assert_eq!(result["type"], "ok", "{}", result["data"]);
+        assert_eq!(result["data"]["matched"], false);
+        assert_eq!(result["data"]["functionId"], "0x12345678");
+        assert_eq!(result["data"]["rawHex"], "12345678ab");
+    }
+
+    #[test]
+    fn decode_input_reads_external_header() {
+        let contract_abi = parse_contract_abi(TEST_ABI).unwrap();
+        let method = contract_abi.function("setValue").unwrap();
+
+        let unsigned_body =
+            make_unsigned_body(method, &test_input(method), Some(test_keypair().public), 60)
+                .unwrap();
+        let body = unsigned_body.body.into_cell().unwrap();
+        let body = base64::encode(ton_types::serialize_toc(&body).unwrap());
+
+        let decode = |internal: bool| {
+            let (body, abi, method) = (
+                c_string(&body),
+                c_string(TEST_ABI),
+                c_string("\"setValue\""),
+            );
+
+            call_ffi(|| unsafe {
+                nt_decode_input(
+                    body.as_ptr() as *mut c_char,
+                    abi.as_ptr() as *mut c_char,
+                    method.as_ptr() as *mut c_char,
+                    internal as c_uint,
+                    1,
+                    1,
+                )
+            })
+        };
+
+        let external = decode(false);
+        assert_eq!(external["type"], "ok", "{}", external["data"]);
+        assert_eq!(external["data"]["matched"], true);
+        assert_eq!(external["data"]["input"]["value"], "42");
+
+        // Read as an internal body, the signature and the header are taken for the function id
+        let internal = decode(true);
+        assert_eq!(internal["type"], "ok", "{}", internal["data"]);
+        assert_eq!(internal["data"]["matched"], false);
+    }
+
+    const OUT_MESSAGES_ABI: &str = r#"{
+        "ABI version": 2,
+        "version": "2.2",
+        "header": ["time", "expire"],
+        "functions": [
+            {
+                "name": "setValue",
+                "inputs": [{ "name": "value", "type": "uint32" }],
+                "outputs": []
+            }
+        ],
+        "data": [],
+        "events": [
+            {
+                "name": "ValueChanged",
+                "inputs": [{ "name": "value", "type": "uint32" }]
+            }
+        ],
+        "fields": []
+    }"#;
+
+    fn out_message(
+        dst: Option<&str>,
+        body: ton_types::BuilderData,
+    ) -> nekoton::core::models::Message {
+        let body = body.into_cell().unwrap();
+
+        serde_json::from_value(serde_json::json!({
+            "hash": ton_types::UInt256::default().to_hex_string(),
+            "src": format!("0:{}", "22".repeat(32)),
+            "dst": dst,
+            "value": "1000000000",
+            "bounce": dst.is_some(),
+            "bounced": false,
+            "body": base64::encode(ton_types::serialize_toc(&body).unwrap()),
+            "bodyHash": body.repr_hash().to_hex_string(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn internal_out_message_is_decoded_as_call() {
+        let contract_abi = parse_contract_abi(OUT_MESSAGES_ABI).unwrap();
+        let method = contract_abi.function("setValue").unwrap();
+
+        let dst = format!("0:{}", "33".repeat(32));
+        let body = method.encode_internal_input(&test_input(method)).unwrap();
+
+        let decoded = decode_out_message(&contract_abi, &out_message(Some(&dst), body));
+        let decoded = serde_json::to_value(&decoded).unwrap();
+
+        assert_eq!(decoded["dst"], dst);
+        assert_eq!(decoded["value"], "1000000000");
+        assert_eq!(decoded["bounce"], true);
+        assert_eq!(
+            decoded["bodyFunctionId"],
+            format!("0x{:08x}", method.input_id)
+        );
+        assert_eq!(decoded["decoded"]["type"], "call");
+        assert_eq!(decoded["decoded"]["data"]["method"], "setValue");
+        assert_eq!(decoded["decoded"]["data"]["input"]["value"], "42");
+    }
+
+    #[test]
+    fn external_out_message_is_decoded_as_event() {
+        let contract_abi = parse_contract_abi(OUT_MESSAGES_ABI).unwrap();
+        let event = contract_abi.event("ValueChanged").unwrap();
+
+        let mut body = ton_types::BuilderData::new();
+        body.append_u32(event.id).unwrap();
+        body.append_u32(42).unwrap();
+
+        let decoded = decode_out_message(&contract_abi, &out_message(None, body));
+        let decoded = serde_json::to_value(&decoded).unwrap();
+
+        assert!(decoded["dst"].is_null());
+        assert_eq!(decoded["decoded"]["type"], "event");
+        assert_eq!(decoded["decoded"]["data"]["event"], "ValueChanged");
+        assert_eq!(decoded["decoded"]["data"]["data"]["value"], "42");
+    }
+
+    #[test]
+    fn unknown_out_message_body_is_left_raw() {
+        let contract_abi = parse_contract_abi(OUT_MESSAGES_ABI).unwrap();
+
+        let mut body = ton_types::BuilderData::new();
+        body.append_u32(0x12345678).unwrap();
+
+        let decoded = decode_out_message(&contract_abi, &out_message(None, body));
+        let decoded = serde_json::to_value(&decoded).unwrap();
+
+        assert_eq!(decoded["bodyFunctionId"], "0x12345678");
+        assert!(decoded["decoded"].is_null());
+    }
+
+    #[test]
+    fn unpack_ex_reports_continuation_cell() {
+        let result = unpack_ex(&uint256_params(5), &chained_boc(), false, false);
+
+        assert_eq!(result["type"], "ok");
+        assert_eq!(result["data"]["cellDepth"], 1);
+        assert_eq!(result["data"]["bitsConsumed"], 768 + 512);
+        assert_eq!(result["data"]["refsConsumed"], 1);
+    }
+
+    #[test]
+    fn unpack_ex_reports_remaining_root_data() {
+        let result = unpack_ex(&uint256_params(2), &chained_boc(), true, false);
+
+        assert_eq!(result["type"], "ok");
+        assert_eq!(result["data"]["cellDepth"], 0);
+        assert_eq!(result["data"]["bitsConsumed"], 512);
+        assert_eq!(result["data"]["refsConsumed"], 0);
+    }
+
+    #[test]
+    fn unpack_ex_rejects_trailing_data_unless_partial() {
+        let result = unpack_ex(&uint256_params(2), &chained_boc(), false, false);
+        assert_eq!(result["type"], "err");
+
+        let result = unpack_ex(&uint256_params(2), &chained_boc(), true, false);
+        assert_eq!(result["type"], "ok");
+    }
+
+    fn unpack(params: &str, boc: &str, allow_partial: bool, as_slice: bool) -> serde_json::Value {
+        let (params, boc) = (c_string(params), c_string(boc));
+
+        call_ffi(|| unsafe {
+            nt_unpack_from_cell(
+                params.as_ptr() as *mut c_char,
+                boc.as_ptr() as *mut c_char,
+                allow_partial as c_uint,
+                as_slice as c_uint,
+            )
+        })
+    }
+
+    #[test]
+    fn unpack_skips_function_id_as_slice() {
+        let mut body = ton_types::BuilderData::new();
+        body.append_u32(0x12345678).unwrap();
+        body.append_raw(&[0x33; 32], 256).unwrap();
+        body.append_u8(0).unwrap();
+        let body = base64::encode(ton_types::serialize_toc(&body.into_cell().unwrap()).unwrap());
+
+        // Trailing bits after the value still fail strict decoding of a slice
+        let result = unpack(&uint256_params(1), &body, false, true);
+        assert_eq!(result["type"], "err");
+
+        let value = BigUint::from_bytes_be(&[0x33; 32]).to_string();
+
+        let result = unpack(&uint256_params(1), &body, true, true);
+        assert_eq!(result["type"], "ok");
+        assert_eq!(result["data"]["value0"], value);
+
+        // Without skipping the id the value is read from the start of the cell
+        let result = unpack(&uint256_params(1), &body, true, false);
+        assert_eq!(result["type"], "ok");
+        assert_ne!(result["data"]["value0"], value);
+    }
+
+    #[test]
+    fn unpack_ex_skips_function_id_as_slice() {
+        let mut body = ton_types::BuilderData::new();
+        body.append_u32(0x12345678).unwrap();
+        body.append_raw(&[0x33; 32], 256).unwrap();
+        let body = base64::encode(ton_types::serialize_toc(&body.into_cell().unwrap()).unwrap());
+
+        let result = unpack_ex(&uint256_params(1), &body, false, true);
+        assert_eq!(result["type"], "ok");
+        assert_eq!(result["data"]["bitsConsumed"], 32 + 256);
+
+        // Without skipping the id the value doesn't end on the cell boundary
+        let result = unpack_ex(&uint256_params(1), &body, false, false);
+        assert_eq!(result["type"], "err");
+
+        let result = unpack_ex(&uint256_params(1), &body, true, false);
+        assert_eq!(result["data"]["bitsConsumed"], 256);
+    }
+
+    #[test]
+    fn bridge_burn_payload_schema() {
+        let payload = ExtraKnownPayload::BridgeBurn(BridgeBurnPayload {
+            evm_address: "0x00".to_owned(),
+            chain_id: 1,
+        });
+
+        assert_eq!(
+            serde_json::to_value(&payload).unwrap(),
+            serde_json::json!({
+                "type": "bridge_burn",
+                "data": { "evmAddress": "0x00", "chainId": 1 },
+            })
+        );
+    }
+
+    #[test]
+    fn libraries_map_checks_hashes() {
+        let mut library = ton_types::BuilderData::new();
+        library.append_u32(42).unwrap();
+        let library = library.into_cell().unwrap();
+
+        let boc = base64::encode(ton_types::serialize_toc(&library).unwrap());
+        let hash = library.repr_hash().to_hex_string();
+
+        let libraries = parse_libraries_map(&serde_json::json!({ hash: boc }).to_string()).unwrap();
+        assert_eq!(libraries, [library]);
+
+        let wrong_hash = ton_types::UInt256::default().to_hex_string();
+        let libraries = serde_json::json!({ wrong_hash: boc }).to_string();
+        assert!(parse_libraries_map(&libraries).is_err());
+    }
+
+    fn run_local(account_stuff: &ton_block::AccountStuff, timestamp: u32, lt: u64) -> String {
+        let account_stuff =
+            base64::encode(ton_types::serialize_toc(&account_stuff.serialize().unwrap()).unwrap());
+
+        let (account_stuff, contract_abi) = (c_string(&account_stuff), c_string(TEST_ABI));
+        let (method, input) = (c_string("setValue"), c_string(r#"{"value":42}"#));
+
+        let result = call_ffi(|| unsafe {
+            nt_run_local(
+                account_stuff.as_ptr() as *mut c_char,
+                contract_abi.as_ptr() as *mut c_char,
+                method.as_ptr() as *mut c_char,
+                input.as_ptr() as *mut c_char,
+                0,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                timestamp,
+                lt,
+                0,
+            )
+        });
+
+        assert_eq!(result["type"], "err");
+
+        result["data"].as_str().unwrap().to_owned()
+    }
+
+    #[test]
+    fn lt_override_requires_blockchain_config() {
+        let account_stuff = ton_block::AccountStuff::default();
+
+        assert!(run_local(&account_stuff, 0, 100).starts_with("Blockchain config is required"));
+        assert!(!run_local(&account_stuff, 1_700_000_000, 0).starts_with("Blockchain config"));
+    }
+
+    const GETTER_ABI: &str = r#"{
+        "ABI version": 2,
+        "version": "2.2",
+        "header": ["time", "expire", "pubkey"],
+        "functions": [
+            {
+                "name": "getNow",
+                "inputs": [],
+                "outputs": [{ "name": "now", "type": "uint32" }]
+            },
+            {
+                "name": "ping",
+                "inputs": [],
+                "outputs": []
+            }
+        ],
+        "data": [],
+        "events": [],
+        "fields": []
+    }"#;
+
+    /// Account which answers any external call with the output id of `getNow` and the current
+    /// time, ignoring the ABI dispatch
+    fn now_getter_account() -> ton_block::AccountStuff {
+        let contract_abi = parse_contract_abi(GETTER_ABI).unwrap();
+        let output_id = contract_abi.function("getNow").unwrap().output_id;
+
+        let mut code = ton_types::BuilderData::new();
+        // PUSHINT output_id, NEWC, STU 32
+        code.append_u8(0x82).unwrap();
+        code.append_bits(2, 5).unwrap();
+        code.append_bits(output_id as usize, 35).unwrap();
+        code.append_raw(&[0xc8, 0xcb, 0x1f], 24).unwrap();
+        // NOW, SWAP, STU 32, ENDC
+        code.append_raw(&[0xf8, 0x23, 0x01, 0xcb, 0x1f, 0xc9], 48)
+            .unwrap();
+        // Header of an external outbound message without addresses and with the body in a ref:
+        // PUSHINT 0b110000, NEWC, STU 6, PUSHINT 1, SWAP, STU 98, STREF, ENDC
+        code.append_raw(
+            &[
+                0x80, 0x30, 0xc8, 0xcb, 0x05, 0x71, 0x01, 0xcb, 0x61, 0xcc, 0xc9,
+            ],
+            88,
+        )
+        .unwrap();
+        // PUSHINT 0, SENDRAWMSG
+        code.append_raw(&[0x70, 0xfb, 0x00], 24).unwrap();
+
+        account_with_code(code.into_cell().unwrap())
+    }
+
+    fn account_with_code(code: ton_types::Cell) -> ton_block::AccountStuff {
+        let state_init = ton_block::StateInit {
+            code: Some(code),
+            data: Some(ton_types::Cell::default()),
+            ..Default::default()
+        };
+
+        let mut account_stuff = ton_block::AccountStuff {
+            addr: MsgAddressInt::from_str(&format!("0:{}", "33".repeat(32))).unwrap(),
+            ..Default::default()
+        };
+        account_stuff.storage.balance = ton_block::CurrencyCollection::with_grams(1_000_000_000);
+        account_stuff.storage.state = ton_block::AccountState::AccountActive { state_init };
+
+        account_stuff
+    }
+
+    fn run_getter(
+        account_stuff: &ton_block::AccountStuff,
+        method: &str,
+        timestamp: u32,
+    ) -> serde_json::Value {
+        let account_stuff =
+            base64::encode(ton_types::serialize_toc(&account_stuff.serialize().unwrap()).unwrap());
+
+        let (account_stuff, contract_abi) = (c_string(&account_stuff), c_string(GETTER_ABI));
+        let (method, input) = (c_string(method), c_string("{}"));
+
+        let result = call_ffi(|| unsafe {
+            nt_run_local(
+                account_stuff.as_ptr() as *mut c_char,
+                contract_abi.as_ptr() as *mut c_char,
+                method.as_ptr() as *mut c_char,
+                input.as_ptr() as *mut c_char,
+                0,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                timestamp,
+                0,
+                1,
+            )
+        });
+
+        assert_eq!(result["type"], "ok", "{}", result["data"]);
+
+        result["data"].clone()
+    }
+
+    fn get_now(account_stuff: &ton_block::AccountStuff, timestamp: u32) -> serde_json::Value {
+        let execution_output = run_getter(account_stuff, "getNow", timestamp);
+        assert_eq!(execution_output["context"]["now"], timestamp);
+
+        execution_output["output"]["now"].clone()
+    }
+
+    #[test]
+    fn timestamp_override_changes_getter_result() {
+        let account_stuff = now_getter_account();
+
+        let earlier = get_now(&account_stuff, 1_600_000_000);
+        let later = get_now(&account_stuff, 1_700_000_000);
+
+        assert_ne!(earlier, later);
+        assert_eq!(earlier.to_string().trim_matches('"'), "1600000000");
+        assert_eq!(later.to_string().trim_matches('"'), "1700000000");
+    }
+
+    fn run_responsible(blockchain_config_boc: Option<&str>) -> serde_json::Value {
+        let account_stuff = account_with_code(ton_types::Cell::default());
+        let account_stuff =
+            base64::encode(ton_types::serialize_toc(&account_stuff.serialize().unwrap()).unwrap());
+
+        let (account_stuff, contract_abi) = (c_string(&account_stuff), c_string(GETTER_ABI));
+        let (method, input) = (c_string("getNow"), c_string("{}"));
+        let blockchain_config_boc = blockchain_config_boc.map(c_string);
+
+        call_ffi(|| unsafe {
+            nt_run_local_responsible_value(
+                account_stuff.as_ptr() as *mut c_char,
+                contract_abi.as_ptr() as *mut c_char,
+                method.as_ptr() as *mut c_char,
+                input.as_ptr() as *mut c_char,
+                blockchain_config_boc
+                    .as_ref()
+                    .map(|e| e.as_ptr() as *mut c_char)
+                    .unwrap_or(std::ptr::null_mut()),
+            )
+        })
+    }
+
+    #[test]
+    fn responsible_value_uses_given_config() {
+        // Empty code returns without an answer, so there is no value either
+        let result = run_responsible(None);
+        assert_eq!(result["type"], "ok", "{}", result["data"]);
+        assert!(result["data"]["responsibleValue"].is_null());
+
+        let result = run_responsible(Some("invalid"));
+        assert_eq!(result["type"], "err");
+    }
+
+    #[test]
+    fn getter_without_outputs_gives_empty_object() {
+        // Empty code returns right away with exit code 0
+        let account_stuff = account_with_code(ton_types::Cell::default());
+
+        let execution_output = run_getter(&account_stuff, "ping", 0);
+
+        assert_eq!(execution_output["code"], 0);
+        assert_eq!(execution_output["output"], serde_json::json!({}));
+    }
+
+    #[test]
+    fn failed_getter_without_outputs_gives_null() {
+        // THROW 42
+        let mut code = ton_types::BuilderData::new();
+        code.append_raw(&[0xf2, 0x2a], 16).unwrap();
+        let account_stuff = account_with_code(code.into_cell().unwrap());
+
+        let execution_output = run_getter(&account_stuff, "ping", 0);
+
+        assert_eq!(execution_output["code"], 42);
+        assert!(execution_output["output"].is_null());
+    }
+
+    /// State init with a dummy code and an empty data dictionary
+    fn test_tvc() -> String {
+        let mut code = ton_types::BuilderData::new();
+        code.append_u32(0xdeadbeef).unwrap();
+
+        let mut data = ton_types::BuilderData::new();
+        data.append_bit_zero().unwrap();
+
+        let state_init = ton_block::StateInit {
+            code: Some(code.into_cell().unwrap()),
+            data: Some(data.into_cell().unwrap()),
+            ..Default::default()
+        };
+
+        base64::encode(ton_types::serialize_toc(&state_init.serialize().unwrap()).unwrap())
+    }
+
+    fn expected_address(tvc: &str, public_key: Option<&str>) -> serde_json::Value {
+        let (tvc, contract_abi) = (c_string(tvc), c_string(TEST_ABI));
+        let public_key = public_key.map(c_string);
+
+        let result = call_ffi(|| unsafe {
+            nt_get_expected_address(
+                tvc.as_ptr() as *mut c_char,
+                contract_abi.as_ptr() as *mut c_char,
+                0,
+                public_key
+                    .as_ref()
+                    .map(|e| e.as_ptr() as *mut c_char)
+                    .unwrap_or(std::ptr::null_mut()),
+                std::ptr::null_mut(),
+            )
+        });
+
+        assert_eq!(result["type"], "ok", "{}", result["data"]);
+
+        result["data"].clone()
+    }
+
+    #[test]
+    fn expected_address_matches_state_init_hash() {
+        let expected = expected_address(&test_tvc(), None);
+
+        let state_init =
+            ton_block::StateInit::construct_from_base64(expected["stateInit"].as_str().unwrap())
+                .unwrap();
+        let hash = state_init.serialize().unwrap().repr_hash().to_hex_string();
+
+        assert_eq!(expected["hash"], hash);
+        assert_eq!(expected["address"], format!("0:{}", hash));
+    }
+
+    #[test]
+    fn expected_address_without_abi_data() {
+        let public_key = hex::encode(test_keypair().public.as_bytes());
+
+        let first = expected_address(&test_tvc(), Some(&public_key));
+        let second = expected_address(&test_tvc(), Some(&public_key));
+        assert_eq!(first, second);
+
+        let without_public_key = expected_address(&test_tvc(), None);
+        assert_ne!(first["address"], without_public_key["address"]);
+    }
+
+    /// Minimal code cell with the new selector prefix, as the compiler emits it
+    fn selector_code_boc() -> String {
+        const NEW_SELECTOR_DATA: [u8; 18] = [
+            0x8a, 0xed, 0x53, 0x20, 0xe3, 0x03, 0x20, 0xc0, 0xff, 0xe3, 0x02, 0x20, 0xc0, 0xfe,
+            0xe3, 0x02, 0xf2, 0x0b,
+        ];
+
+        let mut code = ton_types::BuilderData::new();
+        code.append_raw(&NEW_SELECTOR_DATA, NEW_SELECTOR_DATA.len() * 8)
+            .unwrap();
+        for _ in 0..2 {
+            code.checked_append_reference(ton_types::Cell::default())
+                .unwrap();
+        }
+
+        base64::encode(ton_types::serialize_toc(&code.into_cell().unwrap()).unwrap())
+    }
+
+    fn salt_boc(value: u32) -> String {
+        let mut salt = ton_types::BuilderData::new();
+        salt.append_u32(value).unwrap();
+
+        base64::encode(ton_types::serialize_toc(&salt.into_cell().unwrap()).unwrap())
+    }
+
+    fn set_salt(code: &str, salt: &str) -> String {
+        let (code, salt) = (c_string(code), c_string(salt));
+
+        let result = call_ffi(|| unsafe {
+            nt_set_code_salt(code.as_ptr() as *mut c_char, salt.as_ptr() as *mut c_char)
+        });
+
+        result["data"].as_str().unwrap().to_owned()
+    }
+
+    fn code_hash(code: &str) -> ton_types::UInt256 {
+        parse_cell(code).unwrap().repr_hash()
+    }
+
+    #[test]
+    fn code_salt_round_trip() {
+        let code = selector_code_boc();
+        let salt = salt_boc(42);
+
+        let salted_code = set_salt(&code, &salt);
+
+        let salted_code_ptr = c_string(&salted_code);
+        let result =
+            call_ffi(|| unsafe { nt_get_code_salt(salted_code_ptr.as_ptr() as *mut c_char) });
+        assert_eq!(
+            parse_cell(result["data"].as_str().unwrap())
+                .unwrap()
+                .repr_hash(),
+            parse_cell(&salt).unwrap().repr_hash()
+        );
+
+        assert_ne!(code_hash(&salted_code), code_hash(&code));
+        assert_eq!(code_hash(&set_salt(&code, &salt)), code_hash(&salted_code));
+        assert_ne!(
+            code_hash(&set_salt(&code, &salt_boc(43))),
+            code_hash(&salted_code)
+        );
+    }
+
+    #[test]
+    fn hex_and_base64_bocs_have_same_hash() {
+        let mut cell = ton_types::BuilderData::new();
+        cell.append_u32(0xdeadbeef).unwrap();
+        let cell = cell.into_cell().unwrap();
+
+        let boc = ton_types::serialize_toc(&cell).unwrap();
+
+        let boc_hash = |boc: String| {
+            let boc = c_string(&boc);
+            call_ffi(|| unsafe { nt_get_boc_hash(boc.as_ptr() as *mut c_char) })
+        };
+
+        let base64_hash = boc_hash(base64::encode(&boc));
+        let hex_hash = boc_hash(hex::encode(&boc));
+
+        assert_eq!(base64_hash["data"], cell.repr_hash().to_hex_string());
+        assert_eq!(base64_hash, hex_hash);
+    }
+
+    #[test]
+    fn nested_map_types_allow_whitespace() {
+        let expected = ton_abi::ParamType::Map(
+            Box::new(ton_abi::ParamType::Address),
+            Box::new(ton_abi::ParamType::Map(
+                Box::new(ton_abi::ParamType::Uint(8)),
+                Box::new(ton_abi::ParamType::Tuple(Vec::new())),
+            )),
+        );
+
+        for kind in [
+            "map(address,map(uint8,tuple))",
+            "map(address, map(uint8, tuple))",
+            " map( address ,  map( uint8 , tuple ) ) ",
+        ] {
+            assert_eq!(parse_param_type(kind, 0).unwrap(), expected, "{}", kind);
+        }
+
+        assert!(parse_param_type("map(tuple, uint8)", 0).is_err());
+    }
+
+    #[test]
+    fn nested_fixedbytes_round_trip() {
+        let params = serde_json::json!([
+            { "name": "hashes", "type": "fixedbytes32[]" },
+            { "name": "tags", "type": "map(address,fixedbytes16)" },
+            { "name": "nonce", "type": "optional(fixedbytes8)" },
+        ])
+        .to_string();
+
+        let address = format!("0:{}", "11".repeat(32));
+        let tokens = serde_json::json!({
+            "hashes": [base64::encode([0xaa; 32]), base64::encode([0xbb; 32])],
+            "tags": { address: base64::encode([0xcc; 16]) },
+            "nonce": base64::encode([0xdd; 8]),
+        })
+        .to_string();
+
+        let pack = |tokens: &str| {
+            let (params, tokens) = (c_string(&params), c_string(tokens));
+
+            let result = call_ffi(|| unsafe {
+                nt_pack_into_cell(
+                    params.as_ptr() as *mut c_char,
+                    tokens.as_ptr() as *mut c_char,
+                )
+            });
+            assert_eq!(result["type"], "ok", "{}", result["data"]);
+
+            result["data"].as_str().unwrap().to_owned()
+        };
+
+        let boc = pack(&tokens);
+
+        let (params_ptr, boc_ptr) = (c_string(&params), c_string(&boc));
+        let unpacked = call_ffi(|| unsafe {
+            nt_unpack_from_cell(
+                params_ptr.as_ptr() as *mut c_char,
+                boc_ptr.as_ptr() as *mut c_char,
+                0,
+            )
+        });
+        assert_eq!(unpacked["type"], "ok", "{}", unpacked["data"]);
+
+        let unpacked = &unpacked["data"];
+        assert_eq!(unpacked["hashes"].as_array().unwrap().len(), 2);
+        assert!(unpacked["tags"].get(&address).is_some());
+        assert!(!unpacked["nonce"].is_null());
+
+        assert_eq!(pack(&unpacked.to_string()), boc);
+    }
+
+    #[test]
+    fn bridge_burn_payload_round_trip() {
+        let evm_address = c_string("0x52908400098527886e0f7030069857d2e4169ee7");
+
+        let result = call_ffi(|| unsafe {
+            nt_build_bridge_burn_payload(evm_address.as_ptr() as *mut c_char, 56)
+        });
+
+        let payload = parse_slice(result["data"].as_str().unwrap()).unwrap();
+        let payload = parse_bridge_burn_payload(payload).unwrap();
+
+        assert_eq!(
+            payload.evm_address,
+            "0x52908400098527886e0f7030069857d2e4169ee7"
+        );
+        assert_eq!(payload.chain_id, 56);
+    }
+
+    #[test]
+    fn bridge_burn_payload_matches_proxy_layout() {
+        let evm_address = "0x00908400098527886e0f7030069857d2e4169ee7";
+
+        let evm_address_ptr = c_string(evm_address);
+        let result = call_ffi(|| unsafe {
+            nt_build_bridge_burn_payload(evm_address_ptr.as_ptr() as *mut c_char, 56)
+        });
+
+        // Same layout as `payload.toSlice().decode(uint160, uint32)` in the proxy
+        let mut payload = parse_slice(result["data"].as_str().unwrap()).unwrap();
+        assert_eq!(
+            payload.get_next_bytes(EVM_ADDRESS_LEN).unwrap(),
+            hex::decode(&evm_address[2..]).unwrap()
+        );
+        assert_eq!(payload.get_next_u32().unwrap(), 56);
+        assert_eq!(payload.remaining_bits(), 0);
+        assert_eq!(payload.remaining_references(), 0);
+
+        let payload = parse_slice(result["data"].as_str().unwrap()).unwrap();
+        let payload = parse_bridge_burn_payload(payload).unwrap();
+        assert_eq!(payload.evm_address, evm_address);
+    }
+
+    #[test]
+    fn other_cells_are_not_bridge_burn_payloads() {
+        let mut longer = ton_types::BuilderData::new();
+        longer
+            .append_raw(&[0x52; EVM_ADDRESS_LEN], EVM_ADDRESS_LEN * 8)
+            .unwrap();
+        longer.append_u32(56).unwrap();
+        longer.append_u8(0).unwrap();
+
+        let mut empty_address = ton_types::BuilderData::new();
+        empty_address
+            .append_raw(&[0; EVM_ADDRESS_LEN], EVM_ADDRESS_LEN * 8)
+            .unwrap();
+        empty_address.append_u32(56).unwrap();
+
+        for payload in [longer, empty_address] {
+            let payload = ton_types::SliceData::from(payload.into_cell().unwrap());
+            assert!(parse_bridge_burn_payload(payload).is_none());
+        }
+    }
+
+    const TRANSFER_ABI: &str = r#"{
+        "ABI version": 2,
+        "version": "2.2",
+        "header": ["pubkey", "time", "expire"],
+        "functions": [
+            {
+                "name": "transfer",
+                "inputs": [
+                    { "name": "amount", "type": "uint128" },
+                    { "name": "recipient", "type": "address" },
+                    { "name": "deployWalletValue", "type": "uint128" },
+                    { "name": "remainingGasTo", "type": "address" },
+                    { "name": "notify", "type": "bool" },
+                    { "name": "payload", "type": "cell" },
+                    { "name": "nonce", "type": "uint32" }
+                ],
+                "outputs": []
+            }
+        ],
+        "data": [],
+        "events": [],
+        "fields": []
+    }"#;
+
+    fn transfer_with_comment(notify: bool, comment: &str) -> serde_json::Value {
+        let contract_abi = c_string(TRANSFER_ABI);
+        let recipient = c_string(&format!("0:{}", "11".repeat(32)));
+        let amount = c_string("1000");
+        let deploy_wallet_value = c_string("100000000");
+        let remaining_gas_to = c_string(&format!("0:{}", "22".repeat(32)));
+        let comment = c_string(comment);
+
+        let result = call_ffi(|| unsafe {
+            nt_encode_transfer_with_comment(
+                contract_abi.as_ptr() as *mut c_char,
+                recipient.as_ptr() as *mut c_char,
+                amount.as_ptr() as *mut c_char,
+                deploy_wallet_value.as_ptr() as *mut c_char,
+                remaining_gas_to.as_ptr() as *mut c_char,
+                notify as c_uint,
+                comment.as_ptr() as *mut c_char,
+            )
+        });
+        assert_eq!(result["type"], "ok", "{}", result["data"]);
+
+        let contract_abi = parse_contract_abi(TRANSFER_ABI).unwrap();
+        let method = contract_abi.function("transfer").unwrap();
+
+        let body = parse_slice(result["data"].as_str().unwrap()).unwrap();
+        let input = method.decode_input(body, true).unwrap();
+
+        nekoton_abi::make_abi_tokens(&input).unwrap()
+    }
+
+    #[test]
+    fn transfer_with_comment_uses_given_params() {
+        let input = transfer_with_comment(false, "memo");
+
+        assert_eq!(input["amount"], "1000");
+        assert_eq!(input["deployWalletValue"], "100000000");
+        assert_eq!(input["notify"], false);
+        assert_eq!(input["nonce"], "0");
+
+        let payload = parse_cell(input["payload"].as_str().unwrap()).unwrap();
+        let comment = nekoton_abi::create_comment_payload("memo")
+            .unwrap()
+            .into_cell();
+        assert_eq!(payload, comment);
+    }
+
+    #[test]
+    fn transfer_with_long_comment_spans_cells() {
+        let comment = "a".repeat(300);
+        let input = transfer_with_comment(true, &comment);
+
+        assert_eq!(input["notify"], true);
+
+        let payload = parse_cell(input["payload"].as_str().unwrap()).unwrap();
+        assert!(payload.references_count() > 0);
+        assert_eq!(
+            payload,
+            nekoton_abi::create_comment_payload(&comment)
+                .unwrap()
+                .into_cell()
+        );
+    }
 }
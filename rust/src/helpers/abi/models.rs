@@ -1,5 +1,10 @@
+use std::collections::BTreeMap;
+
+use nekoton::crypto::SignedMessage;
 use serde::{Deserialize, Serialize};
 
+use crate::helpers::abi::value_types::ValueType;
+
 #[derive(Serialize, Deserialize)]
 pub struct AbiParam {
     pub name: String,
@@ -9,16 +14,164 @@ pub struct AbiParam {
 }
 
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ExecutionOutput {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Decoded outputs, `{}` for a getter without outputs and `null` when no answer was produced
     pub output: Option<serde_json::Value>,
+    /// TVM exit code of the compute phase, `0` and `1` mean success
     pub code: i32,
+    /// Only known when the call was executed by the local VM, i.e. with a blockchain config.
+    /// Nekoton getters, which `nt_run_local` uses by default, `nt_batch_run_local` and cached
+    /// results don't measure gas
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_used: Option<u64>,
+    pub context: ExecutionContext,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponsibleExecutionOutput {
+    #[serde(flatten)]
+    pub execution_output: ExecutionOutput,
+    pub responsible_value: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "type", content = "data")]
+pub enum LocalTransactionExecution {
+    #[serde(rename_all = "camelCase")]
+    Executed {
+        transaction: String,
+        account: String,
+        exit_code: Option<i32>,
+        aborted: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    NotAccepted {
+        exit_code: i32,
+    },
+    AccountNotExists,
+    AccountFrozen,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedTransaction {
+    pub exit_code: Option<i32>,
+    pub aborted: bool,
+    pub fees: String,
+    pub new_account_boc: String,
+    pub out_messages: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct PublicKeyValidation {
+    pub valid: bool,
+    pub reason: Option<PublicKeyRejection>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PublicKeyRejection {
+    NotHex,
+    WrongLength,
+    InvalidCurvePoint,
+}
+
+#[derive(Serialize)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+#[derive(Serialize)]
+pub struct CacheStatsOutput {
+    pub abi: CacheStats,
+    pub getters: CacheStats,
+}
+
+#[derive(Deserialize)]
+pub struct LocalCall {
+    pub method: String,
+    pub input: serde_json::Value,
+    #[serde(default)]
+    pub responsible: bool,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum LocalCallResult {
+    Output(ExecutionOutput),
+    Error { error: String },
+}
+
+/// Values placed into c7 during local execution
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionContext {
+    pub now: u32,
+    pub block_lt: String,
 }
 
 #[derive(Serialize)]
 pub struct DecodedInput {
     pub method: String,
     pub input: serde_json::Value,
+    #[serde(rename = "trailingBits")]
+    pub trailing_bits: usize,
+    #[serde(rename = "trailingRefs")]
+    pub trailing_refs: usize,
+    #[serde(rename = "valueTypes", skip_serializing_if = "BTreeMap::is_empty")]
+    pub value_types: BTreeMap<String, ValueType>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnpackedCell {
+    pub tokens: serde_json::Value,
+    /// Bits read from the root and continuation cells, including the function id skipped for
+    /// `as_slice`
+    pub bits_consumed: usize,
+    /// References read along the way, a continuation cell counts as one of its parent's
+    pub refs_consumed: usize,
+    /// How many continuation cells decoding went through, `0` if it stopped in the root
+    pub cell_depth: usize,
+}
+
+/// Decoded body tagged with `matched: true`, so that it shares the schema with [`UnmatchedBody`]
+#[derive(Serialize)]
+pub struct MatchedBody<T> {
+    pub matched: bool,
+    #[serde(flatten)]
+    pub body: T,
+}
+
+impl<T> MatchedBody<T> {
+    pub fn new(body: T) -> Self {
+        Self {
+            matched: true,
+            body,
+        }
+    }
+}
+
+/// Body is well-formed but doesn't belong to the requested function or event
+#[derive(Serialize)]
+pub struct UnmatchedBody {
+    pub matched: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RawDecodedInput {
+    pub matched: bool,
+    pub function_id: Option<String>,
+    /// Data of the root cell, refs are left out
+    pub raw_hex: String,
+    pub bounced: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_body_boc: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -38,6 +191,91 @@ pub struct DecodedTransaction {
     pub method: String,
     pub input: serde_json::Value,
     pub output: serde_json::Value,
+    #[serde(rename = "inMsgValue", skip_serializing_if = "Option::is_none")]
+    pub in_msg_value: Option<String>,
+    #[serde(rename = "inMsgSender", skip_serializing_if = "Option::is_none")]
+    pub in_msg_sender: Option<String>,
+    pub direction: TransactionDirection,
+    #[serde(rename = "outMessages")]
+    pub out_messages: Vec<DecodedOutMessage>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedTransactionWithState {
+    #[serde(flatten)]
+    pub decoded_transaction: DecodedTransaction,
+    pub total_fees: String,
+    pub aborted: bool,
+    pub destroyed: bool,
+    pub action_result_code: Option<i32>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedOutMessage {
+    pub dst: Option<String>,
+    pub value: String,
+    pub bounce: bool,
+    pub body_function_id: Option<String>,
+    /// Only set when the body matches a function or an event of the transaction ABI
+    pub decoded: Option<DecodedOutMessageBody>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum DecodedMessageBody {
+    Input(DecodedCall),
+    Output(DecodedOutput),
+    Event(DecodedEvent),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "type", content = "data")]
+pub enum DecodedOutMessageBody {
+    Call(DecodedCall),
+    Event(DecodedEvent),
+}
+
+#[derive(Serialize)]
+pub struct DecodedCall {
+    pub method: String,
+    pub input: serde_json::Value,
+}
+
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionDirection {
+    In,
+    Out,
+    #[serde(rename = "self")]
+    SelfTransfer,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SafeMultisigTransaction {
+    pub submission: MultisigSubmission,
+    pub confirmations: Vec<MultisigConfirmation>,
+    pub executed: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultisigSubmission {
+    pub transaction_id: serde_json::Value,
+    pub dest: serde_json::Value,
+    pub value: serde_json::Value,
+    pub bounce: serde_json::Value,
+    pub all_balance: serde_json::Value,
+    pub payload: serde_json::Value,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultisigConfirmation {
+    pub transaction_id: serde_json::Value,
+    pub signer_public_key: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -45,3 +283,81 @@ pub struct DecodedTransactionEvent {
     pub event: String,
     pub data: serde_json::Value,
 }
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedTransactionEventError {
+    pub event_name: String,
+    pub error: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "type", content = "data")]
+pub enum DecodedTransactionEventResult {
+    Decoded(DecodedTransactionEvent),
+    Error(DecodedTransactionEventError),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionIds {
+    pub input_id: u32,
+    pub output_id: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncodedExternalInput {
+    pub body: String,
+    pub expire_at: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpectedAddress {
+    pub address: String,
+    pub state_init: String,
+    pub hash: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsignedExternalMessage {
+    #[serde(flatten)]
+    pub signed_message: SignedMessage,
+    /// Only known for ABI 2.0-2.2, other versions are encoded without a signature layout
+    pub data_hash: Option<String>,
+    pub layout: Option<SignatureLayout>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignPayload {
+    pub hash: String,
+    pub body: String,
+    pub time: u64,
+    pub expire_at: u32,
+}
+
+/// Bit offsets inside the root cell of the message body
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureLayout {
+    pub signature_offset: usize,
+    pub signature_length: usize,
+    pub public_key_offset: Option<usize>,
+    pub public_key_length: Option<usize>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "data")]
+pub enum ExtraKnownPayload {
+    BridgeBurn(BridgeBurnPayload),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgeBurnPayload {
+    pub evm_address: String,
+    pub chain_id: u32,
+}
@@ -0,0 +1,411 @@
+use std::{
+    collections::HashMap,
+    sync::{atomic::AtomicU64, Arc},
+};
+
+use ton_abi::{Function, Token};
+use ton_block::{
+    Account, AccountState, AccountStuff, CommonMsgInfo, Deserializable, Message,
+    MsgAddressIntOrNone, OutAction, OutActions, Serializable, SimpleLib, StateInitLib,
+    TrComputePhase, TransactionDescr,
+};
+use ton_executor::{
+    BlockchainConfig, ExecuteParams, ExecutorError, OrdinaryTransactionExecutor,
+    TransactionExecutor,
+};
+use ton_types::{Cell, SliceData};
+use ton_vm::{
+    executor::{gas::gas_state::Gas, Engine},
+    smart_contract_info::SmartContractInfo,
+    stack::{savelist::SaveList, Stack, StackItem},
+};
+
+use crate::{
+    helpers::abi::models::{LocalTransactionExecution, SimulatedTransaction},
+    HandleError,
+};
+
+pub struct VmOutput {
+    pub messages: Vec<Message>,
+    pub exit_code: i32,
+    pub gas_used: u64,
+}
+
+pub struct LocalExecutionOutput {
+    pub tokens: Option<Vec<Token>>,
+    pub exit_code: i32,
+    pub gas_used: u64,
+    /// Value attached to the answer of a responsible call
+    pub responsible_value: Option<String>,
+}
+
+pub fn run_local(
+    function: &Function,
+    account_stuff: &AccountStuff,
+    input: &[Token],
+    responsible: bool,
+    utime: u32,
+    lt: u64,
+    config: &BlockchainConfig,
+    libraries: &[Cell],
+) -> Result<LocalExecutionOutput, String> {
+    let address = account_stuff.addr.to_owned();
+
+    let message = if responsible {
+        let body = function
+            .encode_input(&Default::default(), input, true, None)
+            .handle_error()?;
+
+        let mut message = Message::with_int_header(ton_block::InternalMessageHeader {
+            src: MsgAddressIntOrNone::Some(address.to_owned()),
+            dst: address,
+            ..Default::default()
+        });
+
+        message.set_body(body.into());
+        message
+    } else {
+        let mut header = HashMap::with_capacity(3);
+
+        header.insert(
+            "time".to_owned(),
+            ton_abi::TokenValue::Time(utime as u64 * 1000),
+        );
+        header.insert("expire".to_owned(), ton_abi::TokenValue::Expire(u32::MAX));
+        header.insert("pubkey".to_owned(), ton_abi::TokenValue::PublicKey(None));
+
+        let body = function
+            .encode_input(&header, input, false, None)
+            .handle_error()?;
+
+        let mut message = Message::with_ext_in_header(ton_block::ExternalInboundMessageHeader {
+            dst: address,
+            ..Default::default()
+        });
+
+        message.set_body(body.into());
+        message
+    };
+
+    let output = call_msg(utime, lt, account_stuff, &message, config, libraries)?;
+
+    if output.exit_code != 0 && output.exit_code != 1 {
+        return Ok(LocalExecutionOutput {
+            tokens: None,
+            exit_code: output.exit_code,
+            gas_used: output.gas_used,
+            responsible_value: None,
+        });
+    }
+
+    let mut responsible_value = None;
+
+    let tokens = if responsible {
+        let answer = output
+            .messages
+            .iter()
+            .find(|e| matches!(e.header(), CommonMsgInfo::IntMsgInfo(_)));
+
+        responsible_value = answer
+            .and_then(|e| e.get_value())
+            .map(|e| e.grams.0.to_string());
+
+        match answer.and_then(|e| e.body()) {
+            Some(body) => Some(function.decode_output(body, true).handle_error()?),
+            None => None,
+        }
+    } else {
+        let bodies = output
+            .messages
+            .iter()
+            .filter(|e| matches!(e.header(), CommonMsgInfo::ExtOutMsgInfo(_)))
+            .filter_map(|e| e.body())
+            .collect::<Vec<_>>();
+
+        Some(nekoton_abi::process_raw_outputs(&bodies, function).handle_error()?)
+    };
+
+    Ok(LocalExecutionOutput {
+        tokens,
+        exit_code: output.exit_code,
+        gas_used: output.gas_used,
+        responsible_value,
+    })
+}
+
+pub fn local_execution_lt(account_stuff: &AccountStuff) -> u64 {
+    account_stuff.storage.last_trans_lt + 1
+}
+
+pub fn call_msg(
+    utime: u32,
+    lt: u64,
+    account_stuff: &AccountStuff,
+    message: &Message,
+    config: &BlockchainConfig,
+    libraries: &[Cell],
+) -> Result<VmOutput, String> {
+    let state_init = match &account_stuff.storage.state {
+        ton_block::AccountState::AccountActive { state_init } => state_init,
+        _ => return Err("Account is not active").handle_error(),
+    };
+
+    let code = state_init
+        .code
+        .to_owned()
+        .ok_or("Account has no code")
+        .handle_error()?;
+    let data = state_init
+        .data
+        .to_owned()
+        .ok_or("Account has no data")
+        .handle_error()?;
+
+    let balance = account_stuff.storage.balance.grams.0;
+
+    let mut ctrls = SaveList::new();
+    ctrls.put(4, &mut StackItem::Cell(data)).handle_error()?;
+
+    let myself = account_stuff
+        .addr
+        .serialize()
+        .map(SliceData::from)
+        .handle_error()?;
+
+    let mut sci = SmartContractInfo::with_myself(myself);
+    *sci.block_lt_mut() = lt;
+    *sci.trans_lt_mut() = lt;
+    *sci.unix_time_mut() = utime;
+    *sci.balance_remaining_grams_mut() = balance;
+    *sci.balance_remaining_other_mut() = account_stuff.storage.balance.other_as_hashmap();
+    sci.set_config_params(config.raw_config().config_params.data().cloned());
+
+    ctrls
+        .put(7, &mut sci.into_temp_data_item())
+        .handle_error()?;
+
+    let function_selector = match message.header() {
+        CommonMsgInfo::IntMsgInfo(_) => ton_vm::int!(0),
+        _ => ton_vm::int!(-1),
+    };
+
+    let mut stack = Stack::new();
+    stack
+        .push(ton_vm::int!(balance))
+        .push(ton_vm::int!(0))
+        .push(StackItem::Cell(message.serialize().handle_error()?))
+        .push(StackItem::Slice(message.body().unwrap_or_default()))
+        .push(function_selector);
+
+    let gas_config = config.get_gas_config(account_stuff.addr.is_masterchain());
+    let gas_limit = gas_config.gas_limit as i64;
+    let gas = Gas::new(
+        gas_limit,
+        0,
+        gas_limit,
+        gas_config.get_real_gas_price() as i64,
+    );
+
+    let mut extra_libraries = StateInitLib::default();
+    for library in libraries {
+        extra_libraries
+            .set(
+                &library.repr_hash(),
+                &SimpleLib::new(library.to_owned(), true),
+            )
+            .handle_error()?;
+    }
+
+    let mut engine = Engine::with_capabilities(config.capabilites()).setup_with_libraries(
+        SliceData::from(code),
+        Some(ctrls),
+        Some(stack),
+        Some(gas),
+        vec![state_init.library.inner(), extra_libraries.inner()],
+    );
+
+    let (exit_code, success) = match engine.execute() {
+        Ok(exit_code) => (exit_code, true),
+        Err(err) => (ton_vm::error::tvm_exception_or_custom_code(&err), false),
+    };
+
+    let gas_used = engine.get_gas().get_gas_used().max(0) as u64;
+
+    let mut messages = Vec::new();
+
+    if success {
+        let actions = engine
+            .get_actions()
+            .as_cell()
+            .map(ToOwned::to_owned)
+            .handle_error()?;
+
+        let actions = OutActions::construct_from_cell(actions).handle_error()?;
+
+        for action in actions {
+            if let OutAction::SendMsg { out_msg, .. } = action {
+                messages.push(out_msg);
+            }
+        }
+    }
+
+    Ok(VmOutput {
+        messages,
+        exit_code,
+        gas_used,
+    })
+}
+
+/// Runs the full transaction executor, rejecting external messages which can't be accepted at all
+pub fn execute_transaction(
+    account: Account,
+    message: &Message,
+    config: BlockchainConfig,
+    utime: u32,
+    lt: u64,
+) -> Result<LocalTransactionExecution, String> {
+    if matches!(message.header(), CommonMsgInfo::ExtInMsgInfo(_)) {
+        match &account {
+            Account::AccountNone if message.state_init().is_none() => {
+                return Ok(LocalTransactionExecution::AccountNotExists)
+            },
+            Account::Account(account_stuff)
+                if matches!(
+                    account_stuff.storage.state,
+                    AccountState::AccountFrozen { .. }
+                ) =>
+            {
+                return Ok(LocalTransactionExecution::AccountFrozen)
+            },
+            _ => {},
+        }
+    }
+
+    let mut account_root = account.serialize().handle_error()?;
+
+    let transaction = match run_executor(&mut account_root, message, config, utime, lt) {
+        Ok(transaction) => transaction,
+        Err(err) => {
+            return match err.downcast_ref::<ExecutorError>() {
+                Some(ExecutorError::NoAcceptError(exit_code, _)) => {
+                    Ok(LocalTransactionExecution::NotAccepted {
+                        exit_code: *exit_code,
+                    })
+                },
+                _ => Err(err.to_string()),
+            }
+        },
+    };
+
+    let (exit_code, aborted) = read_compute_result(&transaction)?;
+
+    let transaction = transaction
+        .serialize()
+        .and_then(|e| ton_types::serialize_toc(&e))
+        .map(base64::encode)
+        .handle_error()?;
+
+    let account = ton_types::serialize_toc(&account_root)
+        .map(base64::encode)
+        .handle_error()?;
+
+    Ok(LocalTransactionExecution::Executed {
+        transaction,
+        account,
+        exit_code,
+        aborted,
+    })
+}
+
+/// Applies the message to the account, returning the new account state along with transaction
+/// fees and outbound messages
+pub fn simulate_transaction(
+    account_stuff: AccountStuff,
+    message: &Message,
+    config: BlockchainConfig,
+    utime: u32,
+) -> Result<SimulatedTransaction, String> {
+    let lt = local_execution_lt(&account_stuff);
+
+    let mut account_root = Account::Account(account_stuff).serialize().handle_error()?;
+
+    let transaction = match run_executor(&mut account_root, message, config, utime, lt) {
+        Ok(transaction) => transaction,
+        Err(err) => {
+            return match err.downcast_ref::<ExecutorError>() {
+                Some(ExecutorError::NoAcceptError(exit_code, _)) => {
+                    Err(format!("Message was not accepted, exit code {}", exit_code))
+                },
+                _ => Err(err.to_string()),
+            }
+        },
+    };
+
+    let (exit_code, aborted) = read_compute_result(&transaction)?;
+
+    let mut out_messages = Vec::new();
+    transaction
+        .iterate_out_msgs(|message| {
+            out_messages.push(message.serialize()?);
+            Ok(true)
+        })
+        .handle_error()?;
+
+    let out_messages = out_messages
+        .iter()
+        .map(|e| ton_types::serialize_toc(e).map(base64::encode))
+        .collect::<Result<Vec<_>, _>>()
+        .handle_error()?;
+
+    let new_account_boc = ton_types::serialize_toc(&account_root)
+        .map(base64::encode)
+        .handle_error()?;
+
+    Ok(SimulatedTransaction {
+        exit_code,
+        aborted,
+        fees: transaction.total_fees().grams.0.to_string(),
+        new_account_boc,
+        out_messages,
+    })
+}
+
+fn run_executor(
+    account_root: &mut Cell,
+    message: &Message,
+    config: BlockchainConfig,
+    utime: u32,
+    lt: u64,
+) -> ton_types::Result<ton_block::Transaction> {
+    let params = ExecuteParams {
+        block_unixtime: utime,
+        block_lt: lt,
+        last_tr_lt: Arc::new(AtomicU64::new(lt)),
+        ..Default::default()
+    };
+
+    OrdinaryTransactionExecutor::new(config).execute_with_libs_and_params(
+        Some(message),
+        account_root,
+        params,
+    )
+}
+
+/// Exit code of the compute phase, `None` when it was skipped, and the aborted flag
+fn read_compute_result(
+    transaction: &ton_block::Transaction,
+) -> Result<(Option<i32>, bool), String> {
+    let result = match transaction.read_description().handle_error()? {
+        TransactionDescr::Ordinary(description) => {
+            let exit_code = match description.compute_ph {
+                TrComputePhase::Vm(phase) => Some(phase.exit_code),
+                TrComputePhase::Skipped(_) => None,
+            };
+
+            (exit_code, description.aborted)
+        },
+        _ => (None, true),
+    };
+
+    Ok(result)
+}
@@ -0,0 +1,147 @@
+use serde::Serialize;
+use ton_abi::{Param, ParamType};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenMismatch {
+    pub path: String,
+    pub expected: String,
+    pub got: String,
+}
+
+/// Walks params together with JSON input and collects every value which can't be parsed
+pub fn validate_abi_tokens(params: &[Param], value: &serde_json::Value) -> Vec<TokenMismatch> {
+    let mut mismatches = Vec::new();
+
+    match value {
+        serde_json::Value::Object(object) => {
+            for param in params {
+                let value = object.get(&param.name).unwrap_or(&serde_json::Value::Null);
+
+                validate_token(&param.kind, value, param.name.to_owned(), &mut mismatches);
+            }
+        },
+        value => mismatches.push(TokenMismatch {
+            path: String::new(),
+            expected: "object".to_owned(),
+            got: json_type_name(value).to_owned(),
+        }),
+    }
+
+    mismatches
+}
+
+fn validate_token(
+    kind: &ParamType,
+    value: &serde_json::Value,
+    path: String,
+    mismatches: &mut Vec<TokenMismatch>,
+) {
+    match kind {
+        ParamType::Uint(_)
+        | ParamType::Int(_)
+        | ParamType::Token
+        | ParamType::Time
+        | ParamType::Expire => {
+            if !value.is_string() && !value.is_number() {
+                mismatches.push(make_mismatch(kind, value, path));
+            }
+        },
+        ParamType::Bool => {
+            if !value.is_boolean() {
+                mismatches.push(make_mismatch(kind, value, path));
+            }
+        },
+        ParamType::Cell
+        | ParamType::Address
+        | ParamType::Bytes
+        | ParamType::FixedBytes(_)
+        | ParamType::String => {
+            if !value.is_string() {
+                mismatches.push(make_mismatch(kind, value, path));
+            }
+        },
+        ParamType::PublicKey => {
+            if !value.is_string() && !value.is_null() {
+                mismatches.push(make_mismatch(kind, value, path));
+            }
+        },
+        ParamType::Optional(inner) => {
+            if !value.is_null() {
+                validate_token(inner, value, path, mismatches);
+            }
+        },
+        ParamType::Ref(inner) => validate_token(inner, value, path, mismatches),
+        ParamType::Tuple(components) => match value.as_object() {
+            Some(object) => {
+                for component in components {
+                    let value = object
+                        .get(&component.name)
+                        .unwrap_or(&serde_json::Value::Null);
+
+                    validate_token(
+                        &component.kind,
+                        value,
+                        format!("{}.{}", path, component.name),
+                        mismatches,
+                    );
+                }
+            },
+            None => mismatches.push(make_mismatch(kind, value, path)),
+        },
+        ParamType::Array(inner) => match value.as_array() {
+            Some(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    validate_token(inner, item, format!("{}[{}]", path, i), mismatches);
+                }
+            },
+            None => mismatches.push(make_mismatch(kind, value, path)),
+        },
+        ParamType::FixedArray(inner, len) => match value.as_array() {
+            Some(items) if items.len() == *len => {
+                for (i, item) in items.iter().enumerate() {
+                    validate_token(inner, item, format!("{}[{}]", path, i), mismatches);
+                }
+            },
+            _ => mismatches.push(make_mismatch(kind, value, path)),
+        },
+        ParamType::Map(key, inner) => match value.as_array() {
+            Some(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    match item.as_array().map(Vec::as_slice) {
+                        Some([k, v]) => {
+                            validate_token(key, k, format!("{}[{}][0]", path, i), mismatches);
+                            validate_token(inner, v, format!("{}[{}][1]", path, i), mismatches);
+                        },
+                        _ => mismatches.push(TokenMismatch {
+                            path: format!("{}[{}]", path, i),
+                            expected: "[key, value]".to_owned(),
+                            got: json_type_name(item).to_owned(),
+                        }),
+                    }
+                }
+            },
+            None => mismatches.push(make_mismatch(kind, value, path)),
+        },
+        _ => {},
+    }
+}
+
+fn make_mismatch(kind: &ParamType, value: &serde_json::Value, path: String) -> TokenMismatch {
+    TokenMismatch {
+        path,
+        expected: kind.type_signature(),
+        got: json_type_name(value).to_owned(),
+    }
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
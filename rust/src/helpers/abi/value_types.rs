@@ -0,0 +1,57 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use ton_abi::{Token, TokenValue};
+
+/// Value semantics which are lost once tokens are converted into plain JSON
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum ValueType {
+    Token,
+}
+
+/// Collects paths of all currency amounts, using the same path notation as input validation
+pub fn collect_value_types(tokens: &[Token]) -> BTreeMap<String, ValueType> {
+    let mut value_types = BTreeMap::new();
+
+    for token in tokens {
+        collect_value_type(&token.value, token.name.to_owned(), &mut value_types);
+    }
+
+    value_types
+}
+
+fn collect_value_type(
+    value: &TokenValue,
+    path: String,
+    value_types: &mut BTreeMap<String, ValueType>,
+) {
+    match value {
+        TokenValue::Token(_) => {
+            value_types.insert(path, ValueType::Token);
+        },
+        TokenValue::Optional(_, Some(inner)) | TokenValue::Ref(inner) => {
+            collect_value_type(inner, path, value_types)
+        },
+        TokenValue::Tuple(components) => {
+            for component in components {
+                collect_value_type(
+                    &component.value,
+                    format!("{}.{}", path, component.name),
+                    value_types,
+                );
+            }
+        },
+        TokenValue::Array(_, items) | TokenValue::FixedArray(_, items) => {
+            for (i, item) in items.iter().enumerate() {
+                collect_value_type(item, format!("{}[{}]", path, i), value_types);
+            }
+        },
+        TokenValue::Map(_, _, items) => {
+            for (i, item) in items.values().enumerate() {
+                collect_value_type(item, format!("{}[{}][1]", path, i), value_types);
+            }
+        },
+        _ => {},
+    }
+}
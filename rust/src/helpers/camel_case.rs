@@ -0,0 +1,76 @@
+use serde::Serialize;
+
+/// Serializes nekoton models which keep their snake_case field names, keys are renamed at any
+/// depth so the value must not contain maps keyed by data
+pub fn to_camel_case_value<T>(value: &T) -> serde_json::Result<serde_json::Value>
+where
+    T: Serialize,
+{
+    serde_json::to_value(value).map(rename_keys)
+}
+
+fn rename_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(object) => object
+            .into_iter()
+            .map(|(key, value)| (to_camel_case(&key), rename_keys(value)))
+            .collect(),
+        serde_json::Value::Array(array) => array.into_iter().map(rename_keys).collect(),
+        value => value,
+    }
+}
+
+fn to_camel_case(key: &str) -> String {
+    let mut output = String::with_capacity(key.len());
+    let mut upper = false;
+
+    for c in key.chars() {
+        match c {
+            '_' if !output.is_empty() => upper = true,
+            c if upper => {
+                output.extend(c.to_uppercase());
+                upper = false;
+            },
+            c => output.push(c),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renames_nested_keys() {
+        let value = serde_json::json!({
+            "type": "token_swap_back",
+            "data": {
+                "tokens": "10",
+                "callback_address": "0:00",
+                "callback_payload": "te6ccg",
+            },
+            "items": [{ "signs_required": 2, "expireAt": 1 }],
+        });
+
+        let expected = serde_json::json!({
+            "type": "token_swap_back",
+            "data": {
+                "tokens": "10",
+                "callbackAddress": "0:00",
+                "callbackPayload": "te6ccg",
+            },
+            "items": [{ "signsRequired": 2, "expireAt": 1 }],
+        });
+
+        assert_eq!(to_camel_case_value(&value).unwrap(), expected);
+    }
+
+    #[test]
+    fn keeps_camel_case_keys() {
+        assert_eq!(to_camel_case("minLt"), "minLt");
+        assert_eq!(to_camel_case("send_flags"), "sendFlags");
+        assert_eq!(to_camel_case("_private"), "_private");
+    }
+}
@@ -1,12 +1,27 @@
-mod abi;
+pub(crate) mod abi;
+pub(crate) mod camel_case;
 mod models;
 
-use std::os::raw::{c_char, c_uint};
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    os::raw::{c_char, c_uchar, c_uint},
+};
 
-use ton_block::{Deserializable, MaybeDeserialize, Serializable};
+use nekoton::core::ton_wallet::WalletType;
+use sha2::{Digest, Sha256};
+use ton_block::{Deserializable, MaybeDeserialize, MsgAddressInt, Serializable};
 
 use crate::{
-    helpers::models::SplittedTvc, parse_address, HandleError, MatchResult, ToStringFromPtr,
+    ffi_timer,
+    helpers::abi::{parse_boc, parse_cell, read_expire_at},
+    helpers::models::{
+        AbortedPhase, AccountCellsCount, AccountVisual, BlockInfo, BlockRef, CheckedAddress,
+        CreditPhase, ExtraCurrency, HslColor, JettonBurnNotification, MergedTvc, MessageBocType,
+        MessageBocValidation, MessageValue, ParsedMessage, SplittedTvc, StakesConfig,
+        TransactionAbortStatus, TransactionFees,
+    },
+    parse_address, HandleError, MatchResult, ToOptionalStringFromPtr, ToStringFromPtr,
 };
 
 #[no_mangle]
@@ -15,6 +30,8 @@ pub unsafe extern "C" fn nt_pack_std_smc_addr(
     addr: *mut c_char,
     bounceable: c_uint,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_pack_std_smc_addr");
+
     let addr = addr.to_string_from_ptr();
 
     fn internal_fn(
@@ -40,6 +57,8 @@ pub unsafe extern "C" fn nt_unpack_std_smc_addr(
     packed: *mut c_char,
     base64_url: c_uint,
 ) -> *mut c_char {
+    let _timer = ffi_timer!("nt_unpack_std_smc_addr");
+
     let packed = packed.to_string_from_ptr();
 
     fn internal_fn(packed: String, base64_url: u32) -> Result<serde_json::Value, String> {
@@ -57,6 +76,8 @@ pub unsafe extern "C" fn nt_unpack_std_smc_addr(
 
 #[no_mangle]
 pub unsafe extern "C" fn nt_validate_address(address: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_validate_address");
+
     let address = address.to_string_from_ptr();
 
     fn internal_fn(address: String) -> Result<serde_json::Value, String> {
@@ -69,22 +90,154 @@ pub unsafe extern "C" fn nt_validate_address(address: *mut c_char) -> *mut c_cha
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn nt_repack_address(address: *mut c_char) -> *mut c_char {
+pub unsafe extern "C" fn nt_check_address(address: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_check_address");
+
     let address = address.to_string_from_ptr();
 
     fn internal_fn(address: String) -> Result<serde_json::Value, String> {
-        let address = nekoton_utils::repack_address(&address)
-            .handle_error()?
-            .to_string();
+        let checked_address = match check_address(address.trim()) {
+            Ok((parsed, bounceable)) => CheckedAddress {
+                is_valid: true,
+                address: Some(parsed.to_string()),
+                workchain: Some(parsed.workchain_id() as i8),
+                bounceable,
+                error: None,
+            },
+            Err(err) => CheckedAddress {
+                is_valid: false,
+                address: None,
+                workchain: None,
+                bounceable: None,
+                error: Some(err),
+            },
+        };
+
+        serde_json::to_value(&checked_address).handle_error()
+    }
+
+    internal_fn(address).match_result()
+}
+
+/// Accepts raw `workchain:hex` and packed base64 forms, the flag is known only for the packed one
+fn check_address(address: &str) -> Result<(MsgAddressInt, Option<bool>), String> {
+    if address.is_empty() {
+        return Err("Address is empty".to_owned());
+    }
+
+    if address.contains(':') {
+        return parse_address(address)
+            .map(|address| (address, None))
+            .map_err(|err| format!("Invalid raw address: {}", err));
+    }
+
+    if address.len() != 48 {
+        return Err(format!(
+            "Invalid packed address length: expected 48 characters, got {}",
+            address.len()
+        ));
+    }
+
+    let bytes = base64::decode_config(address, base64::URL_SAFE)
+        .or_else(|_| base64::decode(address))
+        .map_err(|_| "Packed address is not a valid base64".to_owned())?;
+
+    let bounceable = match bytes.first().map(|e| e & 0x7f) {
+        Some(0x11) => true,
+        Some(0x51) => false,
+        _ => return Err("Unknown packed address flags".to_owned()),
+    };
+
+    let address = nekoton_utils::repack_address(address)
+        .map_err(|err| format!("Invalid packed address: {}", err))?;
+
+    Ok((address, Some(bounceable)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_repack_address(
+    address: *mut c_char,
+    workchain_id: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_repack_address");
+
+    let address = address.to_string_from_ptr();
+    let workchain_id = workchain_id.to_optional_string_from_ptr();
+
+    fn internal_fn(
+        address: String,
+        workchain_id: Option<String>,
+    ) -> Result<serde_json::Value, String> {
+        let (address, _) = check_address(address.trim())?;
+
+        let address = match workchain_id {
+            Some(workchain_id) => {
+                let workchain_id = workchain_id
+                    .parse::<i8>()
+                    .map_err(|_| format!("Invalid workchain id: {}", workchain_id))?;
+
+                MsgAddressInt::with_standart(None, workchain_id, address.address())
+                    .handle_error()?
+            },
+            None => address,
+        }
+        .to_string();
 
         serde_json::to_value(address).handle_error()
     }
 
+    internal_fn(address, workchain_id).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_account_visual_seed(address: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_account_visual_seed");
+
+    let address = address.to_string_from_ptr();
+
+    fn internal_fn(address: String) -> Result<serde_json::Value, String> {
+        let address = nekoton_utils::repack_address(&address).handle_error()?;
+
+        let seed = account_visual_seed(&address);
+
+        let color = HslColor {
+            hue: u16::from_be_bytes([seed[0], seed[1]]) % 360,
+            saturation: 45 + seed[2] % 40,
+            lightness: 40 + seed[3] % 25,
+        };
+
+        // Left three columns are taken from the seed, the right ones mirror them
+        let identicon = (0..5)
+            .map(|row| {
+                (0..5)
+                    .map(|column: usize| seed[4 + row * 3 + column.min(4 - column)] & 1 == 1)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let account_visual = AccountVisual {
+            seed: hex::encode(seed),
+            color,
+            identicon,
+        };
+
+        serde_json::to_value(&account_visual).handle_error()
+    }
+
     internal_fn(address).match_result()
 }
 
+/// Hash of the raw `workchain:hex` form, so every packing of the address gives the same seed
+pub fn account_visual_seed(address: &MsgAddressInt) -> [u8; 32] {
+    let mut seed = [0; 32];
+    seed.copy_from_slice(&Sha256::digest(address.to_string().as_bytes()));
+    seed
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_extract_public_key(boc: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_extract_public_key");
+
     let boc = boc.to_string_from_ptr();
 
     fn internal_fn(boc: String) -> Result<serde_json::Value, String> {
@@ -98,15 +251,56 @@ pub unsafe extern "C" fn nt_extract_public_key(boc: *mut c_char) -> *mut c_char
     internal_fn(boc).match_result()
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_ever_wallet_custodians(
+    account_stuff_boc: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_ever_wallet_custodians");
+
+    let account_stuff_boc = account_stuff_boc.to_string_from_ptr();
+
+    fn internal_fn(account_stuff_boc: String) -> Result<serde_json::Value, String> {
+        let account_stuff = parse_account_stuff(&account_stuff_boc)?;
+
+        let state_init = match account_stuff.storage.state {
+            ton_block::AccountState::AccountActive { state_init } => state_init,
+            _ => return Err("Account is not active").handle_error(),
+        };
+
+        let code_hash = state_init
+            .code
+            .as_ref()
+            .map(|e| e.repr_hash())
+            .ok_or("Account has no code")
+            .handle_error()?;
+
+        if code_hash.as_slice() != WalletType::EverWallet.code_hash() {
+            return Err("Account is not an EverWallet").handle_error();
+        }
+
+        let mut data: ton_types::SliceData = state_init
+            .data
+            .ok_or("Account has no data")
+            .handle_error()?
+            .into();
+
+        // EverWallet keeps its only owner key at the start of the data cell
+        let public_key = data.get_next_bytes(32).handle_error()?;
+
+        serde_json::to_value(vec![hex::encode(public_key)]).handle_error()
+    }
+
+    internal_fn(account_stuff_boc).match_result()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_code_to_tvc(code: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_code_to_tvc");
+
     let code = code.to_string_from_ptr();
 
     fn internal_fn(code: String) -> Result<serde_json::Value, String> {
-        let cell = base64::decode(code).handle_error()?;
-
-        let tvc = ton_types::deserialize_tree_of_cells(&mut cell.as_slice())
-            .handle_error()
+        let tvc = parse_cell(&code)
             .and_then(|e| nekoton_abi::code_to_tvc(e).handle_error())
             .and_then(|e| e.serialize().handle_error())
             .and_then(|e| ton_types::serialize_toc(&e).handle_error())
@@ -120,10 +314,12 @@ pub unsafe extern "C" fn nt_code_to_tvc(code: *mut c_char) -> *mut c_char {
 
 #[no_mangle]
 pub unsafe extern "C" fn nt_split_tvc(tvc: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_split_tvc");
+
     let tvc = tvc.to_string_from_ptr();
 
     fn internal_fn(tvc: String) -> Result<serde_json::Value, String> {
-        let state_init = ton_block::StateInit::construct_from_base64(&tvc).handle_error()?;
+        let state_init = parse_boc::<ton_block::StateInit>(&tvc)?;
 
         let data = match state_init.data {
             Some(data) => {
@@ -151,25 +347,877 @@ pub unsafe extern "C" fn nt_split_tvc(tvc: *mut c_char) -> *mut c_char {
     internal_fn(tvc).match_result()
 }
 
-fn parse_account_stuff(boc: &str) -> Result<ton_block::AccountStuff, String> {
-    let bytes = base64::decode(boc).handle_error()?;
-    ton_types::deserialize_tree_of_cells(&mut bytes.as_slice())
-        .and_then(|cell| {
-            let slice = &mut cell.into();
-            Ok(ton_block::AccountStuff {
-                addr: Deserializable::construct_from(slice)?,
-                storage_stat: Deserializable::construct_from(slice)?,
-                storage: ton_block::AccountStorage {
-                    last_trans_lt: Deserializable::construct_from(slice)?,
-                    balance: Deserializable::construct_from(slice)?,
-                    state: Deserializable::construct_from(slice)?,
-                    init_code_hash: if slice.remaining_bits() > 0 {
-                        ton_types::UInt256::read_maybe_from(slice)?
-                    } else {
+#[no_mangle]
+pub unsafe extern "C" fn nt_merge_tvc(code: *mut c_char, data: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_merge_tvc");
+
+    let code = code.to_optional_string_from_ptr();
+    let data = data.to_optional_string_from_ptr();
+
+    fn internal_fn(
+        code: Option<String>,
+        data: Option<String>,
+    ) -> Result<serde_json::Value, String> {
+        let state_init = ton_block::StateInit {
+            code: code.as_deref().map(parse_cell).transpose()?,
+            data: data.as_deref().map(parse_cell).transpose()?,
+            ..Default::default()
+        };
+
+        let cell = state_init.serialize().handle_error()?;
+
+        let tvc = MergedTvc {
+            tvc: base64::encode(ton_types::serialize_toc(&cell).handle_error()?),
+            hash: cell.repr_hash().to_hex_string(),
+        };
+
+        serde_json::to_value(&tvc).handle_error()
+    }
+
+    internal_fn(code, data).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_transaction_fees(transaction_boc: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_transaction_fees");
+
+    let transaction_boc = transaction_boc.to_string_from_ptr();
+
+    fn internal_fn(transaction_boc: String) -> Result<serde_json::Value, String> {
+        let (transaction, description) = parse_ordinary_transaction(&transaction_boc)?;
+
+        let total_fees = transaction.total_fees().grams.0.to_string();
+
+        // Phases are only known for ordinary transactions, components of other ones are zero
+        let (storage_fee, compute_fee, action_fee, forward_fee) = match description {
+            Some(description) => {
+                let compute_fee = match description.compute_ph {
+                    ton_block::TrComputePhase::Vm(phase) => phase.gas_fees.0,
+                    ton_block::TrComputePhase::Skipped(_) => 0,
+                };
+
+                let action = description.action.as_ref();
+
+                (
+                    description
+                        .storage_ph
+                        .map(|e| e.storage_fees_collected.0)
+                        .unwrap_or_default(),
+                    compute_fee,
+                    action
+                        .and_then(|e| e.total_action_fees.as_ref())
+                        .map(|e| e.0)
+                        .unwrap_or_default(),
+                    action
+                        .and_then(|e| e.total_fwd_fees.as_ref())
+                        .map(|e| e.0)
+                        .unwrap_or_default(),
+                )
+            },
+            None => Default::default(),
+        };
+
+        let transaction_fees = TransactionFees {
+            total_fees,
+            storage_fee: storage_fee.to_string(),
+            compute_fee: compute_fee.to_string(),
+            action_fee: action_fee.to_string(),
+            forward_fee: forward_fee.to_string(),
+        };
+
+        serde_json::to_value(&transaction_fees).handle_error()
+    }
+
+    internal_fn(transaction_boc).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_transaction_aborted(transaction: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_transaction_aborted");
+
+    let transaction = transaction.to_string_from_ptr();
+
+    fn internal_fn(transaction: String) -> Result<serde_json::Value, String> {
+        let value = serde_json::from_str::<serde_json::Value>(&transaction).handle_error()?;
+
+        let aborted = value
+            .get("aborted")
+            .and_then(serde_json::Value::as_bool)
+            .ok_or("Invalid transaction")
+            .handle_error()?;
+
+        let code = |key: &str| {
+            value
+                .get(key)
+                .and_then(serde_json::Value::as_i64)
+                .map(|e| e as i32)
+        };
+
+        let exit_code = code("exitCode");
+        let result_code = code("resultCode");
+
+        // Compute phase is blamed when it failed or was skipped, action phase otherwise
+        let (exit_code, phase) = match (aborted, exit_code, result_code) {
+            (false, exit_code, _) => (exit_code, None),
+            (true, Some(code), _) if code != 0 && code != 1 => {
+                (Some(code), Some(AbortedPhase::Compute))
+            },
+            (true, None, _) => (None, Some(AbortedPhase::Compute)),
+            (true, _, Some(code)) if code != 0 => (Some(code), Some(AbortedPhase::Action)),
+            (true, exit_code, _) => (exit_code, None),
+        };
+
+        let transaction_abort_status = TransactionAbortStatus {
+            aborted,
+            exit_code,
+            phase,
+        };
+
+        serde_json::to_value(&transaction_abort_status).handle_error()
+    }
+
+    internal_fn(transaction).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_account_credit_phases(transaction_boc: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_account_credit_phases");
+
+    let transaction_boc = transaction_boc.to_string_from_ptr();
+
+    fn internal_fn(transaction_boc: String) -> Result<serde_json::Value, String> {
+        let (_, description) = parse_ordinary_transaction(&transaction_boc)?;
+
+        // Only ordinary transactions triggered by an internal message have a credit phase
+        let credit_phase = match description.and_then(|e| e.credit_ph) {
+            Some(credit_ph) => {
+                let mut credit_other = Vec::new();
+
+                credit_ph
+                    .credit
+                    .other
+                    .iterate_with_keys(|currency: u32, value| {
+                        credit_other.push(ExtraCurrency {
+                            currency,
+                            value: value.value().to_string(),
+                        });
+                        Ok(true)
+                    })
+                    .handle_error()?;
+
+                CreditPhase {
+                    credit: credit_ph.credit.grams.0.to_string(),
+                    credit_other,
+                    due_fees_collected: credit_ph
+                        .due_fees_collected
+                        .map(|e| e.0)
+                        .unwrap_or_default()
+                        .to_string(),
+                }
+            },
+            None => CreditPhase {
+                credit: 0.to_string(),
+                credit_other: Vec::new(),
+                due_fees_collected: 0.to_string(),
+            },
+        };
+
+        serde_json::to_value(&credit_phase).handle_error()
+    }
+
+    internal_fn(transaction_boc).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_decode_jetton_burn_notification(body: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_decode_jetton_burn_notification");
+
+    let body = body.to_string_from_ptr();
+
+    fn internal_fn(body: String) -> Result<serde_json::Value, String> {
+        let mut slice = ton_types::SliceData::from(parse_cell(&body)?);
+
+        if slice.remaining_bits() < 32
+            || slice.get_next_u32().handle_error()? != JETTON_BURN_NOTIFICATION
+        {
+            return Ok(serde_json::Value::Null);
+        }
+
+        let query_id = slice.get_next_u64().handle_error()?;
+        let amount = ton_block::Grams::construct_from(&mut slice).handle_error()?;
+        let sender = ton_block::MsgAddress::construct_from(&mut slice).handle_error()?;
+        let response_destination =
+            ton_block::MsgAddress::construct_from(&mut slice).handle_error()?;
+
+        let notification = JettonBurnNotification {
+            query_id: query_id.to_string(),
+            amount: amount.0.to_string(),
+            sender: optional_address(sender),
+            response_destination: optional_address(response_destination),
+        };
+
+        serde_json::to_value(&notification).handle_error()
+    }
+
+    internal_fn(body).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_decode_block_info(block_boc: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_decode_block_info");
+
+    let block_boc = block_boc.to_string_from_ptr();
+
+    fn internal_fn(block_boc: String) -> Result<serde_json::Value, String> {
+        let block = parse_boc::<ton_block::Block>(&block_boc)?;
+
+        let info = block.read_info().handle_error()?;
+
+        let prev_ref = info.read_prev_ref().handle_error()?;
+
+        let prev_ref = std::iter::once(prev_ref.prev1().handle_error()?)
+            .chain(prev_ref.prev2().handle_error()?)
+            .map(|e| BlockRef {
+                seqno: e.seq_no,
+                end_lt: e.end_lt.to_string(),
+                root_hash: e.root_hash.to_hex_string(),
+                file_hash: e.file_hash.to_hex_string(),
+            })
+            .collect::<Vec<_>>();
+
+        let block_info = BlockInfo {
+            seqno: info.seq_no(),
+            workchain: info.shard().workchain_id(),
+            shard: info.shard().shard_prefix_as_str_with_tag(),
+            generated_at: info.gen_utime().0,
+            min_ref_mc_seqno: info.min_ref_mc_seqno(),
+            prev_ref,
+            after_merge: info.after_merge(),
+            after_split: info.after_split(),
+        };
+
+        serde_json::to_value(&block_info).handle_error()
+    }
+
+    internal_fn(block_boc).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_pack_token_value(
+    display_amount: *mut c_char,
+    decimals: c_uchar,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_pack_token_value");
+
+    let display_amount = display_amount.to_string_from_ptr();
+
+    fn internal_fn(display_amount: String, decimals: u8) -> Result<serde_json::Value, String> {
+        let display_amount = display_amount.trim();
+
+        if display_amount.starts_with('-') {
+            return Err("Token amount can't be negative").handle_error();
+        }
+
+        let (integer, fraction) = display_amount
+            .split_once('.')
+            .unwrap_or((display_amount, ""));
+
+        let is_digits = |e: &str| e.chars().all(|c| c.is_ascii_digit());
+        if (integer.is_empty() && fraction.is_empty())
+            || !is_digits(integer)
+            || !is_digits(fraction)
+        {
+            return Err("Invalid token amount").handle_error();
+        }
+
+        if fraction.len() > decimals as usize {
+            return Err(format!(
+                "Token amount has more than {} decimal places",
+                decimals
+            ));
+        }
+
+        let overflow = || "Token amount overflows uint128".to_owned();
+
+        let parse = |e: &str| match e.is_empty() {
+            true => Ok(0),
+            false => e.parse::<u128>().map_err(|_| overflow()),
+        };
+
+        let scale = 10u128.checked_pow(decimals as u32).ok_or_else(overflow)?;
+        let fraction_scale = 10u128
+            .checked_pow((decimals as usize - fraction.len()) as u32)
+            .ok_or_else(overflow)?;
+
+        // Fraction is shorter than the scale, so it can't overflow on its own
+        let fraction = parse(fraction)? * fraction_scale;
+
+        let value = parse(integer)?
+            .checked_mul(scale)
+            .and_then(|e| e.checked_add(fraction))
+            .ok_or_else(overflow)?;
+
+        serde_json::to_value(value.to_string()).handle_error()
+    }
+
+    internal_fn(display_amount, decimals).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_message_value(message_boc: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_message_value");
+
+    let message_boc = message_boc.to_string_from_ptr();
+
+    fn internal_fn(message_boc: String) -> Result<serde_json::Value, String> {
+        let message = parse_boc::<ton_block::Message>(&message_boc)?;
+
+        let header = match message.header() {
+            ton_block::CommonMsgInfo::IntMsgInfo(header) => header,
+            _ => return Err("Expected internal message").handle_error(),
+        };
+
+        let message_value = MessageValue {
+            value: header.value.grams.0.to_string(),
+            ihr_fee: header.ihr_fee.0.to_string(),
+            fwd_fee: header.fwd_fee.0.to_string(),
+        };
+
+        serde_json::to_value(&message_value).handle_error()
+    }
+
+    internal_fn(message_boc).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_parse_message(boc: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_parse_message");
+
+    let boc = boc.to_string_from_ptr();
+
+    fn internal_fn(boc: String) -> Result<serde_json::Value, String> {
+        let message = parse_boc::<ton_block::Message>(&boc)?;
+
+        let hash = message
+            .serialize()
+            .handle_error()?
+            .repr_hash()
+            .to_hex_string();
+
+        let mut parsed_message = ParsedMessage {
+            hash,
+            message_type: MessageBocType::Internal,
+            src: None,
+            dst: None,
+            value: None,
+            bounce: None,
+            bounced: None,
+            ihr_disabled: None,
+            ihr_fee: None,
+            fwd_fee: None,
+            import_fee: None,
+            created_lt: None,
+            created_at: None,
+            has_state_init: message.state_init().is_some(),
+            body_hash: None,
+            body_boc: None,
+        };
+
+        match message.header() {
+            ton_block::CommonMsgInfo::IntMsgInfo(header) => {
+                parsed_message.src = optional_int_address(&header.src);
+                parsed_message.dst = Some(header.dst.to_string());
+                parsed_message.value = Some(header.value.grams.0.to_string());
+                parsed_message.bounce = Some(header.bounce);
+                parsed_message.bounced = Some(header.bounced);
+                parsed_message.ihr_disabled = Some(header.ihr_disabled);
+                parsed_message.ihr_fee = Some(header.ihr_fee.0.to_string());
+                parsed_message.fwd_fee = Some(header.fwd_fee.0.to_string());
+                parsed_message.created_lt = Some(header.created_lt.to_string());
+                parsed_message.created_at = Some(header.created_at.0);
+            },
+            ton_block::CommonMsgInfo::ExtInMsgInfo(header) => {
+                parsed_message.message_type = MessageBocType::ExtIn;
+                parsed_message.src = optional_ext_address(&header.src);
+                parsed_message.dst = Some(header.dst.to_string());
+                parsed_message.import_fee = Some(header.import_fee.0.to_string());
+            },
+            ton_block::CommonMsgInfo::ExtOutMsgInfo(header) => {
+                parsed_message.message_type = MessageBocType::ExtOut;
+                parsed_message.src = optional_int_address(&header.src);
+                parsed_message.dst = optional_ext_address(&header.dst);
+                parsed_message.created_lt = Some(header.created_lt.to_string());
+                parsed_message.created_at = Some(header.created_at.0);
+            },
+        }
+
+        if let Some(body) = message.body() {
+            let body = body.into_cell();
+
+            parsed_message.body_hash = Some(body.repr_hash().to_hex_string());
+            parsed_message.body_boc = Some(base64::encode(
+                ton_types::serialize_toc(&body).handle_error()?,
+            ));
+        }
+
+        serde_json::to_value(&parsed_message).handle_error()
+    }
+
+    internal_fn(boc).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_validate_message_boc(
+    message_boc: *mut c_char,
+    expected_dst: *mut c_char,
+    contract_abi: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_validate_message_boc");
+
+    let message_boc = message_boc.to_string_from_ptr();
+    let expected_dst = expected_dst.to_string_from_ptr();
+    let contract_abi = contract_abi.to_optional_string_from_ptr();
+
+    fn internal_fn(
+        message_boc: String,
+        expected_dst: String,
+        contract_abi: Option<String>,
+    ) -> Result<serde_json::Value, String> {
+        let mut errors = Vec::new();
+
+        let expected_dst = match parse_address(&expected_dst) {
+            Ok(address) => Some(address),
+            Err(err) => {
+                errors.push(format!("Invalid expected destination: {}", err));
+                None
+            },
+        };
+
+        let cell = match parse_cell(&message_boc) {
+            Ok(cell) => Some(cell),
+            Err(err) => {
+                errors.push(format!("Invalid BOC: {}", err));
+                None
+            },
+        };
+
+        let message = match cell {
+            Some(cell) => {
+                let mut slice = ton_types::SliceData::from(cell);
+
+                match ton_block::Message::construct_from(&mut slice) {
+                    Ok(message) => {
+                        if slice.remaining_bits() > 0 || slice.remaining_references() > 0 {
+                            errors.push("Message cell has trailing data".to_owned());
+                        }
+
+                        Some(message)
+                    },
+                    Err(err) => {
+                        errors.push(format!("Invalid message: {}", err));
                         None
                     },
+                }
+            },
+            None => None,
+        };
+
+        let message_type = message.as_ref().map(|message| match message.header() {
+            ton_block::CommonMsgInfo::IntMsgInfo(_) => MessageBocType::Internal,
+            ton_block::CommonMsgInfo::ExtInMsgInfo(_) => MessageBocType::ExtIn,
+            ton_block::CommonMsgInfo::ExtOutMsgInfo(_) => MessageBocType::ExtOut,
+        });
+
+        if let Some(message) = &message {
+            match message.dst() {
+                Some(dst) => {
+                    if let Some(expected_dst) = &expected_dst {
+                        if &dst != expected_dst {
+                            errors.push(format!(
+                                "Destination mismatch: expected {}, got {}",
+                                expected_dst, dst
+                            ));
+                        }
+                    }
                 },
-            })
-        })
-        .handle_error()
+                None => errors.push("Message has no internal destination".to_owned()),
+            }
+
+            if message.is_inbound_external() && message.body().is_none() {
+                errors.push("External message has no body".to_owned());
+            }
+        }
+
+        // Header layout depends on the ABI, the expiration is only known when it's provided
+        let expire_at = match (&message, &contract_abi) {
+            (Some(message), Some(contract_abi)) if message.is_inbound_external() => {
+                match message
+                    .body()
+                    .map(|body| read_expire_at(contract_abi, body))
+                {
+                    Some(Ok(expire_at)) => expire_at,
+                    Some(Err(err)) => {
+                        errors.push(format!("Invalid body header: {}", err));
+                        None
+                    },
+                    None => None,
+                }
+            },
+            _ => None,
+        };
+
+        let validation = MessageBocValidation {
+            valid: errors.is_empty(),
+            message_type,
+            expire_at,
+            errors,
+        };
+
+        serde_json::to_value(&validation).handle_error()
+    }
+
+    internal_fn(message_boc, expected_dst, contract_abi).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_account_cells_total_count(
+    account_stuff_boc: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_account_cells_total_count");
+
+    let account_stuff_boc = account_stuff_boc.to_string_from_ptr();
+
+    fn internal_fn(account_stuff_boc: String) -> Result<serde_json::Value, String> {
+        let account_stuff = parse_account_stuff(&account_stuff_boc)?;
+
+        let state_init = match account_stuff.storage.state {
+            ton_block::AccountState::AccountActive { state_init } => state_init,
+            _ => return Err("Account is not active").handle_error(),
+        };
+
+        let roots = [
+            state_init.code,
+            state_init.data,
+            state_init.library.root().cloned(),
+        ];
+
+        let mut visits = HashMap::<ton_types::UInt256, u32>::new();
+        let mut stack = roots.into_iter().flatten().collect::<Vec<_>>();
+
+        while let Some(cell) = stack.pop() {
+            let count = visits.entry(cell.repr_hash()).or_default();
+            *count += 1;
+
+            if *count == 1 {
+                for i in 0..cell.references_count() {
+                    stack.push(cell.reference(i).handle_error()?);
+                }
+            }
+        }
+
+        let total_cells = visits.len() as u32;
+        let shared_cells = visits.values().filter(|e| **e > 1).count() as u32;
+
+        let account_cells_count = AccountCellsCount {
+            total_cells,
+            shared_cells,
+            own_cells: total_cells - shared_cells,
+        };
+
+        serde_json::to_value(&account_cells_count).handle_error()
+    }
+
+    internal_fn(account_stuff_boc).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_config_param_17(config_boc: *mut c_char) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_config_param_17");
+
+    let config_boc = config_boc.to_string_from_ptr();
+
+    fn internal_fn(config_boc: String) -> Result<serde_json::Value, String> {
+        let config = parse_boc::<ton_block::ConfigParams>(&config_boc)?;
+
+        let param = match config.config(17).handle_error()? {
+            Some(ton_block::ConfigParamEnum::ConfigParam17(param)) => param,
+            _ => return Err("Config param 17 not found").handle_error(),
+        };
+
+        let stakes_config = StakesConfig {
+            min_stake: param.min_stake.0.to_string(),
+            max_stake: param.max_stake.0.to_string(),
+            min_total_stake: param.min_total_stake.0.to_string(),
+            max_stake_factor: param.max_stake_factor,
+        };
+
+        serde_json::to_value(&stakes_config).handle_error()
+    }
+
+    internal_fn(config_boc).match_result()
+}
+
+fn parse_account_stuff(boc: &str) -> Result<ton_block::AccountStuff, String> {
+    let cell = parse_cell(boc)?;
+    read_account_stuff(&mut cell.into()).handle_error()
+}
+
+fn read_account_stuff(
+    slice: &mut ton_types::SliceData,
+) -> ton_types::Result<ton_block::AccountStuff> {
+    Ok(ton_block::AccountStuff {
+        addr: Deserializable::construct_from(slice)?,
+        storage_stat: Deserializable::construct_from(slice)?,
+        storage: ton_block::AccountStorage {
+            last_trans_lt: Deserializable::construct_from(slice)?,
+            balance: Deserializable::construct_from(slice)?,
+            state: Deserializable::construct_from(slice)?,
+            init_code_hash: if slice.remaining_bits() > 0 {
+                ton_types::UInt256::read_maybe_from(slice)?
+            } else {
+                None
+            },
+        },
+    })
+}
+
+/// Raw transaction along with its description when it is an ordinary one
+fn parse_ordinary_transaction(
+    boc: &str,
+) -> Result<
+    (
+        ton_block::Transaction,
+        Option<ton_block::TransactionDescrOrdinary>,
+    ),
+    String,
+> {
+    let transaction = parse_boc::<ton_block::Transaction>(boc)?;
+
+    let description = match transaction.read_description().handle_error()? {
+        ton_block::TransactionDescr::Ordinary(description) => Some(description),
+        _ => None,
+    };
+
+    Ok((transaction, description))
+}
+
+const JETTON_BURN_NOTIFICATION: u32 = 0x7bdd97de;
+
+fn optional_int_address(address: &ton_block::MsgAddressIntOrNone) -> Option<String> {
+    match address {
+        ton_block::MsgAddressIntOrNone::Some(address) => Some(address.to_string()),
+        ton_block::MsgAddressIntOrNone::None => None,
+    }
+}
+
+fn optional_ext_address(address: &ton_block::MsgAddressExt) -> Option<String> {
+    match address {
+        ton_block::MsgAddressExt::AddrNone => None,
+        address => Some(address.to_string()),
+    }
+}
+
+fn optional_address(address: ton_block::MsgAddress) -> Option<String> {
+    match address {
+        ton_block::MsgAddress::AddrNone => None,
+        address => Some(address.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, str::FromStr};
+
+    use super::*;
+
+    fn call_ffi(f: impl FnOnce() -> *mut c_char) -> serde_json::Value {
+        let result = unsafe { CString::from_raw(f()) };
+
+        serde_json::from_str(result.to_str().unwrap()).unwrap()
+    }
+
+    fn to_boc(cell: ton_types::Cell) -> CString {
+        CString::new(base64::encode(ton_types::serialize_toc(&cell).unwrap())).unwrap()
+    }
+
+    fn test_address(byte: u8) -> MsgAddressInt {
+        MsgAddressInt::from_str(&format!("0:{}", hex::encode([byte; 32]))).unwrap()
+    }
+
+    fn active_account(state_init: ton_block::StateInit) -> CString {
+        let mut account_stuff = ton_block::AccountStuff {
+            addr: test_address(0x33),
+            ..Default::default()
+        };
+        account_stuff.storage.state = ton_block::AccountState::AccountActive { state_init };
+
+        to_boc(account_stuff.serialize().unwrap())
+    }
+
+    #[test]
+    fn ever_wallet_custodian_is_its_public_key() {
+        let secret = ed25519_dalek::SecretKey::from_bytes(&[7; 32]).unwrap();
+        let public_key = ed25519_dalek::PublicKey::from(&secret);
+
+        let state_init =
+            nekoton::core::ton_wallet::ever_wallet::make_state_init(&public_key).unwrap();
+        let account = active_account(state_init);
+
+        let result =
+            call_ffi(|| unsafe { nt_get_ever_wallet_custodians(account.as_ptr() as *mut c_char) });
+
+        assert_eq!(result["type"], "ok", "{}", result["data"]);
+        assert_eq!(
+            result["data"],
+            serde_json::json!([hex::encode(public_key.as_bytes())])
+        );
+    }
+
+    #[test]
+    fn other_wallets_have_no_ever_wallet_custodians() {
+        let state_init = ton_block::StateInit {
+            code: Some(ton_types::Cell::default()),
+            data: Some(ton_types::Cell::default()),
+            ..Default::default()
+        };
+        let account = active_account(state_init);
+
+        let result =
+            call_ffi(|| unsafe { nt_get_ever_wallet_custodians(account.as_ptr() as *mut c_char) });
+
+        assert_eq!(result["type"], "err");
+    }
+
+    #[test]
+    fn split_tvc_round_trip() {
+        let mut code = ton_types::BuilderData::new();
+        code.append_u32(0xc0de).unwrap();
+        let code = to_boc(code.into_cell().unwrap());
+
+        let tvc = call_ffi(|| unsafe { nt_code_to_tvc(code.as_ptr() as *mut c_char) });
+        let tvc = CString::new(tvc["data"].as_str().unwrap()).unwrap();
+
+        let splitted = call_ffi(|| unsafe { nt_split_tvc(tvc.as_ptr() as *mut c_char) });
+        assert_eq!(splitted["type"], "ok");
+        assert_eq!(splitted["data"]["code"], code.to_str().unwrap());
+        assert!(splitted["data"]["data"].is_null());
+
+        let merged = call_ffi(|| unsafe {
+            nt_merge_tvc(code.as_ptr() as *mut c_char, std::ptr::null_mut())
+        });
+        assert_eq!(merged["data"]["tvc"], tvc.to_str().unwrap());
+    }
+
+    #[test]
+    fn merge_tvc_accepts_hex_boc() {
+        let mut code = ton_types::BuilderData::new();
+        code.append_u32(0xc0de).unwrap();
+        let code = code.into_cell().unwrap();
+
+        let base64_code = to_boc(code.clone());
+        let hex_code = CString::new(hex::encode(ton_types::serialize_toc(&code).unwrap())).unwrap();
+
+        let from_base64 = call_ffi(|| unsafe {
+            nt_merge_tvc(base64_code.as_ptr() as *mut c_char, std::ptr::null_mut())
+        });
+        let from_hex = call_ffi(|| unsafe {
+            nt_merge_tvc(hex_code.as_ptr() as *mut c_char, std::ptr::null_mut())
+        });
+
+        assert_eq!(from_hex["type"], "ok", "{}", from_hex["data"]);
+        assert_eq!(from_hex["data"], from_base64["data"]);
+    }
+
+    #[test]
+    fn account_cells_count_tracks_shared_cells() {
+        let mut shared = ton_types::BuilderData::new();
+        shared.append_u32(1).unwrap();
+        let shared = shared.into_cell().unwrap();
+
+        let with_shared = |tag: u32| {
+            let mut cell = ton_types::BuilderData::new();
+            cell.append_u32(tag).unwrap();
+            cell.checked_append_reference(shared.clone()).unwrap();
+            cell.into_cell().unwrap()
+        };
+
+        let state_init = ton_block::StateInit {
+            code: Some(with_shared(2)),
+            data: Some(with_shared(3)),
+            ..Default::default()
+        };
+
+        let mut account = ton_types::BuilderData::new();
+        test_address(0x22).write_to(&mut account).unwrap();
+        ton_block::StorageInfo::default()
+            .write_to(&mut account)
+            .unwrap();
+        0u64.write_to(&mut account).unwrap();
+        ton_block::CurrencyCollection::default()
+            .write_to(&mut account)
+            .unwrap();
+        ton_block::AccountState::AccountActive { state_init }
+            .write_to(&mut account)
+            .unwrap();
+
+        let account = to_boc(account.into_cell().unwrap());
+
+        let result = call_ffi(|| unsafe {
+            nt_get_account_cells_total_count(account.as_ptr() as *mut c_char)
+        });
+
+        assert_eq!(result["type"], "ok");
+        assert_eq!(result["data"]["totalCells"], 3);
+        assert_eq!(result["data"]["sharedCells"], 1);
+        assert_eq!(result["data"]["ownCells"], 2);
+    }
+
+    #[test]
+    fn jetton_burn_notification_round_trip() {
+        let sender = test_address(0x11);
+
+        let mut body = ton_types::BuilderData::new();
+        body.append_u32(JETTON_BURN_NOTIFICATION).unwrap();
+        body.append_u64(42).unwrap();
+        ton_block::Grams::from(1_000_000_000u64)
+            .write_to(&mut body)
+            .unwrap();
+        sender.write_to(&mut body).unwrap();
+        ton_block::MsgAddress::AddrNone.write_to(&mut body).unwrap();
+
+        let body = to_boc(body.into_cell().unwrap());
+
+        let result = call_ffi(|| unsafe {
+            nt_decode_jetton_burn_notification(body.as_ptr() as *mut c_char)
+        });
+
+        assert_eq!(result["type"], "ok");
+        assert_eq!(result["data"]["queryId"], "42");
+        assert_eq!(result["data"]["amount"], "1000000000");
+        assert_eq!(result["data"]["sender"], sender.to_string());
+        assert_eq!(
+            result["data"]["responseDestination"],
+            serde_json::Value::Null
+        );
+    }
+
+    /// `burn_notification#7bdd97de query_id:42 amount:1000000000 sender:0:11..11
+    /// response_destination:addr_none`, serialized independently of `ton_types`
+    const JETTON_BURN_NOTIFICATION_BOC: &str = "b5ee9c720101010100350000657bdd97de000000000000002a\
+        43b9aca00800222222222222222222222222222222222222222222222222222222222222222240";
+
+    #[test]
+    fn jetton_burn_notification_from_hex_boc() {
+        let body = CString::new(JETTON_BURN_NOTIFICATION_BOC).unwrap();
+
+        let result = call_ffi(|| unsafe {
+            nt_decode_jetton_burn_notification(body.as_ptr() as *mut c_char)
+        });
+
+        assert_eq!(result["type"], "ok", "{}", result["data"]);
+        assert_eq!(result["data"]["queryId"], "42");
+        assert_eq!(result["data"]["amount"], "1000000000");
+        assert_eq!(result["data"]["sender"], test_address(0x11).to_string());
+        assert!(result["data"]["responseDestination"].is_null());
+    }
 }
@@ -1,9 +1,173 @@
 use serde::Serialize;
 
+/// Missing parts are `null`, e.g. a TVC made by `nt_code_to_tvc` has no data
 #[derive(Serialize)]
 pub struct SplittedTvc {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
 }
+
+#[derive(Serialize)]
+pub struct MergedTvc {
+    pub tvc: String,
+    pub hash: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckedAddress {
+    pub is_valid: bool,
+    pub address: Option<String>,
+    pub workchain: Option<i8>,
+    pub bounceable: Option<bool>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct AccountVisual {
+    pub seed: String,
+    pub color: HslColor,
+    pub identicon: Vec<Vec<bool>>,
+}
+
+#[derive(Serialize)]
+pub struct HslColor {
+    pub hue: u16,
+    pub saturation: u8,
+    pub lightness: u8,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionFees {
+    pub total_fees: String,
+    pub storage_fee: String,
+    pub compute_fee: String,
+    pub action_fee: String,
+    pub forward_fee: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionAbortStatus {
+    pub aborted: bool,
+    pub exit_code: Option<i32>,
+    pub phase: Option<AbortedPhase>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AbortedPhase {
+    Compute,
+    Action,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockInfo {
+    pub seqno: u32,
+    pub workchain: i32,
+    pub shard: String,
+    pub generated_at: u32,
+    pub min_ref_mc_seqno: u32,
+    pub prev_ref: Vec<BlockRef>,
+    pub after_merge: bool,
+    pub after_split: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockRef {
+    pub seqno: u32,
+    pub end_lt: String,
+    pub root_hash: String,
+    pub file_hash: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageValue {
+    pub value: String,
+    pub ihr_fee: String,
+    pub fwd_fee: String,
+}
+
+/// Header fields which the message type doesn't have are `null`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedMessage {
+    pub hash: String,
+    pub message_type: MessageBocType,
+    pub src: Option<String>,
+    pub dst: Option<String>,
+    pub value: Option<String>,
+    pub bounce: Option<bool>,
+    pub bounced: Option<bool>,
+    pub ihr_disabled: Option<bool>,
+    pub ihr_fee: Option<String>,
+    pub fwd_fee: Option<String>,
+    pub import_fee: Option<String>,
+    pub created_lt: Option<String>,
+    pub created_at: Option<u32>,
+    pub has_state_init: bool,
+    pub body_hash: Option<String>,
+    pub body_boc: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageBocValidation {
+    pub valid: bool,
+    pub message_type: Option<MessageBocType>,
+    pub expire_at: Option<u32>,
+    pub errors: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MessageBocType {
+    ExtIn,
+    Internal,
+    ExtOut,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountCellsCount {
+    pub total_cells: u32,
+    pub shared_cells: u32,
+    pub own_cells: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreditPhase {
+    pub credit: String,
+    pub credit_other: Vec<ExtraCurrency>,
+    pub due_fees_collected: String,
+}
+
+#[derive(Serialize)]
+pub struct ExtraCurrency {
+    pub currency: u32,
+    pub value: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JettonBurnNotification {
+    pub query_id: String,
+    pub amount: String,
+    pub sender: Option<String>,
+    pub response_destination: Option<String>,
+}
+
+/// Amounts are in nanotons, stake factor is a 16.16 fixed point number
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakesConfig {
+    pub min_stake: String,
+    pub max_stake: String,
+    pub min_total_stake: String,
+    pub max_stake_factor: u32,
+}
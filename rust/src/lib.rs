@@ -5,19 +5,24 @@
     clippy::borrowed_box
 )]
 
+mod cancellation;
+mod canonical_json;
 mod core;
 mod crypto;
 mod external;
 mod helpers;
+mod metrics;
+mod self_test;
 mod transport;
 
 use std::{
     ffi::{CStr, CString},
+    future::Future,
     intrinsics::transmute,
     io,
-    os::raw::{c_char, c_void},
+    os::raw::{c_char, c_longlong, c_uint, c_void},
     str::FromStr,
-    sync::Arc,
+    sync::{Arc, Once},
 };
 
 use allo_isolate::{
@@ -26,26 +31,35 @@ use allo_isolate::{
 };
 use anyhow::Result;
 use lazy_static::lazy_static;
+#[cfg(not(feature = "testable_clock"))]
 use nekoton_utils::SimpleClock;
 use serde::Serialize;
 use tokio::runtime::{Builder, Runtime};
 use ton_block::MsgAddressInt;
 
+use crate::cancellation::CancellationToken;
+
 lazy_static! {
     static ref RUNTIME: io::Result<Runtime> = Builder::new_multi_thread()
         .enable_all()
         .thread_name("nekoton_flutter")
         .build();
+}
+
+#[cfg(not(feature = "testable_clock"))]
+lazy_static! {
     static ref CLOCK: Arc<SimpleClock> = Arc::new(SimpleClock {});
 }
 
-#[macro_export]
-macro_rules! runtime {
-    () => {
-        RUNTIME.as_ref().unwrap()
-    };
+#[cfg(feature = "testable_clock")]
+lazy_static! {
+    static ref CLOCK: Arc<TestableClock> = Arc::new(TestableClock::default());
 }
 
+/// Clock which output can be shifted from Dart side to simulate time-dependent scenarios
+#[cfg(feature = "testable_clock")]
+pub type TestableClock = nekoton_utils::ClockWithOffset;
+
 #[macro_export]
 macro_rules! clock {
     () => {
@@ -53,6 +67,17 @@ macro_rules! clock {
     };
 }
 
+#[macro_export]
+macro_rules! ffi_timer {
+    ($name:literal) => {
+        $crate::metrics::FfiCallTimer::start($name)
+    };
+}
+
+/// Post function is shared by all isolates of the process, while `allo_isolate` keeps it in an
+/// unsynchronized global, so it is stored once and calls from other isolates are ignored
+static STORE_DART_POST_COBJECT: Once = Once::new();
+
 #[no_mangle]
 pub unsafe extern "C" fn nt_store_dart_post_cobject(ptr: *mut c_void) {
     let ptr = transmute::<
@@ -60,12 +85,97 @@ pub unsafe extern "C" fn nt_store_dart_post_cobject(ptr: *mut c_void) {
         unsafe extern "C" fn(port_id: DartPort, message: *mut DartCObject) -> bool,
     >(ptr);
 
-    allo_isolate::store_dart_post_cobject(ptr);
+    STORE_DART_POST_COBJECT.call_once(|| allo_isolate::store_dart_post_cobject(ptr));
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn nt_free_cstring(ptr: *mut c_char) {
-    ptr.to_string_from_ptr();
+    if !ptr.is_null() {
+        drop(CString::from_raw(ptr));
+    }
+}
+
+/// Runtime of the library, an error instead of a panic once it's unavailable
+fn runtime() -> Result<&'static Runtime, String> {
+    get_runtime(&RUNTIME)
+}
+
+fn get_runtime(runtime: &io::Result<Runtime>) -> Result<&Runtime, String> {
+    runtime.as_ref().map_err(|_| "Runtime shutdown".to_owned())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_is_runtime_alive() -> c_uint {
+    RUNTIME.is_ok() as c_uint
+}
+
+/// Spawns the task of an async FFI call, the call is answered with an error instead of a panic
+/// when the runtime is unavailable
+fn spawn_ffi_task<F>(result_port: c_longlong, future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    match runtime() {
+        Ok(runtime) => drop(runtime.spawn(future)),
+        Err(err) => {
+            let result = Err::<(), _>(err).match_result();
+
+            let _ = Isolate::new(result_port).post_with_result(result);
+        },
+    }
+}
+
+/// Same as [`spawn_ffi_task`], but a cancelled token drops the call with its in-flight requests
+/// and answers it with [`ExecutionResult::Cancelled`]. Only for calls which are safe to abandon
+/// at any await point
+fn spawn_cancellable_ffi_task<F>(
+    result_port: c_longlong,
+    cancellation_token: Option<CancellationToken>,
+    future: F,
+) where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let cancellation_token = match cancellation_token {
+        Some(cancellation_token) => cancellation_token,
+        None => return spawn_ffi_task(result_port, future),
+    };
+
+    spawn_ffi_task(result_port, async move {
+        tokio::select! {
+            _ = future => {},
+            _ = cancellation_token.cancelled() => {
+                let _ = Isolate::new(result_port).post_with_result(cancelled_result());
+            },
+        }
+    });
+}
+
+#[cfg(feature = "testable_clock")]
+#[no_mangle]
+pub unsafe extern "C" fn nt_set_clock_offset(offset_ms: c_longlong) -> *mut c_char {
+    let _timer = ffi_timer!("nt_set_clock_offset");
+
+    fn internal_fn(offset_ms: i64) -> Result<serde_json::Value, String> {
+        CLOCK.update_offset(offset_ms);
+
+        Ok(serde_json::Value::Null)
+    }
+
+    internal_fn(offset_ms).match_result()
+}
+
+#[cfg(feature = "testable_clock")]
+#[no_mangle]
+pub unsafe extern "C" fn nt_reset_clock() -> *mut c_char {
+    let _timer = ffi_timer!("nt_reset_clock");
+
+    fn internal_fn() -> Result<serde_json::Value, String> {
+        CLOCK.update_offset(0);
+
+        Ok(serde_json::Value::Null)
+    }
+
+    internal_fn().match_result()
 }
 
 #[derive(Serialize)]
@@ -76,6 +186,15 @@ where
 {
     Ok(T),
     Err(String),
+    /// Call was cancelled with its token, kept apart from errors which may have the same text
+    Cancelled,
+}
+
+fn cancelled_result() -> *mut c_char {
+    // Serialization of a unit variant can't fail
+    canonical_json::to_json_string(&ExecutionResult::<()>::Cancelled)
+        .unwrap()
+        .to_cstring_ptr()
 }
 
 pub trait MatchResult {
@@ -92,7 +211,15 @@ where
             Err(err) => ExecutionResult::Err(err),
         };
 
-        serde_json::to_string(&result).unwrap().to_cstring_ptr()
+        canonical_json::to_json_string(&result)
+            .unwrap_or_else(|err| {
+                let err =
+                    ExecutionResult::<()>::Err(format!("Failed to serialize result: {}", err));
+
+                // Serialization of a plain string error can't fail
+                canonical_json::to_json_string(&err).unwrap()
+            })
+            .to_cstring_ptr()
     }
 }
 
@@ -140,7 +267,17 @@ pub trait ToCStringPtr {
 
 impl ToCStringPtr for String {
     fn to_cstring_ptr(self) -> *mut c_char {
-        CString::new(self).unwrap().into_raw()
+        CString::new(self)
+            .unwrap_or_else(|err| {
+                let err = ExecutionResult::<()>::Err(format!(
+                    "Result contains an interior NUL byte at {}",
+                    err.nul_position()
+                ));
+
+                // JSON escapes NUL bytes, so the error itself is a valid C string
+                CString::new(canonical_json::to_json_string(&err).unwrap()).unwrap()
+            })
+            .into_raw()
     }
 }
 
@@ -166,3 +303,145 @@ impl ToOptionalStringFromPtr for *mut c_char {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Barrier,
+    };
+
+    use super::*;
+
+    static POSTS: [AtomicUsize; 4] = [
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+    ];
+
+    unsafe extern "C" fn fake_post<const N: usize>(_: DartPort, _: *mut DartCObject) -> bool {
+        POSTS[N].fetch_add(1, Ordering::SeqCst);
+        true
+    }
+
+    #[test]
+    fn post_function_is_stored_once_across_threads() {
+        let posts: [unsafe extern "C" fn(DartPort, *mut DartCObject) -> bool; 4] = [
+            fake_post::<0>,
+            fake_post::<1>,
+            fake_post::<2>,
+            fake_post::<3>,
+        ];
+
+        let barrier = Arc::new(Barrier::new(posts.len()));
+
+        let handles = posts
+            .iter()
+            .map(|&post| {
+                let barrier = barrier.clone();
+                let post = post as usize;
+
+                std::thread::spawn(move || {
+                    barrier.wait();
+                    unsafe { nt_store_dart_post_cobject(post as *mut c_void) };
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(Isolate::new(1).post(0));
+
+        // Only the function stored first receives messages, whichever thread won
+        let used = POSTS
+            .iter()
+            .filter(|e| e.load(Ordering::SeqCst) > 0)
+            .count();
+        assert_eq!(used, 1);
+    }
+
+    #[test]
+    fn unavailable_runtime_gives_error() {
+        let runtime = Err(io::Error::new(io::ErrorKind::Other, "shutdown"));
+
+        assert_eq!(
+            get_runtime(&runtime).err().as_deref(),
+            Some("Runtime shutdown")
+        );
+    }
+
+    #[test]
+    fn available_runtime_runs_tasks() {
+        let runtime = runtime().unwrap();
+
+        let handle = runtime.spawn(async { 2 + 2 });
+
+        assert_eq!(runtime.block_on(handle).unwrap(), 4);
+    }
+
+    #[test]
+    fn returned_cstring_is_freed() {
+        let ptr = "value".to_owned().to_cstring_ptr();
+
+        unsafe {
+            assert_eq!(CStr::from_ptr(ptr).to_str().unwrap(), "value");
+
+            nt_free_cstring(ptr);
+            nt_free_cstring(std::ptr::null_mut());
+        }
+    }
+
+    fn match_result_json<T: Serialize>(result: Result<T, String>) -> serde_json::Value {
+        let result = unsafe { CString::from_raw(result.match_result()) };
+        serde_json::from_str(result.to_str().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn unserializable_result_gives_error() {
+        // Maps with non-string keys can't be represented in JSON
+        let value = std::collections::HashMap::from([((1u8, 2u8), 3u8)]);
+
+        let result = match_result_json(Ok::<_, String>(value));
+
+        assert_eq!(result["type"], "err");
+        assert!(result["data"]
+            .as_str()
+            .unwrap()
+            .starts_with("Failed to serialize result"));
+    }
+
+    #[test]
+    fn bytes_token_with_nul_is_returned() {
+        let bytes = vec![0x00, 0x2a, 0x00];
+        let tokens = [ton_abi::Token::new(
+            "payload",
+            ton_abi::TokenValue::Bytes(bytes.clone()),
+        )];
+
+        let result = match_result_json(nekoton_abi::make_abi_tokens(&tokens).handle_error());
+
+        assert_eq!(result["type"], "ok", "{}", result["data"]);
+        let payload = result["data"]["payload"].as_str().unwrap();
+        assert_eq!(base64::decode(payload).unwrap(), bytes);
+    }
+
+    #[test]
+    fn string_with_nul_is_escaped() {
+        let result = match_result_json(Ok::<_, String>("a\0b"));
+
+        assert_eq!(result["type"], "ok");
+        assert_eq!(result["data"], "a\0b");
+    }
+
+    #[test]
+    fn raw_string_with_nul_gives_error() {
+        let result = unsafe { CString::from_raw("a\0b".to_owned().to_cstring_ptr()) };
+        let result = serde_json::from_str::<serde_json::Value>(result.to_str().unwrap()).unwrap();
+
+        assert_eq!(result["type"], "err");
+        assert_eq!(result["data"], "Result contains an interior NUL byte at 1");
+    }
+}
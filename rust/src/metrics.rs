@@ -0,0 +1,181 @@
+use std::{
+    collections::HashMap,
+    os::raw::{c_char, c_uint},
+    sync::{
+        atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Instant,
+};
+
+use lazy_static::lazy_static;
+use serde::Serialize;
+
+use crate::{HandleError, MatchResult};
+
+static METRICS_ENABLED: AtomicBool = AtomicBool::new(false);
+static EVENTS_POSTED: AtomicU64 = AtomicU64::new(0);
+static FFI_CALLS_SERVED: AtomicU64 = AtomicU64::new(0);
+
+static TON_WALLET_SUBSCRIPTIONS: AtomicI64 = AtomicI64::new(0);
+static TOKEN_WALLET_SUBSCRIPTIONS: AtomicI64 = AtomicI64::new(0);
+static GENERIC_CONTRACT_SUBSCRIPTIONS: AtomicI64 = AtomicI64::new(0);
+
+lazy_static! {
+    static ref FFI_CALL_STATS: Mutex<HashMap<&'static str, FfiCallStats>> =
+        Mutex::new(HashMap::new());
+}
+
+#[derive(Default)]
+struct FfiCallStats {
+    count: u64,
+    total_us: u64,
+    max_us: u64,
+}
+
+#[derive(Clone, Copy)]
+pub enum SubscriptionKind {
+    TonWallet,
+    TokenWallet,
+    GenericContract,
+}
+
+impl SubscriptionKind {
+    fn counter(self) -> &'static AtomicI64 {
+        match self {
+            SubscriptionKind::TonWallet => &TON_WALLET_SUBSCRIPTIONS,
+            SubscriptionKind::TokenWallet => &TOKEN_WALLET_SUBSCRIPTIONS,
+            SubscriptionKind::GenericContract => &GENERIC_CONTRACT_SUBSCRIPTIONS,
+        }
+    }
+}
+
+/// Measures an FFI entry point from its creation until drop, does nothing while metrics are disabled
+pub struct FfiCallTimer {
+    name: &'static str,
+    started_at: Option<Instant>,
+}
+
+impl FfiCallTimer {
+    pub fn start(name: &'static str) -> Self {
+        let started_at = METRICS_ENABLED.load(Ordering::Relaxed).then(Instant::now);
+
+        Self { name, started_at }
+    }
+}
+
+impl Drop for FfiCallTimer {
+    fn drop(&mut self) {
+        let started_at = match self.started_at {
+            Some(started_at) => started_at,
+            None => return,
+        };
+
+        let elapsed_us = started_at.elapsed().as_micros() as u64;
+
+        FFI_CALLS_SERVED.fetch_add(1, Ordering::Relaxed);
+
+        let mut stats = FFI_CALL_STATS.lock().unwrap();
+        let stats = stats.entry(self.name).or_default();
+
+        stats.count += 1;
+        stats.total_us += elapsed_us;
+        stats.max_us = stats.max_us.max(elapsed_us);
+    }
+}
+
+pub fn subscription_created(kind: SubscriptionKind) {
+    kind.counter().fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn subscription_dropped(kind: SubscriptionKind) {
+    kind.counter().fetch_sub(1, Ordering::Relaxed);
+}
+
+pub fn record_event_posted() {
+    if METRICS_ENABLED.load(Ordering::Relaxed) {
+        EVENTS_POSTED.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeMetrics {
+    pub enabled: bool,
+    pub worker_threads: usize,
+    pub live_subscriptions: LiveSubscriptions,
+    pub events_posted: u64,
+    pub ffi_calls_served: u64,
+    pub slowest_ffi_calls: Vec<FfiCallMetrics>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveSubscriptions {
+    pub ton_wallet: i64,
+    pub token_wallet: i64,
+    pub generic_contract: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FfiCallMetrics {
+    pub name: String,
+    pub count: u64,
+    pub max_us: u64,
+    pub avg_us: u64,
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_runtime_metrics() -> *mut c_char {
+    fn internal_fn() -> Result<serde_json::Value, String> {
+        let mut slowest_ffi_calls = FFI_CALL_STATS
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, stats)| FfiCallMetrics {
+                name: name.to_string(),
+                count: stats.count,
+                max_us: stats.max_us,
+                avg_us: stats.total_us / stats.count.max(1),
+            })
+            .collect::<Vec<_>>();
+
+        slowest_ffi_calls.sort_by(|a, b| b.max_us.cmp(&a.max_us));
+        slowest_ffi_calls.truncate(10);
+
+        let worker_threads = std::thread::available_parallelism()
+            .map(|e| e.get())
+            .unwrap_or(1);
+
+        let metrics = RuntimeMetrics {
+            enabled: METRICS_ENABLED.load(Ordering::Relaxed),
+            worker_threads,
+            live_subscriptions: LiveSubscriptions {
+                ton_wallet: TON_WALLET_SUBSCRIPTIONS.load(Ordering::Relaxed),
+                token_wallet: TOKEN_WALLET_SUBSCRIPTIONS.load(Ordering::Relaxed),
+                generic_contract: GENERIC_CONTRACT_SUBSCRIPTIONS.load(Ordering::Relaxed),
+            },
+            events_posted: EVENTS_POSTED.load(Ordering::Relaxed),
+            ffi_calls_served: FFI_CALLS_SERVED.load(Ordering::Relaxed),
+            slowest_ffi_calls,
+        };
+
+        serde_json::to_value(&metrics).handle_error()
+    }
+
+    internal_fn().match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_set_metrics_enabled(enabled: c_uint) -> *mut c_char {
+    let enabled = enabled != 0;
+
+    fn internal_fn(enabled: bool) -> Result<serde_json::Value, String> {
+        METRICS_ENABLED.store(enabled, Ordering::Relaxed);
+
+        Ok(serde_json::Value::Null)
+    }
+
+    internal_fn(enabled).match_result()
+}
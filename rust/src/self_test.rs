@@ -0,0 +1,125 @@
+use std::{
+    ffi::CString,
+    os::raw::c_char,
+    panic::{catch_unwind, AssertUnwindSafe},
+    str::FromStr,
+    time::Instant,
+};
+
+use ed25519_dalek::{Signer, Verifier};
+use serde::{Deserialize, Serialize};
+
+use crate::{ffi_timer, nt_free_cstring, runtime, HandleError, MatchResult};
+
+const EMPTY_CELL_HASH: &str = "96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7";
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTestCheck {
+    pub name: String,
+    pub passed: bool,
+    pub elapsed_us: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq)]
+struct JsonRoundTrip {
+    text: String,
+    number: u64,
+    items: Vec<Option<bool>>,
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_self_test() -> *mut c_char {
+    let _timer = ffi_timer!("nt_self_test");
+
+    fn internal_fn() -> Result<serde_json::Value, String> {
+        let checks = vec![
+            run_check("jsonRoundTrip", check_json_round_trip),
+            run_check("bocHash", check_boc_hash),
+            run_check("ed25519", check_ed25519),
+            run_check("runtime", check_runtime),
+            run_check("cstring", check_cstring),
+        ];
+
+        serde_json::to_value(&checks).handle_error()
+    }
+
+    internal_fn().match_result()
+}
+
+fn run_check<F>(name: &str, check: F) -> SelfTestCheck
+where
+    F: FnOnce() -> Result<(), String>,
+{
+    let started_at = Instant::now();
+
+    let result = catch_unwind(AssertUnwindSafe(check))
+        .unwrap_or_else(|_| Err("Check panicked").handle_error());
+
+    SelfTestCheck {
+        name: name.to_owned(),
+        passed: result.is_ok(),
+        elapsed_us: started_at.elapsed().as_micros() as u64,
+        error: result.err(),
+    }
+}
+
+fn check_json_round_trip() -> Result<(), String> {
+    let value = JsonRoundTrip {
+        text: "nekoton".to_owned(),
+        number: u64::MAX,
+        items: vec![Some(true), None, Some(false)],
+    };
+
+    let json = serde_json::to_string(&value).handle_error()?;
+
+    match serde_json::from_str::<JsonRoundTrip>(&json).handle_error()? == value {
+        true => Ok(()),
+        false => Err("Deserialized value differs").handle_error(),
+    }
+}
+
+fn check_boc_hash() -> Result<(), String> {
+    let boc = ton_types::serialize_toc(&ton_types::Cell::default()).handle_error()?;
+    let cell = ton_types::deserialize_tree_of_cells(&mut boc.as_slice()).handle_error()?;
+
+    let expected = ton_types::UInt256::from_str(EMPTY_CELL_HASH).handle_error()?;
+
+    match cell.repr_hash() == expected {
+        true => Ok(()),
+        false => Err("Unexpected empty cell hash").handle_error(),
+    }
+}
+
+fn check_ed25519() -> Result<(), String> {
+    let secret = ed25519_dalek::SecretKey::from_bytes(&[7; 32]).handle_error()?;
+    let public = ed25519_dalek::PublicKey::from(&secret);
+    let keypair = ed25519_dalek::Keypair { secret, public };
+
+    let data = b"nekoton self test";
+
+    let signature = keypair.sign(data);
+
+    public.verify(data, &signature).handle_error()
+}
+
+fn check_runtime() -> Result<(), String> {
+    let runtime = runtime()?;
+
+    let handle = runtime.spawn(async { 2 + 2 });
+
+    match runtime.block_on(handle).handle_error()? {
+        4 => Ok(()),
+        _ => Err("Unexpected task result").handle_error(),
+    }
+}
+
+fn check_cstring() -> Result<(), String> {
+    let ptr = CString::new("nekoton").handle_error()?.into_raw();
+
+    unsafe { nt_free_cstring(ptr) };
+
+    Ok(())
+}
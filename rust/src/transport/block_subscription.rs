@@ -0,0 +1,200 @@
+use std::{
+    os::raw::{c_char, c_longlong, c_void},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+
+use allo_isolate::Isolate;
+use nekoton::transport::{gql::GqlTransport, models::RawContractState, Transport};
+use nekoton_abi::GenTimings;
+use tokio::task::JoinHandle;
+use ton_block::MsgAddressInt;
+
+use crate::{
+    canonical_json::to_json_string,
+    ffi_timer, runtime,
+    transport::{
+        match_transport,
+        models::{BlockTip, TransportType},
+        subscription_counts::{
+            track_subscription_created, track_subscription_destroyed, transport_key,
+        },
+    },
+    HandleError, MatchResult, ToStringFromPtr,
+};
+
+const CONFIG_ADDRESS: &str = "-1:5555555555555555555555555555555555555555555555555555555555555555";
+
+const BLOCK_POLLING_INTERVAL: Duration = Duration::from_secs(2);
+const NEXT_BLOCK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Background task which posts masterchain tip updates until dropped
+pub struct BlockSubscription {
+    handle: JoinHandle<()>,
+}
+
+impl Drop for BlockSubscription {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_subscribe_blocks(
+    on_block_port: c_longlong,
+    transport: *mut c_void,
+    transport_type: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_subscribe_blocks");
+
+    let transport_type = transport_type.to_string_from_ptr();
+
+    // Transport type is checked before the pointer is cast, an unknown one is reported as an error
+    let subscription = serde_json::from_str::<TransportType>(&transport_type)
+        .handle_error()
+        .and_then(|parsed_transport_type| {
+            let runtime = runtime()?;
+
+            let transport_key = transport_key(&match_transport(transport, &transport_type));
+
+            let handle = match parsed_transport_type {
+                TransportType::Gql => {
+                    let gql_transport = (&*(transport as *mut Arc<GqlTransport>)).clone();
+
+                    runtime.spawn(watch_gql_blocks(Isolate::new(on_block_port), gql_transport))
+                },
+                TransportType::Jrpc => {
+                    let transport = match_transport(transport, &transport_type);
+
+                    runtime.spawn(poll_blocks(Isolate::new(on_block_port), transport))
+                },
+            };
+
+            Ok((handle, transport_key))
+        });
+
+    fn internal_fn(
+        subscription: Result<(JoinHandle<()>, usize), String>,
+    ) -> Result<serde_json::Value, String> {
+        let (handle, transport_key) = subscription?;
+
+        let ptr = Box::into_raw(Box::new(BlockSubscription { handle }));
+
+        track_subscription_created(ptr as usize, transport_key);
+
+        serde_json::to_value(ptr as usize).handle_error()
+    }
+
+    internal_fn(subscription).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_block_subscription_free_ptr(ptr: *mut c_void) {
+    println!("nt_block_subscription_free_ptr");
+    track_subscription_destroyed(ptr as usize);
+    Box::from_raw(ptr as *mut BlockSubscription);
+}
+
+async fn watch_gql_blocks(port: Isolate, gql_transport: Arc<GqlTransport>) {
+    let address = MsgAddressInt::from_str(CONFIG_ADDRESS).unwrap();
+
+    let mut current_block_id = None::<String>;
+
+    loop {
+        let next_block_id = match &current_block_id {
+            Some(id) => {
+                gql_transport
+                    .wait_for_next_block(id, &address, NEXT_BLOCK_TIMEOUT)
+                    .await
+            },
+            None => gql_transport.get_latest_block(&address).await.map(|e| e.id),
+        };
+
+        let next_block_id = match next_block_id {
+            Ok(id) => id,
+            Err(_) => {
+                tokio::time::sleep(BLOCK_POLLING_INTERVAL).await;
+                continue;
+            },
+        };
+
+        let info = match gql_transport.get_block(&next_block_id).await {
+            Ok(block) => block.read_info().ok(),
+            Err(_) => None,
+        };
+
+        let tip = match info {
+            Some(info) => BlockTip {
+                seqno: Some(info.seq_no()),
+                end_lt: info.end_lt().to_string(),
+                gen_utime: info.gen_utime().0,
+            },
+            None => {
+                tokio::time::sleep(BLOCK_POLLING_INTERVAL).await;
+                continue;
+            },
+        };
+
+        current_block_id = Some(next_block_id);
+
+        if let Ok(payload) = to_json_string(&tip) {
+            port.post(payload);
+        }
+    }
+}
+
+/// Emulates tip updates for transports without block queries using the generation lt
+/// of the config contract state, block seqno is not available in this case
+async fn poll_blocks(port: Isolate, transport: Arc<dyn Transport>) {
+    let address = MsgAddressInt::from_str(CONFIG_ADDRESS).unwrap();
+
+    let mut last_gen_lt = 0;
+
+    loop {
+        if let Ok(RawContractState::Exists(contract)) = transport.get_contract_state(&address).await
+        {
+            if let GenTimings::Known { gen_lt, gen_utime } = contract.timings {
+                if gen_lt > last_gen_lt {
+                    last_gen_lt = gen_lt;
+
+                    let tip = BlockTip {
+                        seqno: None,
+                        end_lt: gen_lt.to_string(),
+                        gen_utime,
+                    };
+
+                    if let Ok(payload) = to_json_string(&tip) {
+                        port.post(payload);
+                    }
+                }
+            }
+        }
+
+        tokio::time::sleep(BLOCK_POLLING_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, ptr};
+
+    use super::*;
+
+    #[test]
+    fn unknown_transport_type_gives_error() {
+        let transport_type = CString::new("\"Ws\"").unwrap();
+
+        let result = unsafe {
+            CString::from_raw(nt_subscribe_blocks(
+                0,
+                ptr::null_mut(),
+                transport_type.as_ptr() as *mut c_char,
+            ))
+        };
+
+        let result = serde_json::from_str::<serde_json::Value>(result.to_str().unwrap()).unwrap();
+
+        assert_eq!(result["type"], "err");
+    }
+}
@@ -10,19 +10,30 @@ use nekoton::transport::gql::GqlTransport;
 use ton_block::Serializable;
 
 use crate::{
-    external::gql_connection::GqlConnectionImpl, parse_address, runtime, HandleError, MatchResult,
-    PostWithResult, ToStringFromPtr, RUNTIME,
+    external::{
+        gql_connection::GqlConnectionImpl,
+        reconnect_backoff::{register_transport_backoff, remove_transport_backoff},
+    },
+    ffi_timer, parse_address, spawn_ffi_task,
+    transport::network_params::remove_network_config,
+    HandleError, MatchResult, PostWithResult, ToStringFromPtr,
 };
 
 #[no_mangle]
 pub unsafe extern "C" fn nt_gql_transport_create(gql_connection: *mut c_void) -> *mut c_char {
+    let _timer = ffi_timer!("nt_gql_transport_create");
+
     let gql_connection = (&*(gql_connection as *mut Arc<GqlConnectionImpl>)).clone();
 
     fn internal_fn(gql_connection: Arc<GqlConnectionImpl>) -> Result<serde_json::Value, String> {
+        let backoff = gql_connection.backoff.clone();
+
         let gql_transport = GqlTransport::new(gql_connection);
 
         let ptr = Box::into_raw(Box::new(Arc::new(gql_transport)));
 
+        register_transport_backoff(ptr as usize, backoff);
+
         serde_json::to_value(ptr as usize).handle_error()
     }
 
@@ -39,7 +50,9 @@ pub unsafe extern "C" fn nt_gql_transport_get_latest_block_id(
 
     let address = address.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_gql_transport_get_latest_block_id");
+
         async fn internal_fn(
             gql_transport: Arc<GqlTransport>,
             address: String,
@@ -71,7 +84,9 @@ pub unsafe extern "C" fn nt_gql_transport_get_block(
 
     let id = id.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_gql_transport_get_block");
+
         async fn internal_fn(
             gql_transport: Arc<GqlTransport>,
             id: String,
@@ -108,7 +123,9 @@ pub unsafe extern "C" fn nt_gql_transport_wait_for_next_block_id(
     let current_block_id = current_block_id.to_string_from_ptr();
     let address = address.to_string_from_ptr();
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_gql_transport_wait_for_next_block_id");
+
         async fn internal_fn(
             gql_transport: Arc<GqlTransport>,
             current_block_id: String,
@@ -138,5 +155,7 @@ pub unsafe extern "C" fn nt_gql_transport_wait_for_next_block_id(
 #[no_mangle]
 pub unsafe extern "C" fn nt_gql_transport_free_ptr(ptr: *mut c_void) {
     println!("nt_gql_transport_free_ptr");
-    Box::from_raw(ptr as *mut Arc<GqlTransport>);
+    remove_transport_backoff(ptr as usize);
+    let transport = Box::from_raw(ptr as *mut Arc<GqlTransport>);
+    remove_network_config(Arc::as_ptr(&transport) as *const () as usize);
 }
@@ -0,0 +1,286 @@
+use std::{
+    collections::VecDeque,
+    convert::TryFrom,
+    os::raw::{c_char, c_longlong, c_uint, c_void},
+    sync::Arc,
+};
+
+use allo_isolate::Isolate;
+use nekoton::{
+    core::models::{Transaction, TransactionsBatchInfo, TransactionsBatchType},
+    transport::{
+        models::{RawContractState, RawTransaction},
+        Transport,
+    },
+};
+use tokio::sync::{mpsc, Semaphore};
+use ton_block::MsgAddressInt;
+
+use crate::{
+    canonical_json::to_json_string,
+    ffi_timer, metrics, parse_address, spawn_ffi_task,
+    transport::{
+        match_transport,
+        models::{HistoryDownloadProgress, TransactionsList},
+    },
+    HandleError, MatchResult, PostWithResult, ToOptionalStringFromPtr, ToStringFromPtr,
+};
+
+const HISTORY_PAGE_SIZE: u8 = 50;
+const MAX_HISTORY_SEGMENTS: u32 = 16;
+
+/// Page of a segment, `None` marks that the segment is exhausted
+type SegmentPage = (usize, Option<Vec<RawTransaction>>);
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_transport_download_history(
+    result_port: c_longlong,
+    on_batch_port: c_longlong,
+    on_progress_port: c_longlong,
+    transport: *mut c_void,
+    transport_type: *mut c_char,
+    address: *mut c_char,
+    from_lt: *mut c_char,
+    to_lt: *mut c_char,
+    segments: c_uint,
+    max_concurrent_requests: c_uint,
+) {
+    let transport_type = transport_type.to_string_from_ptr();
+    let address = address.to_string_from_ptr();
+    let from_lt = from_lt.to_optional_string_from_ptr();
+    let to_lt = to_lt.to_optional_string_from_ptr();
+
+    let transport = match_transport(transport, &transport_type);
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_transport_download_history");
+
+        async fn internal_fn(
+            on_batch_port: Isolate,
+            on_progress_port: Isolate,
+            transport: Arc<dyn Transport>,
+            address: String,
+            from_lt: Option<String>,
+            to_lt: Option<String>,
+            segments: u32,
+            max_concurrent_requests: u32,
+        ) -> Result<serde_json::Value, String> {
+            let address = parse_address(&address)?;
+
+            let from_lt = match from_lt {
+                Some(from_lt) => from_lt.parse::<u64>().handle_error()?,
+                None => match transport
+                    .get_contract_state(&address)
+                    .await
+                    .handle_error()?
+                {
+                    RawContractState::Exists(contract) => contract.account.storage.last_trans_lt,
+                    RawContractState::NotExists => return serde_json::to_value(0).handle_error(),
+                },
+            };
+
+            let to_lt = to_lt
+                .map(|e| e.parse::<u64>())
+                .transpose()
+                .handle_error()?
+                .unwrap_or_default();
+
+            if from_lt <= to_lt {
+                return serde_json::to_value(0).handle_error();
+            }
+
+            let segments = segments.clamp(1, MAX_HISTORY_SEGMENTS) as u64;
+            let semaphore = Arc::new(Semaphore::new(max_concurrent_requests.max(1) as usize));
+
+            // Segments split the lt range evenly, newest first, each one covers (lower, upper]
+            let step = ((from_lt - to_lt) / segments).max(1);
+            let bounds = (0..segments)
+                .map(|i| {
+                    let upper = from_lt - step * i;
+                    let lower = match i + 1 == segments {
+                        true => to_lt,
+                        false => (from_lt - step * (i + 1)).max(to_lt),
+                    };
+                    (lower, upper)
+                })
+                .filter(|(lower, upper)| lower < upper)
+                .collect::<Vec<_>>();
+
+            let (tx, mut rx) = mpsc::unbounded_channel::<Result<SegmentPage, String>>();
+
+            let handles = bounds
+                .iter()
+                .enumerate()
+                .map(|(index, &(lower, upper))| {
+                    tokio::spawn(download_segment(
+                        transport.clone(),
+                        semaphore.clone(),
+                        address.clone(),
+                        index,
+                        lower,
+                        upper,
+                        tx.clone(),
+                    ))
+                })
+                .collect::<Vec<_>>();
+
+            drop(tx);
+
+            let mut buffers = vec![VecDeque::<Vec<RawTransaction>>::new(); bounds.len()];
+            let mut finished = vec![false; bounds.len()];
+            let mut current = 0;
+
+            let mut fetched = 0u64;
+            let mut estimated_total = None;
+
+            while current < bounds.len() {
+                let (index, page) = match rx.recv().await {
+                    Some(Ok(page)) => page,
+                    Some(Err(err)) => {
+                        handles.iter().for_each(|e| e.abort());
+                        return Err(err);
+                    },
+                    None => return Err("History download stopped unexpectedly").handle_error(),
+                };
+
+                match page {
+                    Some(page) => {
+                        fetched += page.len() as u64;
+
+                        if estimated_total.is_none() {
+                            estimated_total = estimate_total(&page, from_lt - to_lt);
+                        }
+
+                        buffers[index].push_back(page);
+                    },
+                    None => finished[index] = true,
+                }
+
+                let progress = HistoryDownloadProgress {
+                    fetched,
+                    estimated_total: estimated_total.unwrap_or(fetched).max(fetched),
+                };
+
+                on_progress_port.post(to_json_string(&progress).handle_error()?);
+                metrics::record_event_posted();
+
+                // Pages are delivered strictly from the newest segment to the oldest one
+                while current < bounds.len() {
+                    while let Some(page) = buffers[current].pop_front() {
+                        on_batch_port
+                            .post(to_json_string(&make_transactions_list(page)).handle_error()?);
+                        metrics::record_event_posted();
+                    }
+
+                    match finished[current] {
+                        true => current += 1,
+                        false => break,
+                    }
+                }
+            }
+
+            serde_json::to_value(fetched).handle_error()
+        }
+
+        let result = internal_fn(
+            Isolate::new(on_batch_port),
+            Isolate::new(on_progress_port),
+            transport,
+            address,
+            from_lt,
+            to_lt,
+            segments,
+            max_concurrent_requests,
+        )
+        .await
+        .match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
+async fn download_segment(
+    transport: Arc<dyn Transport>,
+    semaphore: Arc<Semaphore>,
+    address: MsgAddressInt,
+    index: usize,
+    lower: u64,
+    upper: u64,
+    tx: mpsc::UnboundedSender<Result<SegmentPage, String>>,
+) {
+    let mut from_lt = upper;
+
+    loop {
+        let page = {
+            let _permit = semaphore.acquire().await.unwrap();
+
+            transport
+                .get_transactions(&address, from_lt, HISTORY_PAGE_SIZE)
+                .await
+                .handle_error()
+        };
+
+        let page = match page {
+            Ok(page) => page,
+            Err(err) => {
+                let _ = tx.send(Err(err));
+                return;
+            },
+        };
+
+        let next_lt = page
+            .last()
+            .map(|e| e.data.prev_trans_lt)
+            .unwrap_or_default();
+
+        let page = page
+            .into_iter()
+            .filter(|e| e.data.lt > lower && e.data.lt <= upper)
+            .collect::<Vec<_>>();
+
+        if !page.is_empty() && tx.send(Ok((index, Some(page)))).is_err() {
+            return;
+        }
+
+        if next_lt <= lower || next_lt == 0 {
+            let _ = tx.send(Ok((index, None)));
+            return;
+        }
+
+        from_lt = next_lt;
+    }
+}
+
+/// Extrapolates transactions density of the first page to the whole lt range
+fn estimate_total(page: &[RawTransaction], lt_range: u64) -> Option<u64> {
+    let (first, last) = (page.first()?, page.last()?);
+
+    let page_range = first.data.lt.saturating_sub(last.data.lt);
+    if page.len() < 2 || page_range == 0 {
+        return None;
+    }
+
+    Some((lt_range as u128 * (page.len() - 1) as u128 / page_range as u128) as u64)
+}
+
+fn make_transactions_list(page: Vec<RawTransaction>) -> TransactionsList {
+    let info = match (page.first(), page.last()) {
+        (Some(first), Some(last)) => Some(TransactionsBatchInfo {
+            min_lt: last.data.lt,
+            max_lt: first.data.lt,
+            batch_type: TransactionsBatchType::Old,
+        }),
+        _ => None,
+    };
+
+    let transactions = page
+        .into_iter()
+        .filter_map(|e| Transaction::try_from((e.hash, e.data)).ok())
+        .collect::<Vec<_>>();
+
+    TransactionsList {
+        transactions,
+        continuation: None,
+        info,
+    }
+}
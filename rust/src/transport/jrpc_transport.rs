@@ -5,16 +5,38 @@ use std::{
 
 use nekoton::transport::jrpc::JrpcTransport;
 
-use crate::{external::jrpc_connection::JrpcConnectionImpl, HandleError, MatchResult};
+use crate::{
+    external::{
+        jrpc_connection::JrpcConnectionImpl,
+        reconnect_backoff::{register_transport_backoff, remove_transport_backoff},
+    },
+    ffi_timer,
+    transport::{
+        library_cells::{register_jrpc_connection, remove_jrpc_connection},
+        network_params::remove_network_config,
+    },
+    HandleError, MatchResult,
+};
 
 #[no_mangle]
 pub unsafe extern "C" fn nt_jrpc_transport_create(jrpc_connection: *mut c_void) -> *mut c_char {
+    let _timer = ffi_timer!("nt_jrpc_transport_create");
+
     let jrpc_connection = (&*(jrpc_connection as *mut Arc<JrpcConnectionImpl>)).clone();
 
     fn internal_fn(jrpc_connection: Arc<JrpcConnectionImpl>) -> Result<serde_json::Value, String> {
-        let jrpc_transport = JrpcTransport::new(jrpc_connection);
+        let backoff = jrpc_connection.backoff.clone();
+
+        let jrpc_transport = Arc::new(JrpcTransport::new(jrpc_connection.clone()));
+
+        register_jrpc_connection(
+            Arc::as_ptr(&jrpc_transport) as *const () as usize,
+            jrpc_connection,
+        );
+
+        let ptr = Box::into_raw(Box::new(jrpc_transport));
 
-        let ptr = Box::into_raw(Box::new(Arc::new(jrpc_transport)));
+        register_transport_backoff(ptr as usize, backoff);
 
         serde_json::to_value(ptr as usize).handle_error()
     }
@@ -25,5 +47,8 @@ pub unsafe extern "C" fn nt_jrpc_transport_create(jrpc_connection: *mut c_void)
 #[no_mangle]
 pub unsafe extern "C" fn nt_jrpc_transport_free_ptr(ptr: *mut c_void) {
     println!("nt_jrpc_transport_free_ptr");
-    Box::from_raw(ptr as *mut Arc<JrpcTransport>);
+    remove_transport_backoff(ptr as usize);
+    let transport = Box::from_raw(ptr as *mut Arc<JrpcTransport>);
+    remove_jrpc_connection(Arc::as_ptr(&transport) as *const () as usize);
+    remove_network_config(Arc::as_ptr(&transport) as *const () as usize);
 }
@@ -0,0 +1,170 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use lazy_static::lazy_static;
+use nekoton::{external::JrpcConnection, transport::Transport};
+use serde::{Deserialize, Serialize};
+use ton_types::{Cell, UInt256};
+
+use crate::{
+    external::jrpc_connection::JrpcConnectionImpl, helpers::abi::parse_cell,
+    transport::subscription_counts::transport_key, HandleError,
+};
+
+lazy_static! {
+    /// Nekoton transports don't expose the connection they were created with, so connections of
+    /// JRPC transports are kept here to send requests the transport itself has no method for
+    static ref JRPC_CONNECTIONS: Mutex<HashMap<usize, Arc<JrpcConnectionImpl>>> =
+        Mutex::new(HashMap::new());
+}
+
+pub fn register_jrpc_connection(transport: usize, connection: Arc<JrpcConnectionImpl>) {
+    JRPC_CONNECTIONS
+        .lock()
+        .unwrap()
+        .insert(transport, connection);
+}
+
+pub fn remove_jrpc_connection(transport: usize) {
+    JRPC_CONNECTIONS.lock().unwrap().remove(&transport);
+}
+
+#[derive(Serialize)]
+struct JrpcRequest<'a, T> {
+    jsonrpc: &'static str,
+    id: u32,
+    method: &'static str,
+    params: &'a T,
+}
+
+#[derive(Deserialize)]
+struct JrpcResponse<T> {
+    result: Option<T>,
+    error: Option<JrpcError>,
+}
+
+#[derive(Deserialize)]
+struct JrpcError {
+    code: i32,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct GetLibraryCellRequest {
+    hash: String,
+}
+
+#[derive(Deserialize)]
+struct GetLibraryCellResponse {
+    cell: Option<String>,
+}
+
+/// Fetches a published library cell with the `getLibraryCell` JRPC method. GQL endpoints have no
+/// library lookup. Returns `None` if no library with this hash is published
+pub async fn get_library_cell(
+    transport: &Arc<dyn Transport>,
+    hash: &UInt256,
+) -> Result<Option<Cell>, String> {
+    let connection = JRPC_CONNECTIONS
+        .lock()
+        .unwrap()
+        .get(&transport_key(transport))
+        .cloned()
+        .ok_or("Library cells can only be fetched over JRPC")?;
+
+    let request = serde_json::to_string(&JrpcRequest {
+        jsonrpc: "2.0",
+        id: 1,
+        method: "getLibraryCell",
+        params: &GetLibraryCellRequest {
+            hash: hash.to_hex_string(),
+        },
+    })
+    .handle_error()?;
+
+    let response = connection.post(&request).await.handle_error()?;
+
+    parse_library_cell_response(&response, hash)
+}
+
+fn parse_library_cell_response(response: &str, hash: &UInt256) -> Result<Option<Cell>, String> {
+    let response =
+        serde_json::from_str::<JrpcResponse<GetLibraryCellResponse>>(response).handle_error()?;
+
+    if let Some(error) = response.error {
+        return Err(format!("JRPC error {}: {}", error.code, error.message));
+    }
+
+    let cell = match response.result.and_then(|e| e.cell) {
+        Some(cell) => parse_cell(&cell)?,
+        None => return Ok(None),
+    };
+
+    if cell.repr_hash() != *hash {
+        return Err("Library cell hash mismatch".to_owned());
+    }
+
+    Ok(Some(cell))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn library_cell() -> Cell {
+        let mut cell = ton_types::BuilderData::new();
+        cell.append_u32(0x11b).unwrap();
+        cell.into_cell().unwrap()
+    }
+
+    fn response(cell: Option<&Cell>) -> String {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "cell": cell.map(|e| base64::encode(ton_types::serialize_toc(e).unwrap())),
+            },
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn found_library_cell_is_returned() {
+        let cell = library_cell();
+
+        let parsed = parse_library_cell_response(&response(Some(&cell)), &cell.repr_hash());
+
+        assert_eq!(parsed.unwrap(), Some(cell));
+    }
+
+    #[test]
+    fn missing_library_cell_is_none() {
+        let parsed = parse_library_cell_response(&response(None), &library_cell().repr_hash());
+
+        assert_eq!(parsed.unwrap(), None);
+    }
+
+    #[test]
+    fn library_cell_with_other_hash_is_rejected() {
+        let parsed =
+            parse_library_cell_response(&response(Some(&library_cell())), &UInt256::default());
+
+        assert!(parsed.is_err());
+    }
+
+    #[test]
+    fn jrpc_error_is_reported() {
+        let response = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": { "code": -32601, "message": "Method not found" },
+        })
+        .to_string();
+
+        let parsed = parse_library_cell_response(&response, &library_cell().repr_hash());
+
+        assert_eq!(parsed.unwrap_err(), "JRPC error -32601: Method not found");
+    }
+}
@@ -1,12 +1,19 @@
+mod block_subscription;
 mod gql_transport;
+mod history_download;
 mod jrpc_transport;
+mod library_cells;
 pub(crate) mod models;
+pub(crate) mod network_params;
+pub(crate) mod subscription_counts;
 
 use std::{
+    collections::HashMap,
     convert::TryFrom,
-    os::raw::{c_char, c_longlong, c_uchar, c_void},
+    os::raw::{c_char, c_longlong, c_uchar, c_uint, c_void},
     str::FromStr,
     sync::Arc,
+    time::Duration,
 };
 
 use allo_isolate::Isolate;
@@ -15,14 +22,22 @@ use nekoton::{
     transport::{gql::GqlTransport, jrpc::JrpcTransport, models::RawContractState, Transport},
 };
 use nekoton_abi::TransactionId;
-use ton_block::Serializable;
+use nekoton_utils::Clock;
+use ton_block::{Deserializable, MsgAddressInt, Serializable};
 
 use crate::{
-    parse_address, runtime,
-    transport::models::{
-        AccountsList, FullContractState, RawContractStateHelper, TransactionsList, TransportType,
+    cancellation::CancellationToken,
+    canonical_json::to_json_string,
+    clock, ffi_timer, parse_address, spawn_cancellable_ffi_task, spawn_ffi_task,
+    transport::{
+        library_cells::get_library_cell,
+        models::{
+            AccountsList, AwaitedMessage, AwaitedTransactionPayload, FullContractState,
+            RawContractStateHelper, TransactionsList, TransportType, UnfreezeParams,
+        },
+        network_params::get_network_config,
     },
-    HandleError, MatchResult, PostWithResult, ToOptionalStringFromPtr, ToStringFromPtr, RUNTIME,
+    HandleError, MatchResult, PostWithResult, ToOptionalStringFromPtr, ToStringFromPtr, CLOCK,
 };
 
 #[no_mangle]
@@ -37,7 +52,9 @@ pub unsafe extern "C" fn nt_transport_get_contract_state(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_transport_get_contract_state");
+
         async fn internal_fn(
             transport: Arc<dyn Transport>,
             address: String,
@@ -70,7 +87,9 @@ pub unsafe extern "C" fn nt_transport_get_full_contract_state(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_transport_get_full_contract_state");
+
         async fn internal_fn(
             transport: Arc<dyn Transport>,
             address: String,
@@ -134,7 +153,9 @@ pub unsafe extern "C" fn nt_transport_get_accounts_by_code_hash(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_transport_get_accounts_by_code_hash");
+
         async fn internal_fn(
             transport: Arc<dyn Transport>,
             code_hash: String,
@@ -180,7 +201,9 @@ pub unsafe extern "C" fn nt_transport_get_transactions(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_transport_get_transactions");
+
         async fn internal_fn(
             transport: Arc<dyn Transport>,
             address: String,
@@ -251,7 +274,9 @@ pub unsafe extern "C" fn nt_transport_get_transaction(
 
     let transport = match_transport(transport, &transport_type);
 
-    runtime!().spawn(async move {
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_transport_get_transaction");
+
         async fn internal_fn(
             transport: Arc<dyn Transport>,
             hash: String,
@@ -275,6 +300,328 @@ pub unsafe extern "C" fn nt_transport_get_transaction(
     });
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_library_cell(
+    result_port: c_longlong,
+    transport: *mut c_void,
+    transport_type: *mut c_char,
+    library_hash: *mut c_char,
+) {
+    let transport_type = transport_type.to_string_from_ptr();
+    let library_hash = library_hash.to_string_from_ptr();
+
+    let transport = match_transport(transport, &transport_type);
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_get_library_cell");
+
+        async fn internal_fn(
+            transport: Arc<dyn Transport>,
+            library_hash: String,
+        ) -> Result<serde_json::Value, String> {
+            let library_hash = parse_hash(&library_hash)?;
+
+            let library_cell = get_library_cell(&transport, &library_hash)
+                .await?
+                .map(|e| ton_types::serialize_toc(&e))
+                .transpose()
+                .handle_error()?
+                .map(base64::encode);
+
+            serde_json::to_value(library_cell).handle_error()
+        }
+
+        let result = internal_fn(transport, library_hash).await.match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_prepare_unfreeze(
+    result_port: c_longlong,
+    transport: *mut c_void,
+    transport_type: *mut c_char,
+    address: *mut c_char,
+    state_init: *mut c_char,
+) {
+    let transport_type = transport_type.to_string_from_ptr();
+    let address = address.to_string_from_ptr();
+    let state_init = state_init.to_string_from_ptr();
+
+    let transport = match_transport(transport, &transport_type);
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_prepare_unfreeze");
+
+        async fn internal_fn(
+            transport: Arc<dyn Transport>,
+            address: String,
+            state_init: String,
+        ) -> Result<serde_json::Value, String> {
+            let address = parse_address(&address)?;
+
+            let state_init =
+                ton_block::StateInit::construct_from_base64(&state_init).handle_error()?;
+
+            let state_init_cell = state_init.serialize().handle_error()?;
+
+            let provided_hash = state_init_cell.repr_hash();
+
+            let account = match transport
+                .get_contract_state(&address)
+                .await
+                .handle_error()?
+            {
+                RawContractState::Exists(state) => state.account,
+                RawContractState::NotExists => return Err("Account not exists").handle_error(),
+            };
+
+            let expected_hash = match &account.storage.state {
+                ton_block::AccountState::AccountFrozen { state_init_hash } => state_init_hash,
+                _ => return Err("Account is not frozen").handle_error(),
+            };
+
+            if expected_hash != &provided_hash {
+                return Err(format!(
+                    "State init hash mismatch: expected {}, provided {}",
+                    expected_hash.to_hex_string(),
+                    provided_hash.to_hex_string(),
+                ));
+            }
+
+            let network_config = get_network_config(&transport).await?;
+
+            let is_masterchain = address.is_masterchain();
+
+            let due_payment = account
+                .storage_stat
+                .due_payment
+                .as_ref()
+                .map(|e| e.0)
+                .unwrap_or_default();
+
+            let storage_fee = network_config.config.calc_storage_fee(
+                &account.storage_stat,
+                is_masterchain,
+                clock!().now_sec_u64() as u32,
+            );
+
+            let gas_fee = match is_masterchain {
+                true => network_config.params.masterchain_gas.flat_gas_price,
+                false => network_config.params.basechain_gas.flat_gas_price,
+            } as u128;
+
+            let amount = due_payment + storage_fee + gas_fee;
+
+            let state_init = ton_types::serialize_toc(&state_init_cell)
+                .map(base64::encode)
+                .handle_error()?;
+
+            let unfreeze_params = UnfreezeParams {
+                state_init,
+                amount: amount.to_string(),
+                due_payment: due_payment.to_string(),
+            };
+
+            serde_json::to_value(&unfreeze_params).handle_error()
+        }
+
+        let result = internal_fn(transport, address, state_init)
+            .await
+            .match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_network_params(
+    result_port: c_longlong,
+    transport: *mut c_void,
+    transport_type: *mut c_char,
+) {
+    let transport_type = transport_type.to_string_from_ptr();
+
+    let transport = match_transport(transport, &transport_type);
+
+    spawn_ffi_task(result_port, async move {
+        let _timer = ffi_timer!("nt_get_network_params");
+
+        async fn internal_fn(transport: Arc<dyn Transport>) -> Result<serde_json::Value, String> {
+            let network_config = get_network_config(&transport).await?;
+
+            serde_json::to_value(&network_config.params).handle_error()
+        }
+
+        let result = internal_fn(transport).await.match_result();
+
+        Isolate::new(result_port).post_with_result(result).unwrap();
+    });
+}
+
+/// Seconds before the start of `nt_await_transactions` which are still scanned for the messages
+const AWAIT_SCAN_GRACE_PERIOD: u32 = 60;
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_await_transactions(
+    result_port: c_longlong,
+    transport: *mut c_void,
+    transport_type: *mut c_char,
+    messages: *mut c_char,
+    expire_at: c_uint,
+    cancellation_token: *mut c_void,
+) {
+    let transport_type = transport_type.to_string_from_ptr();
+    let messages = messages.to_string_from_ptr();
+
+    let transport = match_transport(transport, &transport_type);
+
+    let cancellation_token = CancellationToken::from_ptr(cancellation_token);
+
+    spawn_cancellable_ffi_task(result_port, cancellation_token, async move {
+        let _timer = ffi_timer!("nt_await_transactions");
+
+        async fn internal_fn(
+            port: &Isolate,
+            transport: Arc<dyn Transport>,
+            messages: String,
+            expire_at: u32,
+        ) -> Result<(), String> {
+            let messages = serde_json::from_str::<Vec<AwaitedMessage>>(&messages).handle_error()?;
+
+            let mut pending = HashMap::with_capacity(messages.len());
+
+            for message in messages {
+                let hash = parse_hash(&message.hash)?;
+                let expire_at = message.expire_at.unwrap_or(expire_at);
+
+                pending.insert(hash, (message.dst, expire_at));
+            }
+
+            // Messages are broadcast right before awaiting, older transactions can't contain them
+            let scan_since =
+                (clock!().now_sec_u64() as u32).saturating_sub(AWAIT_SCAN_GRACE_PERIOD);
+
+            let mut known_lts = HashMap::<MsgAddressInt, u64>::new();
+
+            while !pending.is_empty() {
+                let mut destinations = Vec::<&MsgAddressInt>::new();
+
+                for (dst, _) in pending.values() {
+                    if !destinations.contains(&dst) {
+                        destinations.push(dst);
+                    }
+                }
+
+                let mut found = Vec::new();
+
+                for dst in destinations {
+                    let last_trans_lt =
+                        match transport.get_contract_state(dst).await.handle_error()? {
+                            RawContractState::Exists(contract) => {
+                                contract.account.storage.last_trans_lt
+                            },
+                            RawContractState::NotExists => continue,
+                        };
+
+                    let known_lt = known_lts.get(dst).copied().unwrap_or_default();
+
+                    if last_trans_lt <= known_lt {
+                        continue;
+                    }
+
+                    let mut from_lt = u64::MAX;
+
+                    'pages: loop {
+                        let raw_transactions = transport
+                            .get_transactions(dst, from_lt, 16)
+                            .await
+                            .handle_error()?;
+
+                        for raw_transaction in &raw_transactions {
+                            if raw_transaction.data.lt <= known_lt
+                                || raw_transaction.data.now < scan_since
+                            {
+                                break 'pages;
+                            }
+
+                            if let Some(in_msg) = raw_transaction.data.in_msg_cell() {
+                                let message_hash = in_msg.repr_hash();
+
+                                if pending.contains_key(&message_hash) {
+                                    found.push((message_hash, raw_transaction.clone()));
+                                }
+                            }
+                        }
+
+                        match raw_transactions.last() {
+                            Some(last)
+                                if raw_transactions.len() >= 16 && last.data.prev_trans_lt != 0 =>
+                            {
+                                from_lt = last.data.prev_trans_lt
+                            },
+                            _ => break,
+                        }
+                    }
+
+                    known_lts.insert(dst.to_owned(), last_trans_lt);
+                }
+
+                for (message_hash, raw_transaction) in found {
+                    pending.remove(&message_hash);
+
+                    let transaction =
+                        Transaction::try_from((raw_transaction.hash, raw_transaction.data))
+                            .handle_error()?;
+
+                    let payload = to_json_string(&AwaitedTransactionPayload::Found {
+                        message_hash: message_hash.to_hex_string(),
+                        transaction,
+                    })
+                    .handle_error()?;
+
+                    port.post(payload);
+                }
+
+                let now = clock!().now_sec_u64() as u32;
+
+                let expired = pending
+                    .iter()
+                    .filter(|(_, (_, expire_at))| *expire_at < now)
+                    .map(|(hash, _)| *hash)
+                    .collect::<Vec<_>>();
+
+                for message_hash in expired {
+                    pending.remove(&message_hash);
+
+                    let payload = to_json_string(&AwaitedTransactionPayload::Expired {
+                        message_hash: message_hash.to_hex_string(),
+                    })
+                    .handle_error()?;
+
+                    port.post(payload);
+                }
+
+                if !pending.is_empty() {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+            }
+
+            Ok(())
+        }
+
+        let port = Isolate::new(result_port);
+
+        let payload = match internal_fn(&port, transport, messages, expire_at).await {
+            Ok(_) => to_json_string(&AwaitedTransactionPayload::Completed).unwrap(),
+            Err(err) => to_json_string(&AwaitedTransactionPayload::Failed { error: err }).unwrap(),
+        };
+
+        port.post(payload);
+    });
+}
+
 pub unsafe fn match_transport(transport: *mut c_void, transport_type: &str) -> Arc<dyn Transport> {
     let transport_type = serde_json::from_str::<TransportType>(transport_type).unwrap();
 
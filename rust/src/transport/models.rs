@@ -3,7 +3,7 @@ use nekoton::{
     transport::models::{ExistingContract, RawContractState},
 };
 use nekoton_abi::{GenTimings, LastTransactionId, TransactionId};
-use nekoton_utils::{serde_optional_address, serde_vec_address};
+use nekoton_utils::{serde_address, serde_optional_address, serde_vec_address};
 use serde::{Deserialize, Serialize};
 use ton_block::MsgAddressInt;
 
@@ -58,3 +58,120 @@ pub struct AccountsList {
     )]
     pub continuation: Option<MsgAddressInt>,
 }
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnfreezeParams {
+    pub state_init: String,
+    pub amount: String,
+    pub due_payment: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkParams {
+    pub key_block_seqno: u32,
+    pub masterchain_gas: GasParams,
+    pub basechain_gas: GasParams,
+    pub masterchain_block_limits: BlockLimitsParams,
+    pub basechain_block_limits: BlockLimitsParams,
+    pub fundamental_addresses: Vec<String>,
+    pub size_limits: SizeLimits,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasParams {
+    pub gas_price: u64,
+    pub gas_limit: u64,
+    pub special_gas_limit: u64,
+    pub gas_credit: u64,
+    pub block_gas_limit: u64,
+    pub freeze_due_limit: u64,
+    pub delete_due_limit: u64,
+    pub flat_gas_limit: u64,
+    pub flat_gas_price: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockLimitsParams {
+    pub bytes: LimitsParams,
+    pub gas: LimitsParams,
+    pub lt_delta: LimitsParams,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitsParams {
+    pub underload: u32,
+    pub soft_limit: u32,
+    pub hard_limit: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeLimits {
+    pub max_msg_bits: u32,
+    pub max_msg_cells: u32,
+    pub max_library_cells: u32,
+    pub max_vm_data_depth: u16,
+    pub max_ext_msg_size: u32,
+    pub max_ext_msg_depth: u16,
+}
+
+impl Default for SizeLimits {
+    fn default() -> Self {
+        Self {
+            max_msg_bits: 1 << 21,
+            max_msg_cells: 1 << 13,
+            max_library_cells: 1000,
+            max_vm_data_depth: 512,
+            max_ext_msg_size: 65535,
+            max_ext_msg_depth: 512,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AwaitedMessage {
+    pub hash: String,
+    #[serde(with = "serde_address")]
+    pub dst: MsgAddressInt,
+    pub expire_at: Option<u32>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum AwaitedTransactionPayload {
+    #[serde(rename_all = "camelCase")]
+    Found {
+        message_hash: String,
+        transaction: Transaction,
+    },
+    #[serde(rename_all = "camelCase")]
+    Expired {
+        message_hash: String,
+    },
+    Completed,
+    Failed {
+        error: String,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockTip {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seqno: Option<u32>,
+    pub end_lt: String,
+    pub gen_utime: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryDownloadProgress {
+    pub fetched: u64,
+    pub estimated_total: u64,
+}
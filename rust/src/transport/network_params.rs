@@ -0,0 +1,195 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
+use nekoton::transport::Transport;
+use ton_block::{ConfigParamEnum, GasLimitsPrices, ParamLimits};
+use ton_executor::BlockchainConfig;
+use ton_types::{SliceData, UInt256};
+
+use crate::{
+    transport::{
+        models::{BlockLimitsParams, GasParams, LimitsParams, NetworkParams, SizeLimits},
+        subscription_counts::transport_key,
+    },
+    HandleError,
+};
+
+/// Key blocks appear every few hours, a cached config isn't checked against the latest one sooner
+const NETWORK_CONFIG_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+lazy_static! {
+    static ref NETWORK_CONFIGS: Mutex<HashMap<usize, CachedNetworkConfig>> =
+        Mutex::new(HashMap::new());
+}
+
+struct CachedNetworkConfig {
+    network_config: Arc<NetworkConfig>,
+    checked_at: Instant,
+}
+
+fn is_fresh(checked_at: Instant, now: Instant) -> bool {
+    now.saturating_duration_since(checked_at) < NETWORK_CONFIG_CHECK_INTERVAL
+}
+
+pub struct NetworkConfig {
+    pub config: BlockchainConfig,
+    pub params: NetworkParams,
+}
+
+/// Returns config of the latest key block. The latest key block is fetched at most once per check
+/// interval and its config is reparsed only when the key block seqno changes
+pub async fn get_network_config(
+    transport: &Arc<dyn Transport>,
+) -> Result<Arc<NetworkConfig>, String> {
+    let key = transport_key(transport);
+
+    if let Some(cached) = NETWORK_CONFIGS.lock().unwrap().get(&key) {
+        if is_fresh(cached.checked_at, Instant::now()) {
+            return Ok(cached.network_config.clone());
+        }
+    }
+
+    let key_block = transport.get_latest_key_block().await.handle_error()?;
+
+    let key_block_seqno = key_block.read_info().handle_error()?.seq_no();
+
+    if let Some(cached) = NETWORK_CONFIGS.lock().unwrap().get_mut(&key) {
+        if cached.network_config.params.key_block_seqno == key_block_seqno {
+            cached.checked_at = Instant::now();
+            return Ok(cached.network_config.clone());
+        }
+    }
+
+    let config = key_block
+        .read_extra()
+        .handle_error()?
+        .read_custom()
+        .handle_error()?
+        .and_then(|e| e.config().cloned())
+        .ok_or("Key block has no config")
+        .handle_error()?;
+
+    let config = BlockchainConfig::with_config(config).handle_error()?;
+
+    let params = parse_network_params(&config, key_block_seqno)?;
+
+    let network_config = Arc::new(NetworkConfig { config, params });
+
+    NETWORK_CONFIGS.lock().unwrap().insert(
+        key,
+        CachedNetworkConfig {
+            network_config: network_config.clone(),
+            checked_at: Instant::now(),
+        },
+    );
+
+    Ok(network_config)
+}
+
+/// Drops the config cached for a transport which is being freed
+pub fn remove_network_config(transport: usize) {
+    NETWORK_CONFIGS.lock().unwrap().remove(&transport);
+}
+
+fn parse_network_params(
+    blockchain_config: &BlockchainConfig,
+    key_block_seqno: u32,
+) -> Result<NetworkParams, String> {
+    let config = blockchain_config.raw_config();
+
+    let block_limits = |masterchain: bool| -> Result<BlockLimitsParams, String> {
+        let limits = config.block_limits(masterchain).handle_error()?;
+
+        Ok(BlockLimitsParams {
+            bytes: make_limits_params(limits.bytes()),
+            gas: make_limits_params(limits.gas()),
+            lt_delta: make_limits_params(limits.lt_delta()),
+        })
+    };
+
+    let mut fundamental_addresses = Vec::new();
+
+    config
+        .fundamental_smc_addr()
+        .handle_error()?
+        .iterate_keys(|key: UInt256| {
+            fundamental_addresses.push(key.to_hex_string());
+            Ok(true)
+        })
+        .handle_error()?;
+
+    let size_limits = match config.config(43).handle_error()? {
+        Some(ConfigParamEnum::ConfigParamAny(_, mut slice)) => parse_size_limits(&mut slice)?,
+        _ => SizeLimits::default(),
+    };
+
+    Ok(NetworkParams {
+        key_block_seqno,
+        masterchain_gas: make_gas_params(blockchain_config.get_gas_config(true)),
+        basechain_gas: make_gas_params(blockchain_config.get_gas_config(false)),
+        masterchain_block_limits: block_limits(true)?,
+        basechain_block_limits: block_limits(false)?,
+        fundamental_addresses,
+        size_limits,
+    })
+}
+
+fn make_gas_params(gas: &GasLimitsPrices) -> GasParams {
+    GasParams {
+        gas_price: gas.gas_price,
+        gas_limit: gas.gas_limit,
+        special_gas_limit: gas.special_gas_limit,
+        gas_credit: gas.gas_credit,
+        block_gas_limit: gas.block_gas_limit,
+        freeze_due_limit: gas.freeze_due_limit,
+        delete_due_limit: gas.delete_due_limit,
+        flat_gas_limit: gas.flat_gas_limit,
+        flat_gas_price: gas.flat_gas_price,
+    }
+}
+
+fn make_limits_params(limits: &ParamLimits) -> LimitsParams {
+    LimitsParams {
+        underload: limits.underload(),
+        soft_limit: limits.soft_limit(),
+        hard_limit: limits.hard_limit(),
+    }
+}
+
+fn parse_size_limits(slice: &mut SliceData) -> Result<SizeLimits, String> {
+    let tag = slice.get_next_byte().handle_error()?;
+
+    if tag != 0x01 && tag != 0x02 {
+        return Err("Invalid size limits config tag").handle_error();
+    }
+
+    Ok(SizeLimits {
+        max_msg_bits: slice.get_next_u32().handle_error()?,
+        max_msg_cells: slice.get_next_u32().handle_error()?,
+        max_library_cells: slice.get_next_u32().handle_error()?,
+        max_vm_data_depth: slice.get_next_u16().handle_error()?,
+        max_ext_msg_size: slice.get_next_u32().handle_error()?,
+        max_ext_msg_depth: slice.get_next_u16().handle_error()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_config_is_rechecked_after_interval() {
+        let checked_at = Instant::now();
+
+        assert!(is_fresh(checked_at, checked_at));
+        assert!(is_fresh(checked_at, checked_at + Duration::from_secs(59)));
+        assert!(!is_fresh(
+            checked_at,
+            checked_at + NETWORK_CONFIG_CHECK_INTERVAL
+        ));
+    }
+}
@@ -0,0 +1,94 @@
+use std::{
+    collections::HashMap,
+    os::raw::{c_char, c_void},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use lazy_static::lazy_static;
+use nekoton::transport::Transport;
+
+use crate::{ffi_timer, transport::match_transport, HandleError, MatchResult, ToStringFromPtr};
+
+static GLOBAL_SUBSCRIPTION_COUNT: AtomicU32 = AtomicU32::new(0);
+
+lazy_static! {
+    static ref TRANSPORT_COUNTERS: Mutex<HashMap<usize, Arc<AtomicU32>>> =
+        Mutex::new(HashMap::new());
+    static ref SUBSCRIPTION_COUNTERS: Mutex<HashMap<usize, Arc<AtomicU32>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Transports are identified by their shared state, so all handles of one transport count together
+pub fn transport_key(transport: &Arc<dyn Transport>) -> usize {
+    Arc::as_ptr(transport) as *const () as usize
+}
+
+pub fn track_subscription_created(subscription: usize, transport: usize) {
+    let mut transport_counters = TRANSPORT_COUNTERS.lock().unwrap();
+
+    let counter = transport_counters.entry(transport).or_default().clone();
+
+    counter.fetch_add(1, Ordering::SeqCst);
+    GLOBAL_SUBSCRIPTION_COUNT.fetch_add(1, Ordering::SeqCst);
+
+    SUBSCRIPTION_COUNTERS
+        .lock()
+        .unwrap()
+        .insert(subscription, counter);
+}
+
+pub fn track_subscription_destroyed(subscription: usize) {
+    let mut transport_counters = TRANSPORT_COUNTERS.lock().unwrap();
+
+    let counter = match SUBSCRIPTION_COUNTERS.lock().unwrap().remove(&subscription) {
+        Some(counter) => counter,
+        None => return,
+    };
+
+    GLOBAL_SUBSCRIPTION_COUNT.fetch_sub(1, Ordering::SeqCst);
+
+    if counter.fetch_sub(1, Ordering::SeqCst) == 1 {
+        transport_counters.retain(|_, e| !Arc::ptr_eq(e, &counter));
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_transport_subscription_count(
+    transport: *mut c_void,
+    transport_type: *mut c_char,
+) -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_transport_subscription_count");
+
+    let transport_type = transport_type.to_string_from_ptr();
+
+    let transport = match_transport(transport, &transport_type);
+
+    fn internal_fn(transport: Arc<dyn Transport>) -> Result<serde_json::Value, String> {
+        let count = TRANSPORT_COUNTERS
+            .lock()
+            .unwrap()
+            .get(&transport_key(&transport))
+            .map(|e| e.load(Ordering::SeqCst))
+            .unwrap_or_default();
+
+        serde_json::to_value(count).handle_error()
+    }
+
+    internal_fn(transport).match_result()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nt_get_global_subscription_count() -> *mut c_char {
+    let _timer = ffi_timer!("nt_get_global_subscription_count");
+
+    fn internal_fn() -> Result<serde_json::Value, String> {
+        let count = GLOBAL_SUBSCRIPTION_COUNT.load(Ordering::SeqCst);
+
+        serde_json::to_value(count).handle_error()
+    }
+
+    internal_fn().match_result()
+}